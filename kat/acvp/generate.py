@@ -0,0 +1,111 @@
+#!/usr/bin/env python3
+# Generates the ACVP-format SHA-3 and SHAKE vectors in this directory with
+# Python's hashlib, an implementation independent of src/fips202.rs.
+#
+# Each file combines the prompt and the expected results, in the layout of the
+# internalProjection.json files of the NIST ACVP-Server, but these are not ACVP
+# vectors. The Monte Carlo chaining below mirrors src/acvp.rs, so only the AFT
+# and VOT results are an independent check. Messages are byte-oriented and drawn
+# from a seeded PRNG, so the files are reproducible.
+
+import hashlib
+import json
+import random
+
+SHA3 = {"SHA3-224": (hashlib.sha3_224, 144), "SHA3-256": (hashlib.sha3_256, 136),
+        "SHA3-384": (hashlib.sha3_384, 104), "SHA3-512": (hashlib.sha3_512, 72)}
+SHAKE = {"SHAKE-128": (hashlib.shake_128, 168, 128), "SHAKE-256": (hashlib.shake_256, 136, 256)}
+MIN_OUT_LEN = 16
+MAX_OUT_LEN = 4096
+
+
+def aft_lengths(rate):
+    # Every length up to two blocks, then longer messages around block boundaries
+    lengths = list(range(0, 2 * rate + 2))
+    for blocks in (3, 4, 7, 8, 16):
+        lengths += [blocks * rate - 1, blocks * rate, blocks * rate + 1]
+    return lengths
+
+
+def aft_tests(rng, rate, digest):
+    tests = []
+    for length in aft_lengths(rate):
+        msg = rng.randbytes(length)
+        tests.append({"tcId": 0, "msg": msg.hex().upper(), "len": 8 * length, **digest(msg)})
+    return tests
+
+
+def sha3_mct(function, seed):
+    results = []
+    md = seed
+    for _ in range(100):
+        for _ in range(1000):
+            md = function(md).digest()
+        results.append({"md": md.hex().upper()})
+    return results
+
+
+def shake_mct(function, msg, min_out_len, max_out_len):
+    # SHAKE Monte Carlo test of the SHA-3 validation system, in bytes
+    min_bytes, max_bytes = min_out_len // 8, max_out_len // 8
+    out_range = max_bytes - min_bytes + 1
+    out_len = max_bytes
+    output = msg
+    results = []
+    for _ in range(100):
+        for _ in range(1000):
+            inner = (output + bytes(16))[:16]
+            output = function(inner).digest(out_len)
+            out_len = min_bytes + int.from_bytes(output[-2:], "big") % out_range
+        results.append({"md": output.hex().upper(), "outLen": 8 * len(output)})
+    return results
+
+
+def number(groups):
+    tc_id = 0
+    for group in groups:
+        for test in group["tests"]:
+            tc_id += 1
+            test["tcId"] = tc_id
+    return [{"tgId": tg_id, **group} for tg_id, group in enumerate(groups, 1)]
+
+
+def write(algorithm, revision, groups):
+    vector_set = {"vsId": 0, "algorithm": algorithm, "revision": revision, "isSample": True,
+                  "testGroups": number(groups)}
+    with open(f"{algorithm}.json", "w") as f:
+        json.dump([{"acvVersion": "1.0"}, vector_set], f, indent=2)
+        f.write("\n")
+
+
+def main():
+    for algorithm, (function, rate) in SHA3.items():
+        rng = random.Random(algorithm)
+        size = function().digest_size
+        aft = aft_tests(rng, rate, lambda msg: {"md": function(msg).hexdigest().upper()})
+        seed = rng.randbytes(size)
+        mct = [{"tcId": 0, "msg": seed.hex().upper(), "len": 8 * size, "resultsArray": sha3_mct(function, seed)}]
+        write(algorithm, "2.0", [{"testType": "AFT", "tests": aft},
+                                 {"testType": "MCT", "mctVersion": "standard", "tests": mct}])
+
+    for algorithm, (function, rate, strength) in SHAKE.items():
+        rng = random.Random(algorithm)
+        bounds = {"inBit": False, "inEmpty": True, "outBit": False, "minOutLen": MIN_OUT_LEN, "maxOutLen": MAX_OUT_LEN}
+        aft = aft_tests(rng, rate,
+                        lambda msg: {"outLen": strength, "md": function(msg).hexdigest(strength // 8).upper()})
+        vot = []
+        msg = rng.randbytes(strength // 8)
+        for out_bytes in sorted(set(range(MIN_OUT_LEN // 8, MAX_OUT_LEN // 8 + 1, 5)) | {rate - 1, rate, rate + 1,
+                                                                                       MAX_OUT_LEN // 8}):
+            vot.append({"tcId": 0, "msg": msg.hex().upper(), "len": strength, "outLen": 8 * out_bytes,
+                        "md": function(msg).hexdigest(out_bytes).upper()})
+        seed = rng.randbytes(16)
+        mct = [{"tcId": 0, "msg": seed.hex().upper(), "len": 128,
+                "resultsArray": shake_mct(function, seed, 128, MAX_OUT_LEN)}]
+        write(algorithm, "1.0", [{"testType": "AFT", **bounds, "tests": aft},
+                                 {"testType": "VOT", **bounds, "tests": vot},
+                                 {"testType": "MCT", **bounds, "minOutLen": 128, "tests": mct}])
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,411 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runner for SHA-3 and SHAKE (FIPS 202) test vectors in the ACVP JSON format.
+//!
+//! It reads vector sets that carry their expected results, laid out like the
+//! `internalProjection.json` files of the NIST ACVP-Server, either bare or
+//! after an `acvVersion` object. Algorithm functional tests (AFT), variable
+//! output tests (VOT) and the standard Monte Carlo test (MCT) of
+//! byte-oriented messages and outputs are run. Large data tests (LDT), the
+//! alternate Monte Carlo test and bit-oriented lengths are reported as
+//! skipped.
+//!
+//! This is not an ACVP conformance test. No ACVP-Server vectors are bundled,
+//! and the runner has not been checked against one. The vector sets in
+//! `kat/acvp/` come from Python's `hashlib` through `kat/acvp/generate.py`,
+//! so AFT and VOT results cross-check this crate against an independent
+//! Keccak. The MCT expected values do not: `generate.py` implements the same
+//! chaining as [`run`], so a shared misreading of the Monte Carlo procedure
+//! would pass. The sets cover every message length up to two blocks, lengths
+//! around larger block boundaries, and SHAKE output lengths of 16 to 4096
+//! bits.
+//!
+//! This module is only public with the `test-vectors` feature.
+
+use alloc::{vec, vec::Vec};
+
+use crate::json::Value;
+use crate::sha3::{sha3_224, sha3_256, sha3_384, sha3_512};
+use crate::shake::{Shake128, Shake256};
+use crate::{hex, VAZ256Error, VAZ256Result};
+
+/// Hash functions with ACVP vector sets
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    Sha3_224,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    Shake128,
+    Shake256,
+}
+
+impl Algorithm {
+    /// Looks up an ACVP `algorithm` name such as `SHAKE-128`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "SHA3-224" => Some(Algorithm::Sha3_224),
+            "SHA3-256" => Some(Algorithm::Sha3_256),
+            "SHA3-384" => Some(Algorithm::Sha3_384),
+            "SHA3-512" => Some(Algorithm::Sha3_512),
+            "SHAKE-128" => Some(Algorithm::Shake128),
+            "SHAKE-256" => Some(Algorithm::Shake256),
+            _ => None,
+        }
+    }
+
+    /// Digest size of the fixed-output functions
+    pub fn output_size(self) -> Option<usize> {
+        match self {
+            Algorithm::Sha3_224 => Some(28),
+            Algorithm::Sha3_256 => Some(32),
+            Algorithm::Sha3_384 => Some(48),
+            Algorithm::Sha3_512 => Some(64),
+            Algorithm::Shake128 | Algorithm::Shake256 => None,
+        }
+    }
+
+    /// Hashes `msg`. `out_len` bytes are returned for SHAKE and ignored for SHA-3.
+    pub fn digest(self, msg: &[u8], out_len: usize) -> Vec<u8> {
+        match self {
+            Algorithm::Sha3_224 => sha3_224(msg).to_vec(),
+            Algorithm::Sha3_256 => sha3_256(msg).to_vec(),
+            Algorithm::Sha3_384 => sha3_384(msg).to_vec(),
+            Algorithm::Sha3_512 => sha3_512(msg).to_vec(),
+            Algorithm::Shake128 => {
+                let mut out = vec![0u8; out_len];
+                let mut shake = Shake128::new();
+                shake.update(msg);
+                shake.finalize(&mut out);
+                out
+            }
+            Algorithm::Shake256 => {
+                let mut out = vec![0u8; out_len];
+                let mut shake = Shake256::new();
+                shake.update(msg);
+                shake.finalize(&mut out);
+                out
+            }
+        }
+    }
+}
+
+/// Outcome of a vector set
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub algorithm: Algorithm,
+    pub passed: usize,
+    /// `tcId`s of the failed tests
+    pub failed: Vec<usize>,
+    /// `tcId`s of the tests that weren't run: large data, alternate Monte Carlo and bit-oriented tests
+    pub skipped: Vec<usize>,
+}
+
+fn number(value: &Value, name: &str) -> VAZ256Result<usize> {
+    let number = value.get(name).and_then(Value::as_i64).ok_or(VAZ256Error::DeserializationError)?;
+    usize::try_from(number).map_err(|_| VAZ256Error::DeserializationError)
+}
+
+fn bytes(value: &Value, name: &str) -> VAZ256Result<Vec<u8>> {
+    let text = value.get(name).and_then(Value::as_str).ok_or(VAZ256Error::DeserializationError)?;
+    hex::decode(text).map_err(|_| VAZ256Error::HexDecodingError)
+}
+
+/// Length in bits of field `name` in bytes, or `None` if it isn't whole bytes
+fn byte_length(value: &Value, name: &str) -> VAZ256Result<Option<usize>> {
+    let bits = number(value, name)?;
+    Ok((bits % 8 == 0).then_some(bits / 8))
+}
+
+/// The message of a test checked against its `len`, or `None` if it is bit-oriented.
+/// An empty message may be written as `00`.
+fn message(test: &Value) -> VAZ256Result<Option<Vec<u8>>> {
+    let msg = bytes(test, "msg")?;
+    let bits = number(test, "len")?;
+    if bits == 0 && msg == [0] {
+        return Ok(Some(Vec::new()));
+    }
+    if bits.div_ceil(8) != msg.len() {
+        return Err(VAZ256Error::DeserializationError);
+    }
+    Ok((bits % 8 == 0).then_some(msg))
+}
+
+/// Monte Carlo checkpoints: each output and its length in bytes
+type Checkpoints = Vec<(Vec<u8>, usize)>;
+
+/// SHA-3 Monte Carlo test: 100 checkpoints of 1000 chained digests
+fn sha3_mct(digest: &impl Fn(&[u8], usize) -> Vec<u8>, seed: Vec<u8>) -> Checkpoints {
+    let mut md = seed;
+    (0..100)
+        .map(|_| {
+            for _ in 0..1000 {
+                md = digest(&md, 0);
+            }
+            (md.clone(), md.len())
+        })
+        .collect()
+}
+
+/// SHAKE Monte Carlo test: each output seeds the next with its first 16 bytes
+/// and picks the next output length with its last 2 bytes
+fn shake_mct(digest: &impl Fn(&[u8], usize) -> Vec<u8>, seed: Vec<u8>, min: usize, max: usize) -> Checkpoints {
+    let range = max - min + 1;
+    let mut out_len = max;
+    let mut output = seed;
+    (0..100)
+        .map(|_| {
+            for _ in 0..1000 {
+                let mut inner = [0u8; 16];
+                let n = output.len().min(16);
+                inner[..n].copy_from_slice(&output[..n]);
+                output = digest(&inner, out_len);
+                let tail = match output.len() {
+                    0 => 0,
+                    1 => output[0] as usize,
+                    n => u16::from_be_bytes([output[n - 2], output[n - 1]]) as usize,
+                };
+                out_len = min + tail % range;
+            }
+            (output.clone(), output.len())
+        })
+        .collect()
+}
+
+/// Runs one AFT, VOT or standard MCT test, or returns `None` if it is bit-oriented
+fn run_test(
+    digest: &impl Fn(&[u8], usize) -> Vec<u8>,
+    algorithm: Algorithm,
+    test_type: &str,
+    group: &Value,
+    test: &Value,
+) -> VAZ256Result<Option<bool>> {
+    let Some(msg) = message(test)? else {
+        return Ok(None);
+    };
+    if test_type != "MCT" {
+        let md = bytes(test, "md")?;
+        if test.get("outLen").is_some() {
+            match byte_length(test, "outLen")? {
+                None => return Ok(None),
+                Some(out_len) if out_len != md.len() => return Err(VAZ256Error::DeserializationError),
+                Some(_) => {}
+            }
+        }
+        return Ok(Some(digest(&msg, md.len()) == md));
+    }
+
+    let results = match algorithm.output_size() {
+        Some(_) => sha3_mct(digest, msg),
+        None => {
+            let (Some(min), Some(max)) = (byte_length(group, "minOutLen")?, byte_length(group, "maxOutLen")?) else {
+                return Ok(None);
+            };
+            if min > max {
+                return Err(VAZ256Error::DeserializationError);
+            }
+            shake_mct(digest, msg, min, max)
+        }
+    };
+    let expected = test.get("resultsArray").and_then(Value::as_array).ok_or(VAZ256Error::DeserializationError)?;
+    if expected.len() != results.len() {
+        return Err(VAZ256Error::DeserializationError);
+    }
+    let mut passed = true;
+    for (result, (md, out_len)) in expected.iter().zip(results) {
+        if result.get("outLen").is_some() && byte_length(result, "outLen")? != Some(out_len) {
+            passed = false;
+        }
+        passed &= bytes(result, "md")? == md;
+    }
+    Ok(Some(passed))
+}
+
+/// Runs a vector set with the crate's SHA-3 and SHAKE
+pub fn run(text: &str) -> VAZ256Result<Report> {
+    run_with(text, Algorithm::digest)
+}
+
+/// Runs a vector set against `digest(algorithm, msg, out_len)`, which must follow [`Algorithm::digest`]
+pub fn run_with(text: &str, digest: impl Fn(Algorithm, &[u8], usize) -> Vec<u8>) -> VAZ256Result<Report> {
+    let root = Value::parse(text)?;
+    // Files hold `[{"acvVersion": ...}, {vector set}]`, or just the vector set
+    let vector_set = match &root {
+        Value::Array(items) => items.iter().find(|item| item.get("algorithm").is_some()),
+        _ => Some(&root),
+    }
+    .ok_or(VAZ256Error::DeserializationError)?;
+    let algorithm = vector_set
+        .get("algorithm")
+        .and_then(Value::as_str)
+        .and_then(Algorithm::from_name)
+        .ok_or(VAZ256Error::DeserializationError)?;
+    let digest = |msg: &[u8], out_len: usize| digest(algorithm, msg, out_len);
+    let groups = vector_set.get("testGroups").and_then(Value::as_array).ok_or(VAZ256Error::DeserializationError)?;
+
+    let mut report = Report { algorithm, passed: 0, failed: Vec::new(), skipped: Vec::new() };
+    for group in groups {
+        let test_type = group.get("testType").and_then(Value::as_str).ok_or(VAZ256Error::DeserializationError)?;
+        let tests = group.get("tests").and_then(Value::as_array).ok_or(VAZ256Error::DeserializationError)?;
+        let runnable = match test_type {
+            "AFT" | "VOT" => true,
+            "MCT" => {
+                let version = group.get("mctVersion").and_then(Value::as_str).unwrap_or("standard");
+                version == "standard" && group.get("outBit").and_then(Value::as_bool) != Some(true)
+            }
+            "LDT" => false,
+            _ => return Err(VAZ256Error::DeserializationError),
+        };
+        for test in tests {
+            let tc_id = number(test, "tcId")?;
+            let passed = if runnable { run_test(&digest, algorithm, test_type, group, test)? } else { None };
+            match passed {
+                Some(true) => report.passed += 1,
+                Some(false) => report.failed.push(tc_id),
+                None => report.skipped.push(tc_id),
+            }
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fips202;
+    use alloc::format;
+
+    const VECTOR_SETS: [(&str, &str, usize); 6] = [
+        ("SHA3-224", include_str!("../kat/acvp/SHA3-224.json"), 306),
+        ("SHA3-256", include_str!("../kat/acvp/SHA3-256.json"), 290),
+        ("SHA3-384", include_str!("../kat/acvp/SHA3-384.json"), 226),
+        ("SHA3-512", include_str!("../kat/acvp/SHA3-512.json"), 162),
+        ("SHAKE-128", include_str!("../kat/acvp/SHAKE-128.json"), 459),
+        ("SHAKE-256", include_str!("../kat/acvp/SHAKE-256.json"), 395),
+    ];
+
+    #[test]
+    fn test_vector_sets() {
+        for (name, text, count) in VECTOR_SETS {
+            let report = run(text).unwrap();
+            assert_eq!(Some(report.algorithm), Algorithm::from_name(name));
+            assert_eq!(report.failed, Vec::<usize>::new(), "{}", name);
+            assert_eq!(report.passed, count, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_one_shot_shake256() {
+        // The one-shot SHAKE256 behind key compression and sampling
+        let one_shot = |_: Algorithm, msg: &[u8], out_len: usize| {
+            let mut out = vec![0u8; out_len];
+            fips202::shake256(&mut out, out_len, msg, msg.len());
+            out
+        };
+        let report = run_with(VECTOR_SETS[5].1, one_shot).unwrap();
+        assert_eq!((report.passed, report.failed.len()), (395, 0));
+    }
+
+    #[test]
+    fn test_failures_are_reported() {
+        // A digest that is wrong only for one message length
+        let broken = |algorithm: Algorithm, msg: &[u8], out_len: usize| {
+            let mut out = algorithm.digest(msg, out_len);
+            if msg.len() == 136 {
+                out[0] ^= 1;
+            }
+            out
+        };
+        let report = run_with(VECTOR_SETS[1].1, broken).unwrap();
+        assert_eq!(report.failed, vec![137]);
+        assert_eq!(report.passed, 289);
+    }
+
+    #[test]
+    fn test_malformed_vector_sets() {
+        let set = |group: &str| {
+            let test = r#"{"tcId":1,"msg":"00","len":8,"md":"00"}"#;
+            let groups = group.replace("TEST", test);
+            format!(r#"[{{"acvVersion":"1.0"}},{{"algorithm":"SHAKE-128","testGroups":[{}]}}]"#, groups)
+        };
+        let aft = set(r#"{"testType":"AFT","tests":[TEST]}"#);
+        let report = run(&aft).unwrap();
+        assert_eq!(report.failed, vec![1]);
+        let wrong_length = aft.replace(r#""len":8"#, r#""len":16"#);
+        assert_eq!(run(&wrong_length), Err(VAZ256Error::DeserializationError));
+        let unknown = aft.replace("SHAKE-128", "SHA-1");
+        assert_eq!(run(&unknown), Err(VAZ256Error::DeserializationError));
+        assert_eq!(run(&set(r#"{"testType":"XYZ","tests":[TEST]}"#)), Err(VAZ256Error::DeserializationError));
+        assert_eq!(run("[]"), Err(VAZ256Error::DeserializationError));
+
+        // Bit-oriented tests are skipped
+        let bit_outputs = aft.replace(r#""md":"00""#, r#""md":"00","outLen":7"#);
+        for bit_oriented in [aft.replace(r#""len":8"#, r#""len":7"#), bit_outputs] {
+            let report = run(&bit_oriented).unwrap();
+            assert_eq!((report.passed, report.failed.len(), report.skipped), (0, 0, vec![1]));
+        }
+    }
+
+    #[test]
+    fn test_server_layout() {
+        // Hand-written in the layout of an ACVP-Server internalProjection.json: a bare vector set with
+        // an LDT group, an empty message written as `00`, and the alternate Monte Carlo test. The digests
+        // are the FIPS 202 values of the empty message and "abc".
+        let sha3_256 = r#"{
+            "vsId": 42, "algorithm": "SHA3-256", "revision": "2.0", "isSample": false,
+            "testGroups": [
+                {"tgId": 1, "testType": "AFT", "tests": [
+                    {"tcId": 1, "msg": "00", "len": 0,
+                     "md": "A7FFC6F8BF1ED76651C14756A061D662F580FF4DE43B49FA82D80A4B80F8434A"},
+                    {"tcId": 2, "msg": "616263", "len": 24,
+                     "md": "3A985DA74FE225B2045C172D6BD390BD855F086E3E9D525B46BFE24511431532"}]},
+                {"tgId": 2, "testType": "MCT", "mctVersion": "alternate", "tests": [
+                    {"tcId": 3, "msg": "616263", "len": 24, "resultsArray": []}]},
+                {"tgId": 3, "testType": "LDT", "tests": [
+                    {"tcId": 4, "largeMsg": {"content": "616263", "contentLength": 24,
+                     "fullLength": 8589934592, "expansionTechnique": "repeating"},
+                     "md": "00"}]}]
+        }"#;
+        let report = run(sha3_256).unwrap();
+        assert_eq!(report.algorithm, Algorithm::Sha3_256);
+        assert_eq!((report.passed, report.failed.len(), report.skipped), (2, 0, vec![3, 4]));
+
+        let shake = |algorithm: &str, md: &str| {
+            format!(
+                r#"{{"vsId": 42, "algorithm": "{}", "revision": "1.0", "isSample": false, "testGroups": [
+                    {{"tgId": 1, "testType": "VOT", "inBit": false, "inEmpty": true, "outBit": true,
+                      "minOutLen": 16, "maxOutLen": 65536, "tests": [
+                        {{"tcId": 1, "msg": "00", "len": 0, "outLen": {}, "md": "{}"}},
+                        {{"tcId": 2, "msg": "00", "len": 0, "outLen": 17, "md": "000000"}}]}},
+                    {{"tgId": 2, "testType": "MCT", "inBit": false, "inEmpty": true, "outBit": true,
+                      "minOutLen": 16, "maxOutLen": 65536, "tests": [
+                        {{"tcId": 3, "msg": "00", "len": 0, "resultsArray": []}}]}}]}}"#,
+                algorithm,
+                4 * md.len(),
+                md
+            )
+        };
+        let shake128 = shake("SHAKE-128", "7F9C2BA4E88F827D616045507605853ED73B8093F6EFBC88EB1A6EACFA66EF26");
+        let shake256 = shake(
+            "SHAKE-256",
+            "46B9DD2B0BA88D13233B3FEB743EEB243FCD52EA62B81B82B50C27646ED5762F\
+             D75DC4DDD8C0F200CB05019D67B592F6FC821C49479AB48640292EACB3B7C4BE",
+        );
+        for (text, algorithm) in [(shake128, Algorithm::Shake128), (shake256, Algorithm::Shake256)] {
+            let report = run(&text).unwrap();
+            assert_eq!(report.algorithm, algorithm);
+            assert_eq!((report.passed, report.failed.len(), report.skipped), (1, 0, vec![2, 3]));
+        }
+    }
+}
@@ -0,0 +1,355 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! AVX2 kernels for the polynomial arithmetic of Dilithium5.
+//!
+//! Every kernel computes exactly what its scalar counterpart in [`crate::ntt`],
+//! [`crate::reduce`] and [`crate::rounding_dilithium5`] computes, eight
+//! coefficients at a time. They are only sound to call once [`available`]
+//! has returned `true`; `poly_dilithium5` makes that check before each call.
+
+use core::arch::x86_64::*;
+
+use crate::ntt::ZETAS;
+use crate::params_dilithium5::{GAMMA2, Q};
+use crate::reduce::Q_INV;
+
+const N: usize = crate::params_dilithium5::N as usize;
+const F: i32 = 41978; // mont^2/256
+
+/// Whether the running CPU supports AVX2. Without `std` there is no runtime
+/// detection, so this is decided at compile time by `target_feature`.
+pub fn available() -> bool {
+    #[cfg(feature = "std")]
+    return std::is_x86_feature_detected!("avx2");
+    #[cfg(not(feature = "std"))]
+    return cfg!(target_feature = "avx2");
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+fn load(a: &[i32], i: usize) -> __m256i {
+    let lanes = &a[i..i + 8];
+    // SAFETY: `lanes` is 8 readable integers and the load is unaligned
+    unsafe { _mm256_loadu_si256(lanes.as_ptr().cast()) }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+fn store(a: &mut [i32], i: usize, v: __m256i) {
+    let lanes = &mut a[i..i + 8];
+    // SAFETY: `lanes` is 8 writable integers and the store is unaligned
+    unsafe { _mm256_storeu_si256(lanes.as_mut_ptr().cast(), v) }
+}
+
+/// `montgomery_reduce(a * b)` on each of the eight lanes
+#[inline]
+#[target_feature(enable = "avx2")]
+fn montgomery_mul(a: __m256i, b: __m256i) -> __m256i {
+    let qinv = _mm256_set1_epi32(Q_INV);
+    let q = _mm256_set1_epi32(Q);
+
+    // 64-bit products of the even lanes, then of the odd lanes
+    let even = _mm256_mul_epi32(a, b);
+    let odd = _mm256_mul_epi32(_mm256_srli_epi64::<32>(a), _mm256_srli_epi64::<32>(b));
+    let t_even = _mm256_mul_epi32(_mm256_mul_epi32(even, qinv), q);
+    let t_odd = _mm256_mul_epi32(_mm256_mul_epi32(odd, qinv), q);
+
+    // The low halves cancel, so subtracting the high halves alone gives (a*b - t*Q) >> 32
+    let r_even = _mm256_srli_epi64::<32>(_mm256_sub_epi32(even, t_even));
+    let r_odd = _mm256_sub_epi32(odd, t_odd);
+    _mm256_blend_epi32::<0b1010_1010>(r_even, r_odd)
+}
+
+/// Gathers the given zetas into one vector, negated for the inverse transform
+#[inline]
+#[target_feature(enable = "avx2")]
+fn zetas(k: [usize; 8], inverse: bool) -> __m256i {
+    let z = k.map(|k| if inverse { -ZETAS[k] } else { ZETAS[k] });
+    load(&z, 0)
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+fn butterfly(lo: __m256i, hi: __m256i, zeta: __m256i) -> (__m256i, __m256i) {
+    let t = montgomery_mul(zeta, hi);
+    (_mm256_add_epi32(lo, t), _mm256_sub_epi32(lo, t))
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+fn inverse_butterfly(lo: __m256i, hi: __m256i, zeta: __m256i) -> (__m256i, __m256i) {
+    let sum = _mm256_add_epi32(lo, hi);
+    (sum, montgomery_mul(zeta, _mm256_sub_epi32(lo, hi)))
+}
+
+/// Splits 16 coefficients into the two halves of each butterfly for distance 4, 2 or 1
+#[inline]
+#[target_feature(enable = "avx2")]
+fn split(v0: __m256i, v1: __m256i, len: usize) -> (__m256i, __m256i) {
+    match len {
+        4 => (_mm256_permute2x128_si256::<0x20>(v0, v1), _mm256_permute2x128_si256::<0x31>(v0, v1)),
+        2 => (_mm256_unpacklo_epi64(v0, v1), _mm256_unpackhi_epi64(v0, v1)),
+        _ => {
+            let (a, b) = (_mm256_castsi256_ps(v0), _mm256_castsi256_ps(v1));
+            let lo = _mm256_shuffle_ps::<0b1000_1000>(a, b);
+            let hi = _mm256_shuffle_ps::<0b1101_1101>(a, b);
+            (_mm256_castps_si256(lo), _mm256_castps_si256(hi))
+        }
+    }
+}
+
+/// Inverse of [`split`]
+#[inline]
+#[target_feature(enable = "avx2")]
+fn join(lo: __m256i, hi: __m256i, len: usize) -> (__m256i, __m256i) {
+    match len {
+        4 => (_mm256_permute2x128_si256::<0x20>(lo, hi), _mm256_permute2x128_si256::<0x31>(lo, hi)),
+        2 => (_mm256_unpacklo_epi64(lo, hi), _mm256_unpackhi_epi64(lo, hi)),
+        _ => (_mm256_unpacklo_epi32(lo, hi), _mm256_unpackhi_epi32(lo, hi)),
+    }
+}
+
+/// Index, within 16 coefficients, of the block each lane of [`split`] belongs to
+fn split_blocks(len: usize) -> [usize; 8] {
+    match len {
+        4 => [0, 0, 0, 0, 1, 1, 1, 1],
+        2 => [0, 0, 2, 2, 1, 1, 3, 3],
+        _ => [0, 1, 4, 5, 2, 3, 6, 7],
+    }
+}
+
+/// Forward NTT, same as [`crate::ntt::ntt`]
+#[target_feature(enable = "avx2")]
+pub fn ntt(a: &mut [i32; N]) {
+    let mut len = 128;
+    while len >= 8 {
+        for (block, start) in (0..N).step_by(2 * len).enumerate() {
+            let zeta = _mm256_set1_epi32(ZETAS[N / (2 * len) + block]);
+            for j in (start..start + len).step_by(8) {
+                let (lo, hi) = butterfly(load(a, j), load(a, j + len), zeta);
+                store(a, j, lo);
+                store(a, j + len, hi);
+            }
+        }
+        len >>= 1;
+    }
+
+    for c in (0..N).step_by(16) {
+        let (mut v0, mut v1) = (load(a, c), load(a, c + 8));
+        for len in [4, 2, 1] {
+            let first = N / (2 * len) + c / (2 * len);
+            let zeta = zetas(split_blocks(len).map(|b| first + b), false);
+            let (lo, hi) = split(v0, v1, len);
+            let (lo, hi) = butterfly(lo, hi, zeta);
+            (v0, v1) = join(lo, hi, len);
+        }
+        store(a, c, v0);
+        store(a, c + 8, v1);
+    }
+}
+
+/// Inverse NTT and multiplication by 2^32, same as [`crate::ntt::invntt_tomont`]
+#[target_feature(enable = "avx2")]
+pub fn invntt_tomont(a: &mut [i32; N]) {
+    for c in (0..N).step_by(16) {
+        let (mut v0, mut v1) = (load(a, c), load(a, c + 8));
+        for len in [1, 2, 4] {
+            let last = N / len - 1 - c / (2 * len);
+            let zeta = zetas(split_blocks(len).map(|b| last - b), true);
+            let (lo, hi) = split(v0, v1, len);
+            let (lo, hi) = inverse_butterfly(lo, hi, zeta);
+            (v0, v1) = join(lo, hi, len);
+        }
+        store(a, c, v0);
+        store(a, c + 8, v1);
+    }
+
+    let mut len = 8;
+    while len < N {
+        for (block, start) in (0..N).step_by(2 * len).enumerate() {
+            let zeta = _mm256_set1_epi32(-ZETAS[N / len - 1 - block]);
+            for j in (start..start + len).step_by(8) {
+                let (lo, hi) = inverse_butterfly(load(a, j), load(a, j + len), zeta);
+                store(a, j, lo);
+                store(a, j + len, hi);
+            }
+        }
+        len <<= 1;
+    }
+
+    let f = _mm256_set1_epi32(F);
+    for i in (0..N).step_by(8) {
+        let v = montgomery_mul(f, load(a, i));
+        store(a, i, v);
+    }
+}
+
+/// Pointwise Montgomery multiplication, same as `poly_dilithium5::pointwise_montgomery`
+#[target_feature(enable = "avx2")]
+pub fn pointwise_montgomery(c: &mut [i32; N], a: &[i32; N], b: &[i32; N]) {
+    for i in (0..N).step_by(8) {
+        store(c, i, montgomery_mul(load(a, i), load(b, i)));
+    }
+}
+
+/// [`crate::reduce::reduce32`] on every coefficient
+#[target_feature(enable = "avx2")]
+pub fn reduce(a: &mut [i32; N]) {
+    let q = _mm256_set1_epi32(Q);
+    let half = _mm256_set1_epi32(1 << 22);
+    for i in (0..N).step_by(8) {
+        let v = load(a, i);
+        let t = _mm256_srai_epi32::<23>(_mm256_add_epi32(v, half));
+        store(a, i, _mm256_sub_epi32(v, _mm256_mullo_epi32(t, q)));
+    }
+}
+
+/// [`crate::reduce::caddq`] on every coefficient
+#[target_feature(enable = "avx2")]
+pub fn caddq(a: &mut [i32; N]) {
+    let q = _mm256_set1_epi32(Q);
+    for i in (0..N).step_by(8) {
+        let v = load(a, i);
+        let t = _mm256_and_si256(_mm256_srai_epi32::<31>(v), q);
+        store(a, i, _mm256_add_epi32(v, t));
+    }
+}
+
+/// [`crate::rounding_dilithium5::decompose`] on every coefficient of `a1`, which receives the low
+/// bits while `a0` receives the high bits, matching `poly_dilithium5::decompose`
+#[target_feature(enable = "avx2")]
+pub fn decompose(a1: &mut [i32; N], a0: &mut [i32; N]) {
+    let q = _mm256_set1_epi32(Q);
+    let half_q = _mm256_set1_epi32((Q - 1) / 2);
+    let alpha = _mm256_set1_epi32(2 * GAMMA2 as i32);
+    for i in (0..N).step_by(8) {
+        let a = load(a1, i);
+        let mut high = _mm256_srai_epi32::<7>(_mm256_add_epi32(a, _mm256_set1_epi32(127)));
+        high = _mm256_mullo_epi32(high, _mm256_set1_epi32(1025));
+        high = _mm256_srai_epi32::<22>(_mm256_add_epi32(high, _mm256_set1_epi32(1 << 21)));
+        high = _mm256_and_si256(high, _mm256_set1_epi32(15));
+
+        let mut low = _mm256_sub_epi32(a, _mm256_mullo_epi32(high, alpha));
+        let wrap = _mm256_srai_epi32::<31>(_mm256_sub_epi32(half_q, low));
+        low = _mm256_sub_epi32(low, _mm256_and_si256(wrap, q));
+
+        store(a1, i, low);
+        store(a0, i, high);
+    }
+}
+
+/// Whether any coefficient has absolute value of at least `b`, the loop of `poly_dilithium5::chknorm`
+#[target_feature(enable = "avx2")]
+pub fn chknorm(a: &[i32; N], b: i32) -> i32 {
+    let bound = _mm256_set1_epi32(b - 1);
+    let mut over = _mm256_setzero_si256();
+    for i in (0..N).step_by(8) {
+        let t = _mm256_abs_epi32(load(a, i));
+        over = _mm256_or_si256(over, _mm256_cmpgt_epi32(t, bound));
+    }
+    (_mm256_movemask_epi8(over) != 0) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fips202, ntt, reduce, rounding_dilithium5};
+
+    /// Deterministic coefficients in `[-bound, bound)`
+    fn coeffs(label: &[u8], bound: i32) -> [i32; N] {
+        let mut bytes = [0u8; 4 * N];
+        fips202::shake256(&mut bytes, 4 * N, label, label.len());
+        let mut a = [0i32; N];
+        for (c, chunk) in a.iter_mut().zip(bytes.chunks(4)) {
+            let r = u32::from_le_bytes(chunk.try_into().unwrap()) % (2 * bound as u32);
+            *c = (r as i64 - bound as i64) as i32;
+        }
+        a
+    }
+
+    #[test]
+    fn test_ntt_matches_scalar() {
+        if !available() {
+            return;
+        }
+        for label in [&b"ntt 0"[..], b"ntt 1", b"ntt 2"] {
+            let input = coeffs(label, Q);
+            let (mut scalar, mut simd) = (input, input);
+            ntt::ntt(&mut scalar);
+            // SAFETY: AVX2 support was just checked
+            unsafe { ntt(&mut simd) };
+            assert_eq!(scalar, simd);
+
+            let (mut scalar, mut simd) = (input, input);
+            ntt::invntt_tomont(&mut scalar);
+            // SAFETY: as above
+            unsafe { invntt_tomont(&mut simd) };
+            assert_eq!(scalar, simd);
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_matches_scalar() {
+        if !available() {
+            return;
+        }
+        let a = coeffs(b"pointwise a", 9 * Q);
+        let b = coeffs(b"pointwise b", 9 * Q);
+        let mut c = [0i32; N];
+        // SAFETY: AVX2 support was just checked
+        unsafe { pointwise_montgomery(&mut c, &a, &b) };
+        for i in 0..N {
+            assert_eq!(c[i], reduce::montgomery_reduce(a[i] as i64 * b[i] as i64));
+        }
+
+        let mut v = coeffs(b"reduce", i32::MAX - (1 << 22));
+        let expected = v.map(reduce::reduce32);
+        // SAFETY: as above
+        unsafe { reduce(&mut v) };
+        assert_eq!(v, expected);
+
+        let expected = v.map(reduce::caddq);
+        // SAFETY: as above
+        unsafe { caddq(&mut v) };
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn test_rounding_matches_scalar() {
+        if !available() {
+            return;
+        }
+        let mut a1 = coeffs(b"decompose", Q).map(reduce::caddq);
+        a1[0] = 0;
+        a1[1] = Q - 1;
+        a1[2] = (Q - 1) / 2;
+        let expected = a1.map(rounding_dilithium5::decompose);
+        let mut a0 = [0i32; N];
+        // SAFETY: AVX2 support was just checked
+        unsafe { decompose(&mut a1, &mut a0) };
+        for i in 0..N {
+            assert_eq!((a1[i], a0[i]), expected[i]);
+        }
+
+        let mut z = coeffs(b"chknorm", 999);
+        z[N - 1] = -1000;
+        // SAFETY: as above
+        unsafe {
+            assert_eq!(chknorm(&z, 1001), 0);
+            assert_eq!(chknorm(&z, 1000), 1);
+            z[N - 1] = 999;
+            assert_eq!(chknorm(&z, 1000), 0);
+        }
+    }
+}
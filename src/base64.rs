@@ -0,0 +1,207 @@
+// This is a feature-reduced implementation of Base64 (RFC 4648).
+// Created by the author to keep the crate dependency-free and only work with necessary functions.
+
+use core::fmt;
+use alloc::{string::String, vec::Vec};
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Error type for Base64 decoding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromBase64Error {
+    /// Invalid character encountered
+    InvalidBase64Character { c: char, index: usize },
+    /// Invalid string length or misplaced padding
+    InvalidStringLength,
+}
+
+impl fmt::Display for FromBase64Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromBase64Error::InvalidBase64Character { c, index } => {
+                write!(f, "Invalid character '{}' at position {}", c, index)
+            }
+            FromBase64Error::InvalidStringLength => write!(f, "Invalid string length"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromBase64Error {}
+
+/// Encode a slice of bytes as a padded Base64 string
+pub fn encode<T: AsRef<[u8]>>(data: T) -> String {
+    encode_with(data.as_ref(), ALPHABET, true)
+}
+
+/// Encode a slice of bytes as an unpadded Base64url string (RFC 4648 section 5)
+pub fn encode_url<T: AsRef<[u8]>>(data: T) -> String {
+    encode_with(data.as_ref(), URL_ALPHABET, false)
+}
+
+fn encode_with(bytes: &[u8], alphabet: &[u8; 64], pad: bool) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+        out.push(alphabet[(n >> 18) as usize & 63] as char);
+        out.push(alphabet[(n >> 12) as usize & 63] as char);
+        if chunk.len() > 1 {
+            out.push(alphabet[(n >> 6) as usize & 63] as char);
+        } else if pad {
+            out.push('=');
+        }
+        if chunk.len() > 2 {
+            out.push(alphabet[n as usize & 63] as char);
+        } else if pad {
+            out.push('=');
+        }
+    }
+
+    out
+}
+
+/// Decode a padded Base64 string into a vector of bytes
+pub fn decode<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromBase64Error> {
+    let data = data.as_ref();
+
+    if data.len() % 4 != 0 {
+        return Err(FromBase64Error::InvalidStringLength);
+    }
+
+    let mut bytes = Vec::with_capacity(data.len() / 4 * 3);
+
+    for (n, chunk) in data.chunks(4).enumerate() {
+        let last = (n + 1) * 4 == data.len();
+        let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+        if padding > 2 || (padding > 0 && !last) {
+            return Err(FromBase64Error::InvalidStringLength);
+        }
+
+        let mut acc = 0u32;
+        for (i, &c) in chunk[..4 - padding].iter().enumerate() {
+            acc |= (decode_sextet(c, n * 4 + i)? as u32) << (18 - 6 * i);
+        }
+
+        bytes.push((acc >> 16) as u8);
+        if padding < 2 {
+            bytes.push((acc >> 8) as u8);
+        }
+        if padding < 1 {
+            bytes.push(acc as u8);
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Decode an unpadded Base64url string into a vector of bytes
+///
+/// Padding characters and non-zero trailing bits are rejected, so every
+/// byte string has exactly one accepted encoding.
+pub fn decode_url<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromBase64Error> {
+    let data = data.as_ref();
+
+    if data.len() % 4 == 1 {
+        return Err(FromBase64Error::InvalidStringLength);
+    }
+
+    let mut bytes = Vec::with_capacity(data.len() * 3 / 4);
+
+    for (n, chunk) in data.chunks(4).enumerate() {
+        let mut acc = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            let sextet = match c {
+                b'-' => 62,
+                b'_' => 63,
+                b'+' | b'/' => {
+                    return Err(FromBase64Error::InvalidBase64Character {
+                        c: c as char,
+                        index: n * 4 + i,
+                    })
+                }
+                _ => decode_sextet(c, n * 4 + i)?,
+            };
+            acc |= (sextet as u32) << (18 - 6 * i);
+        }
+
+        let len = chunk.len() * 3 / 4;
+        if acc & (0xFFFFFF >> (8 * len)) != 0 {
+            return Err(FromBase64Error::InvalidStringLength);
+        }
+        bytes.extend_from_slice(&acc.to_be_bytes()[1..1 + len]);
+    }
+
+    Ok(bytes)
+}
+
+#[inline]
+fn decode_sextet(c: u8, index: usize) -> Result<u8, FromBase64Error> {
+    match c {
+        b'A'..=b'Z' => Ok(c - b'A'),
+        b'a'..=b'z' => Ok(c - b'a' + 26),
+        b'0'..=b'9' => Ok(c - b'0' + 52),
+        b'+' => Ok(62),
+        b'/' => Ok(63),
+        _ => Err(FromBase64Error::InvalidBase64Character {
+            c: c as char,
+            index,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode(b""), "");
+        assert_eq!(encode(b"f"), "Zg==");
+        assert_eq!(encode(b"fo"), "Zm8=");
+        assert_eq!(encode(b"foo"), "Zm9v");
+        assert_eq!(encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode("Zg==").unwrap(), b"f");
+        assert_eq!(decode("Zm8=").unwrap(), b"fo");
+        assert_eq!(decode("Zm9vYmFy").unwrap(), b"foobar");
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        assert!(matches!(
+            decode("Zm9").unwrap_err(),
+            FromBase64Error::InvalidStringLength
+        ));
+        assert!(matches!(
+            decode("Zg==Zm8=").unwrap_err(),
+            FromBase64Error::InvalidStringLength
+        ));
+    }
+
+    #[test]
+    fn test_url() {
+        assert_eq!(encode_url(b"f"), "Zg");
+        assert_eq!(encode_url(b"fo"), "Zm8");
+        assert_eq!(encode_url([0xfb, 0xff]), "-_8");
+        assert_eq!(decode_url("-_8").unwrap(), [0xfb, 0xff]);
+        assert_eq!(decode_url("Zm9vYmFy").unwrap(), b"foobar");
+        assert!(decode_url("Zg==").is_err());
+        assert!(decode_url("+/8").is_err());
+        assert!(decode_url("Zh").is_err());
+        assert!(decode_url("Zm9vY").is_err());
+    }
+
+    #[test]
+    fn test_invalid_character() {
+        assert!(matches!(
+            decode("Zm9*").unwrap_err(),
+            FromBase64Error::InvalidBase64Character { c: '*', index: 3 }
+        ));
+    }
+}
@@ -0,0 +1,732 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! `vaz256` command-line tool for key generation, signing and verification.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use vaz256::json::Value;
+use vaz256::keyfile::{EncryptedSecretKey, KdfParams, DEFAULT_MAX_MEMORY_KIB};
+use vaz256::manifest::{Manifest, SymlinkPolicy, WalkOptions};
+use vaz256::{base64, hex, inspect, keygen, sign, verify, PublicKey, SecretKey, Signature, VAZ256Error};
+use vaz256::{DILITHIUM5_PUBLIC_KEY_SIZE, SECRET_KEY_SIZE, SIGNATURE_SIZE};
+
+const USAGE: &str = "\
+Usage:
+  vaz256 keygen [-o PREFIX] [--passphrase-file PASS_FILE [--kdf-memory KIB]] [--force]
+      Writes PREFIX.key (secret, mode 0600) and PREFIX.pub; PREFIX defaults to `vaz256`.
+      With PASS_FILE, the secret key is encrypted under the passphrase it holds, using
+      KIB KiB of memory (default: 2048) to derive the encryption key
+  vaz256 sign -k KEY_FILE [--passphrase-file PASS_FILE] [-i FILE] [-o SIG_FILE] [-f hex|base64|raw]
+      Signs FILE (default: stdin) and writes the signature to SIG_FILE (default: stdout)
+  vaz256 verify -p PUB_FILE -s SIG_FILE [-i FILE]
+      Verifies the signature of FILE (default: stdin); the signature encoding is detected
+  vaz256 manifest sign -k KEY_FILE [--passphrase-file PASS_FILE] [-o MANIFEST]
+                       [-f hex|base64|raw] [--symlinks POLICY] DIR
+      Writes the SHAKE256 digests of all files below DIR to MANIFEST (default: DIR/VAZ256SUMS)
+      and its signature to MANIFEST.sig
+  vaz256 manifest verify -p PUB_FILE [-m MANIFEST] [-s SIG_FILE] [--symlinks POLICY] DIR
+      Verifies MANIFEST against SIG_FILE (default: MANIFEST.sig), then reports modified,
+      missing and extra files below DIR
+      POLICY is `reject` (default), `skip` or `follow`
+  vaz256 inspect [-t signature|public|secret] [-p PUB_FILE] [--json] FILE
+      Dissects a signature or key in raw, hex or base64 form; the type is inferred from
+      its length, 32-byte `.key` files being secret keys. With PUB_FILE, reports the error
+      verifying a signature against that key would fail with regardless of the message
+
+Exit codes:
+  0  success
+  1  bad signature
+  2  usage error
+  3  signature made by a different key
+  4  malformed key or signature
+  5  I/O error
+  6  directory does not match the manifest
+  7  wrong passphrase or corrupted key file
+  8  internal failure: no randomness, failed self-test or out of memory";
+
+/// Failure of a command, mapped to a distinct exit code
+#[derive(Debug)]
+enum CliError {
+    Usage(String),
+    Io(PathBuf, io::Error),
+    Crypto(VAZ256Error),
+    ManifestMismatch,
+}
+
+impl CliError {
+    fn exit_code(&self) -> u8 {
+        match self {
+            CliError::Crypto(VAZ256Error::VerificationFailed) => 1,
+            CliError::Usage(_) => 2,
+            CliError::Crypto(VAZ256Error::PublicKeyMismatch) => 3,
+            CliError::Crypto(
+                VAZ256Error::DeserializationError
+                | VAZ256Error::InvalidLength
+                | VAZ256Error::HexDecodingError
+                | VAZ256Error::ChainValidationFailed
+                | VAZ256Error::ClaimsValidationFailed,
+            ) => 4,
+            CliError::Io(..) => 5,
+            CliError::ManifestMismatch => 6,
+            CliError::Crypto(VAZ256Error::DecryptionFailed) => 7,
+            CliError::Crypto(
+                VAZ256Error::KeyGenerationFailed
+                | VAZ256Error::SigningFailed
+                | VAZ256Error::EntropyUnavailable
+                | VAZ256Error::SelfTestFailed
+                | VAZ256Error::OutOfMemory,
+            ) => 8,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}\n\n{}", message, USAGE),
+            CliError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            CliError::Crypto(VAZ256Error::VerificationFailed) => write!(f, "bad signature"),
+            CliError::Crypto(VAZ256Error::PublicKeyMismatch) => write!(f, "signature was made by a different key"),
+            CliError::Crypto(VAZ256Error::DecryptionFailed) => write!(f, "wrong passphrase or corrupted key file"),
+            CliError::Crypto(VAZ256Error::KeyGenerationFailed) => {
+                write!(f, "internal error: generated key failed its consistency check")
+            }
+            CliError::Crypto(VAZ256Error::SigningFailed) => write!(f, "internal error: signing failed"),
+            CliError::Crypto(VAZ256Error::EntropyUnavailable) => {
+                write!(f, "internal error: the operating system's random number generator is unavailable")
+            }
+            CliError::Crypto(VAZ256Error::SelfTestFailed) => write!(f, "internal error: cryptographic self-test failed"),
+            CliError::Crypto(VAZ256Error::OutOfMemory) => write!(f, "out of memory"),
+            CliError::Crypto(e) => write!(f, "malformed input: {:?}", e),
+            CliError::ManifestMismatch => write!(f, "directory does not match the manifest"),
+        }
+    }
+}
+
+impl From<VAZ256Error> for CliError {
+    fn from(e: VAZ256Error) -> Self {
+        CliError::Crypto(e)
+    }
+}
+
+type CliResult<T> = Result<T, CliError>;
+
+/// Default manifest file name inside the signed directory
+const MANIFEST_NAME: &str = "VAZ256SUMS";
+
+/// Output encoding of signatures
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Format {
+    Hex,
+    Base64,
+    Raw,
+}
+
+/// Parsed `-x VALUE` options, `--flag` switches and operands of a subcommand
+struct Options {
+    values: Vec<(String, String)>,
+    flags: Vec<String>,
+    operands: Vec<String>,
+}
+
+impl Options {
+    /// Parses `args` against the accepted value options and flags, allowing
+    /// at most `max_operands` operands
+    fn parse(args: &[String], values: &[&str], flags: &[&str], max_operands: usize) -> CliResult<Self> {
+        let mut options = Options { values: Vec::new(), flags: Vec::new(), operands: Vec::new() };
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if values.contains(&arg.as_str()) {
+                let value = args.next().ok_or_else(|| CliError::Usage(format!("{} requires a value", arg)))?;
+                if options.value(arg).is_some() {
+                    return Err(CliError::Usage(format!("{} given more than once", arg)));
+                }
+                options.values.push((arg.clone(), value.clone()));
+            } else if flags.contains(&arg.as_str()) {
+                options.flags.push(arg.clone());
+            } else if !arg.starts_with('-') && options.operands.len() < max_operands {
+                options.operands.push(arg.clone());
+            } else {
+                return Err(CliError::Usage(format!("unexpected argument `{}`", arg)));
+            }
+        }
+        Ok(options)
+    }
+
+    fn value(&self, name: &str) -> Option<&str> {
+        self.values.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+    }
+
+    fn required(&self, name: &str) -> CliResult<&str> {
+        self.value(name).ok_or_else(|| CliError::Usage(format!("missing {}", name)))
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|f| f == name)
+    }
+
+    fn operand(&self, name: &str) -> CliResult<&str> {
+        self.operands.first().map(String::as_str).ok_or_else(|| CliError::Usage(format!("missing {}", name)))
+    }
+}
+
+fn read_file(path: &Path) -> CliResult<Vec<u8>> {
+    fs::read(path).map_err(|e| CliError::Io(path.to_path_buf(), e))
+}
+
+fn read_text(path: &Path) -> CliResult<String> {
+    fs::read_to_string(path).map_err(|e| CliError::Io(path.to_path_buf(), e))
+}
+
+/// Reads FILE, or stdin when it is absent or `-`
+fn read_input(path: Option<&str>) -> CliResult<Vec<u8>> {
+    match path {
+        Some(path) if path != "-" => read_file(Path::new(path)),
+        _ => {
+            let mut data = Vec::new();
+            io::stdin()
+                .read_to_end(&mut data)
+                .map_err(|e| CliError::Io(PathBuf::from("<stdin>"), e))?;
+            Ok(data)
+        }
+    }
+}
+
+/// Writes to SIG_FILE, or stdout when it is absent or `-`
+fn write_output(path: Option<&str>, data: &[u8]) -> CliResult<()> {
+    match path {
+        Some(path) if path != "-" => fs::write(path, data).map_err(|e| CliError::Io(PathBuf::from(path), e)),
+        _ => {
+            let mut stdout = io::stdout();
+            stdout
+                .write_all(data)
+                .and_then(|_| stdout.flush())
+                .map_err(|e| CliError::Io(PathBuf::from("<stdout>"), e))
+        }
+    }
+}
+
+/// Writes a new key file, refusing to replace an existing one unless `force` is set;
+/// `private` files are made readable only by their owner on Unix, including replaced ones
+/// Fails with an I/O error if `path` exists, so that a command writing several
+/// files can check all of them before creating any
+fn check_absent(path: &Path) -> CliResult<()> {
+    match fs::symlink_metadata(path) {
+        Ok(_) => Err(CliError::Io(path.to_path_buf(), io::Error::from(io::ErrorKind::AlreadyExists))),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(CliError::Io(path.to_path_buf(), e)),
+    }
+}
+
+fn write_key_file(path: &Path, data: &[u8], force: bool, private: bool) -> CliResult<()> {
+    let mut options = OpenOptions::new();
+    options.write(true);
+    if force {
+        // Truncated only once the permissions are restricted
+        options.create(true).truncate(false);
+    } else {
+        options.create_new(true);
+    }
+    #[cfg(unix)]
+    if private {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options
+        .open(path)
+        .and_then(|mut file| {
+            // The mode above only applies to new files, an existing one keeps its own
+            #[cfg(unix)]
+            if private {
+                use std::os::unix::fs::PermissionsExt;
+                file.set_permissions(fs::Permissions::from_mode(0o600))?;
+            }
+            #[cfg(not(unix))]
+            let _ = private;
+            file.set_len(0)?;
+            file.write_all(data)
+        })
+        .map_err(|e| CliError::Io(path.to_path_buf(), e))
+}
+
+fn encode_signature(signature: &Signature, format: Format) -> Vec<u8> {
+    match format {
+        Format::Hex => format!("{}\n", signature.to_hex()).into_bytes(),
+        Format::Base64 => format!("{}\n", base64::encode(signature.to_bytes())).into_bytes(),
+        Format::Raw => signature.to_bytes().to_vec(),
+    }
+}
+
+/// Decodes a raw, hex or base64 signature
+fn decode_signature(data: &[u8]) -> CliResult<Signature> {
+    if data.len() == SIGNATURE_SIZE {
+        return Ok(Signature::from_bytes(data)?);
+    }
+    let text = std::str::from_utf8(data).map_err(|_| VAZ256Error::DeserializationError)?.trim();
+    let bytes = if text.len() == 2 * SIGNATURE_SIZE {
+        hex::decode(text).map_err(|_| VAZ256Error::HexDecodingError)?
+    } else {
+        base64::decode(text).map_err(|_| VAZ256Error::DeserializationError)?
+    };
+    Ok(Signature::from_bytes(&bytes)?)
+}
+
+fn parse_format(value: Option<&str>) -> CliResult<Format> {
+    match value.unwrap_or("hex") {
+        "hex" => Ok(Format::Hex),
+        "base64" => Ok(Format::Base64),
+        "raw" => Ok(Format::Raw),
+        other => Err(CliError::Usage(format!("unknown format `{}`", other))),
+    }
+}
+
+fn parse_symlinks(value: Option<&str>) -> CliResult<SymlinkPolicy> {
+    match value.unwrap_or("reject") {
+        "reject" => Ok(SymlinkPolicy::Reject),
+        "skip" => Ok(SymlinkPolicy::Skip),
+        "follow" => Ok(SymlinkPolicy::Follow),
+        other => Err(CliError::Usage(format!("unknown symlink policy `{}`", other))),
+    }
+}
+
+/// Returns `path` relative to `dir` when it lies inside it, so the manifest
+/// and its signature are not listed in themselves
+fn relative_to(dir: &Path, path: &Path) -> Option<String> {
+    let dir = fs::canonicalize(dir).ok()?;
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let path = fs::canonicalize(parent).ok()?.join(path.file_name()?);
+    let relative = path.strip_prefix(&dir).ok()?;
+    let components: Option<Vec<&str>> = relative.components().map(|c| c.as_os_str().to_str()).collect();
+    Some(components?.join("/"))
+}
+
+/// Manifest and signature paths, and the walk options excluding both
+fn manifest_paths(
+    dir: &Path,
+    manifest: Option<&str>,
+    signature: Option<&str>,
+    symlinks: Option<&str>,
+) -> CliResult<(PathBuf, PathBuf, WalkOptions)> {
+    let manifest = manifest.map(PathBuf::from).unwrap_or_else(|| dir.join(MANIFEST_NAME));
+    let signature = signature.map(PathBuf::from).unwrap_or_else(|| {
+        let mut name = manifest.clone().into_os_string();
+        name.push(".sig");
+        PathBuf::from(name)
+    });
+    let options = WalkOptions {
+        symlinks: parse_symlinks(symlinks)?,
+        exclude: [&manifest, &signature].iter().filter_map(|path| relative_to(dir, path)).collect(),
+    };
+    Ok((manifest, signature, options))
+}
+
+fn cmd_manifest_sign(args: &[String]) -> CliResult<()> {
+    let options = Options::parse(args, &["-k", "-o", "-f", "--symlinks", "--passphrase-file"], &[], 1)?;
+    let dir = Path::new(options.operand("DIR")?);
+    let format = parse_format(options.value("-f"))?;
+    let secret = read_secret_key(&options)?;
+    let (manifest_path, signature_path, walk) =
+        manifest_paths(dir, options.value("-o"), None, options.value("--symlinks"))?;
+
+    let manifest = Manifest::from_dir(dir, &walk).map_err(|e| CliError::Io(dir.to_path_buf(), e))?;
+    let text = manifest.to_string();
+    let signature = sign(text.as_bytes(), &secret)?;
+    fs::write(&manifest_path, &text).map_err(|e| CliError::Io(manifest_path.clone(), e))?;
+    fs::write(&signature_path, encode_signature(&signature, format))
+        .map_err(|e| CliError::Io(signature_path.clone(), e))?;
+    eprintln!("Signed {} files in {}", manifest.len(), manifest_path.display());
+    Ok(())
+}
+
+fn cmd_manifest_verify(args: &[String]) -> CliResult<()> {
+    let options = Options::parse(args, &["-p", "-m", "-s", "--symlinks"], &[], 1)?;
+    let dir = Path::new(options.operand("DIR")?);
+    let public = PublicKey::from_hex(read_text(Path::new(options.required("-p")?))?.trim())?;
+    let (manifest_path, signature_path, walk) =
+        manifest_paths(dir, options.value("-m"), options.value("-s"), options.value("--symlinks"))?;
+
+    let signature = decode_signature(&read_file(&signature_path)?)?;
+    let manifest = Manifest::verify_signed(&read_file(&manifest_path)?, &signature, &public)?;
+    let report = manifest.compare_dir(dir, &walk).map_err(|e| CliError::Io(dir.to_path_buf(), e))?;
+    for (status, paths) in [("MODIFIED", &report.modified), ("MISSING", &report.missing), ("EXTRA", &report.extra)] {
+        for path in paths {
+            println!("{} {}", status, path);
+        }
+    }
+    if !report.is_clean() {
+        return Err(CliError::ManifestMismatch);
+    }
+    eprintln!("Manifest OK: {} files", manifest.len());
+    Ok(())
+}
+
+fn cmd_manifest(args: &[String]) -> CliResult<()> {
+    match args.split_first() {
+        Some((command, rest)) if command == "sign" => cmd_manifest_sign(rest),
+        Some((command, rest)) if command == "verify" => cmd_manifest_verify(rest),
+        Some((command, _)) => Err(CliError::Usage(format!("unknown manifest command `{}`", command))),
+        None => Err(CliError::Usage("missing manifest command".to_string())),
+    }
+}
+
+/// Decodes a hex, base64 or raw blob
+fn decode_blob(data: &[u8]) -> Vec<u8> {
+    if let Ok(text) = std::str::from_utf8(data) {
+        let text = text.trim();
+        if let Ok(bytes) = hex::decode(text) {
+            return bytes;
+        }
+        if let Ok(bytes) = base64::decode(text) {
+            return bytes;
+        }
+    }
+    data.to_vec()
+}
+
+/// Prints a report as `name: value` lines
+fn print_report(report: &Value) {
+    for (name, value) in report.as_object().unwrap_or_default() {
+        match value {
+            Value::String(text) => println!("{}: {}", name, text),
+            _ => println!("{}: {}", name, value),
+        }
+    }
+}
+
+fn cmd_inspect(args: &[String]) -> CliResult<()> {
+    let options = Options::parse(args, &["-t", "-p"], &["--json"], 1)?;
+    let path = options.operand("FILE")?;
+    let bytes = decode_blob(&read_input(Some(path))?);
+    let kind = match options.value("-t") {
+        Some(kind) => kind,
+        None if bytes.len() == SIGNATURE_SIZE => "signature",
+        None if bytes.len() == SECRET_KEY_SIZE && path.ends_with(".key") => "secret",
+        None if bytes.len() == SECRET_KEY_SIZE || bytes.len() == DILITHIUM5_PUBLIC_KEY_SIZE => "public",
+        None => return Err(CliError::Crypto(VAZ256Error::InvalidLength)),
+    };
+
+    let report = match kind {
+        "signature" => {
+            let report = inspect::signature(&bytes)?;
+            let mut json = report.to_json();
+            if let (Some(public), Value::Object(members)) = (options.value("-p"), &mut json) {
+                let public = PublicKey::from_hex(read_text(Path::new(public))?.trim())?;
+                let error = report.error_for(&public).map_or(Value::Null, |e| Value::from(format!("{:?}", e)));
+                members.retain(|(name, _)| name != "error");
+                members.push(("error".to_string(), error));
+            }
+            json
+        }
+        "public" => inspect::public_key(&bytes)?.to_json(),
+        "secret" => inspect::secret_key(&SecretKey::from_bytes(&bytes)?).to_json(),
+        other => return Err(CliError::Usage(format!("unknown type `{}`", other))),
+    };
+    if options.flag("--json") {
+        println!("{}", report);
+    } else {
+        print_report(&report);
+    }
+    Ok(())
+}
+
+/// Reads a passphrase, dropping the line ending after it
+fn read_passphrase(path: &str) -> CliResult<Vec<u8>> {
+    let mut passphrase = read_file(Path::new(path))?;
+    if passphrase.last() == Some(&b'\n') {
+        passphrase.pop();
+        if passphrase.last() == Some(&b'\r') {
+            passphrase.pop();
+        }
+    }
+    Ok(passphrase)
+}
+
+/// Reads the `-k` secret key, decrypting it with `--passphrase-file` if it is sealed
+fn read_secret_key(options: &Options) -> CliResult<SecretKey> {
+    let text = read_text(Path::new(options.required("-k")?))?;
+    if !text.trim_start().starts_with("-----BEGIN") {
+        return Ok(SecretKey::from_hex(text.trim())?);
+    }
+    let sealed = EncryptedSecretKey::from_pem(&text)?;
+    let path = options.required("--passphrase-file")?;
+    Ok(sealed.decrypt(&read_passphrase(path)?)?)
+}
+
+fn cmd_keygen(args: &[String]) -> CliResult<()> {
+    let options = Options::parse(args, &["-o", "--passphrase-file", "--kdf-memory"], &["--force"], 0)?;
+    let prefix = options.value("-o").unwrap_or("vaz256");
+    let force = options.flag("--force");
+    let secret_path = PathBuf::from(format!("{}.key", prefix));
+    let public_path = PathBuf::from(format!("{}.pub", prefix));
+    if !force {
+        check_absent(&secret_path)?;
+        check_absent(&public_path)?;
+    }
+
+    let (secret, public) = keygen()?;
+    let secret_file = match options.value("--passphrase-file") {
+        Some(path) => {
+            let mut params = KdfParams::default();
+            if let Some(memory) = options.value("--kdf-memory") {
+                params.memory_kib = memory
+                    .parse()
+                    .ok()
+                    .filter(|&kib| kib <= DEFAULT_MAX_MEMORY_KIB)
+                    .ok_or_else(|| CliError::Usage(format!("invalid KDF memory `{}`", memory)))?;
+            }
+            EncryptedSecretKey::encrypt(&secret, &read_passphrase(path)?, params)?.to_pem()
+        }
+        None => format!("{}\n", secret.to_hex()),
+    };
+    write_key_file(&secret_path, secret_file.as_bytes(), force, true)?;
+    write_key_file(&public_path, format!("{}\n", public.to_hex()).as_bytes(), force, false)?;
+    eprintln!("Wrote {} and {}", secret_path.display(), public_path.display());
+    Ok(())
+}
+
+fn cmd_sign(args: &[String]) -> CliResult<()> {
+    let options = Options::parse(args, &["-k", "-i", "-o", "-f", "--passphrase-file"], &[], 0)?;
+    let format = parse_format(options.value("-f"))?;
+    let secret = read_secret_key(&options)?;
+    let message = read_input(options.value("-i"))?;
+    let signature = sign(&message, &secret)?;
+    write_output(options.value("-o"), &encode_signature(&signature, format))
+}
+
+fn cmd_verify(args: &[String]) -> CliResult<()> {
+    let options = Options::parse(args, &["-p", "-s", "-i"], &[], 0)?;
+    let public = PublicKey::from_hex(read_text(Path::new(options.required("-p")?))?.trim())?;
+    let signature = decode_signature(&read_file(Path::new(options.required("-s")?))?)?;
+    let message = read_input(options.value("-i"))?;
+    verify(&message, &signature, &public)?;
+    eprintln!("Signature OK");
+    Ok(())
+}
+
+fn run(args: &[String]) -> CliResult<()> {
+    match args.split_first() {
+        Some((command, rest)) => match command.as_str() {
+            "keygen" => cmd_keygen(rest),
+            "sign" => cmd_sign(rest),
+            "verify" => cmd_verify(rest),
+            "manifest" => cmd_manifest(rest),
+            "inspect" => cmd_inspect(rest),
+            "help" | "-h" | "--help" => {
+                println!("{}", USAGE);
+                Ok(())
+            }
+            "--version" => {
+                println!("vaz256 {}", env!("CARGO_PKG_VERSION"));
+                Ok(())
+            }
+            other => Err(CliError::Usage(format!("unknown command `{}`", other))),
+        },
+        None => Err(CliError::Usage("missing command".to_string())),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("vaz256: {}", e);
+            ExitCode::from(e.exit_code())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("vaz256-cli-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_options() {
+        let options = Options::parse(&args(&["-o", "out", "--force"]), &["-o"], &["--force"], 0).unwrap();
+        assert_eq!(options.value("-o"), Some("out"));
+        assert!(options.flag("--force"));
+        assert!(Options::parse(&args(&["-o"]), &["-o"], &[], 0).is_err());
+        assert!(Options::parse(&args(&["-x"]), &["-o"], &[], 0).is_err());
+        assert!(Options::parse(&args(&["-o", "a", "-o", "b"]), &["-o"], &[], 0).is_err());
+
+        let options = Options::parse(&args(&["-o", "out", "dist"]), &["-o"], &[], 1).unwrap();
+        assert_eq!(options.operand("DIR").unwrap(), "dist");
+        assert!(Options::parse(&args(&["dist"]), &[], &[], 0).is_err());
+        assert!(Options::parse(&args(&["dist", "more"]), &[], &[], 1).is_err());
+        assert!(Options::parse(&args(&[]), &[], &[], 1).unwrap().operand("DIR").is_err());
+    }
+
+    #[test]
+    fn test_keygen_sign_verify() {
+        let dir = temp_dir("round-trip");
+        let prefix = dir.join("id");
+        let prefix = prefix.to_str().unwrap();
+        let file = dir.join("message.txt");
+        fs::write(&file, b"release contents").unwrap();
+        let (key, public, file) = (format!("{}.key", prefix), format!("{}.pub", prefix), file.to_str().unwrap());
+
+        run(&args(&["keygen", "-o", prefix])).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&key).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+        assert_eq!(run(&args(&["keygen", "-o", prefix])).unwrap_err().exit_code(), 5);
+        // An existing public key stops keygen before it writes the secret key
+        let orphan = dir.join("orphan");
+        fs::write(dir.join("orphan.pub"), b"existing").unwrap();
+        assert_eq!(run(&args(&["keygen", "-o", orphan.to_str().unwrap()])).unwrap_err().exit_code(), 5);
+        assert!(!dir.join("orphan.key").exists());
+        #[cfg(unix)]
+        {
+            // Replacing a world-readable file still leaves the key private
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&key, fs::Permissions::from_mode(0o644)).unwrap();
+            fs::write(&key, "a longer file than the key that replaces it".repeat(10)).unwrap();
+            run(&args(&["keygen", "-o", prefix, "--force"])).unwrap();
+            assert_eq!(fs::metadata(&key).unwrap().permissions().mode() & 0o777, 0o600);
+            assert!(SecretKey::from_hex(fs::read_to_string(&key).unwrap().trim()).is_ok());
+        }
+
+        for format in ["hex", "base64", "raw"] {
+            let sig = dir.join(format!("message.{}", format));
+            let sig = sig.to_str().unwrap();
+            run(&args(&["sign", "-k", &key, "-i", file, "-o", sig, "-f", format])).unwrap();
+            run(&args(&["verify", "-p", &public, "-s", sig, "-i", file])).unwrap();
+        }
+        let sig = dir.join("message.hex");
+        let sig = sig.to_str().unwrap();
+
+        fs::write(dir.join("other.txt"), b"tampered").unwrap();
+        let other = dir.join("other.txt");
+        let err = run(&args(&["verify", "-p", &public, "-s", sig, "-i", other.to_str().unwrap()])).unwrap_err();
+        assert_eq!(err.exit_code(), 1);
+
+        let other_prefix = dir.join("other");
+        run(&args(&["keygen", "-o", other_prefix.to_str().unwrap()])).unwrap();
+        let other_public = format!("{}.pub", other_prefix.to_str().unwrap());
+        let err = run(&args(&["verify", "-p", &other_public, "-s", sig, "-i", file])).unwrap_err();
+        assert_eq!(err.exit_code(), 3);
+
+        fs::write(dir.join("bad.sig"), b"not a signature").unwrap();
+        let bad = dir.join("bad.sig");
+        let err = run(&args(&["verify", "-p", &public, "-s", bad.to_str().unwrap(), "-i", file])).unwrap_err();
+        assert_eq!(err.exit_code(), 4);
+
+        assert_eq!(run(&args(&["frobnicate"])).unwrap_err().exit_code(), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_manifest_sign_verify() {
+        let dir = temp_dir("manifest");
+        let prefix = dir.join("release");
+        let prefix = prefix.to_str().unwrap();
+        let (key, public) = (format!("{}.key", prefix), format!("{}.pub", prefix));
+        run(&args(&["keygen", "-o", prefix])).unwrap();
+
+        let release = dir.join("dist");
+        fs::create_dir_all(release.join("docs")).unwrap();
+        fs::write(release.join("vaz256.tar.gz"), b"archive").unwrap();
+        fs::write(release.join("docs/README"), b"readme").unwrap();
+        let release = release.to_str().unwrap();
+
+        run(&args(&["manifest", "sign", "-k", &key, release])).unwrap();
+        let manifest = fs::read_to_string(dir.join("dist/VAZ256SUMS")).unwrap();
+        assert_eq!(manifest.lines().count(), 2);
+        run(&args(&["manifest", "verify", "-p", &public, release])).unwrap();
+
+        fs::write(dir.join("dist/docs/README"), b"changed").unwrap();
+        let err = run(&args(&["manifest", "verify", "-p", &public, release])).unwrap_err();
+        assert_eq!(err.exit_code(), 6);
+
+        fs::write(dir.join("dist/VAZ256SUMS"), manifest.replace("docs/README", "docs/README.md")).unwrap();
+        let err = run(&args(&["manifest", "verify", "-p", &public, release])).unwrap_err();
+        assert_eq!(err.exit_code(), 1);
+
+        assert_eq!(run(&args(&["manifest", "sign", "-k", &key])).unwrap_err().exit_code(), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_inspect() {
+        assert_eq!(decode_blob(b"0a0b\n"), [10, 11]);
+        assert_eq!(decode_blob(b"CgsM"), [10, 11, 12]);
+        assert_eq!(decode_blob(&[0xff, 0x00]), [0xff, 0x00]);
+
+        let dir = temp_dir("inspect");
+        let prefix = dir.join("id");
+        let prefix = prefix.to_str().unwrap();
+        let (key, public) = (format!("{}.key", prefix), format!("{}.pub", prefix));
+        let sig = dir.join("message.b64");
+        let sig = sig.to_str().unwrap();
+        fs::write(dir.join("message"), b"message").unwrap();
+        let message = dir.join("message");
+        run(&args(&["keygen", "-o", prefix])).unwrap();
+        run(&args(&["sign", "-k", &key, "-i", message.to_str().unwrap(), "-o", sig, "-f", "base64"])).unwrap();
+
+        run(&args(&["inspect", "--json", "-p", &public, sig])).unwrap();
+        run(&args(&["inspect", &key])).unwrap();
+        run(&args(&["inspect", &public])).unwrap();
+        assert_eq!(run(&args(&["inspect", "-t", "signature", &public])).unwrap_err().exit_code(), 4);
+        assert_eq!(run(&args(&["inspect", "-t", "bogus", &public])).unwrap_err().exit_code(), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_encrypted_key() {
+        let dir = temp_dir("encrypted");
+        let prefix = dir.join("id");
+        let prefix = prefix.to_str().unwrap();
+        let (key, public) = (format!("{}.key", prefix), format!("{}.pub", prefix));
+        let (pass, wrong) = (dir.join("pass"), dir.join("wrong"));
+        fs::write(&pass, b"correct horse\n").unwrap();
+        fs::write(&wrong, b"wrong horse\n").unwrap();
+        let (pass, wrong) = (pass.to_str().unwrap(), wrong.to_str().unwrap());
+        let message = dir.join("message");
+        fs::write(&message, b"message").unwrap();
+        let message = message.to_str().unwrap();
+        let sig = dir.join("message.sig");
+        let sig = sig.to_str().unwrap();
+
+        run(&args(&["keygen", "-o", prefix, "--passphrase-file", pass, "--kdf-memory", "8"])).unwrap();
+        assert!(fs::read_to_string(&key).unwrap().starts_with("-----BEGIN VAZ256 ENCRYPTED SECRET KEY-----"));
+        assert_eq!(read_passphrase(pass).unwrap(), b"correct horse");
+
+        run(&args(&["sign", "-k", &key, "--passphrase-file", pass, "-i", message, "-o", sig])).unwrap();
+        run(&args(&["verify", "-p", &public, "-s", sig, "-i", message])).unwrap();
+        let err = run(&args(&["sign", "-k", &key, "--passphrase-file", wrong, "-i", message])).unwrap_err();
+        assert_eq!(err.exit_code(), 7);
+        assert_eq!(run(&args(&["sign", "-k", &key, "-i", message])).unwrap_err().exit_code(), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,337 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Deterministic CBOR (RFC 8949 section 4.2) encoding and decoding.
+//!
+//! The encoder always emits the shortest argument form, definite lengths and
+//! map entries sorted by their encoded keys. The decoder rejects
+//! non-shortest arguments, indefinite lengths, duplicate map keys and
+//! floating point values. Only the simple values `false`, `true` and `null`
+//! are supported.
+
+use alloc::{boxed::Box, string::{String, ToString}, vec::Vec};
+
+use crate::{VAZ256Error, VAZ256Result};
+
+const MAX_DEPTH: usize = 64;
+
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+const MAJOR_SIMPLE: u8 = 7;
+
+const SIMPLE_FALSE: u8 = 20;
+const SIMPLE_TRUE: u8 = 21;
+const SIMPLE_NULL: u8 = 22;
+
+/// CBOR data item
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// Integer in the range `-2^64..2^64`
+    Integer(i128),
+    Bytes(Vec<u8>),
+    Text(String),
+    Array(Vec<Value>),
+    Map(Vec<(Value, Value)>),
+    Tag(u64, Box<Value>),
+    Bool(bool),
+    Null,
+}
+
+impl Value {
+    /// Returns the deterministic encoding of the item
+    ///
+    /// # Panics
+    /// If an integer lies outside `-2^64..2^64`.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode_into(&mut out);
+        out
+    }
+
+    fn encode_into(&self, out: &mut Vec<u8>) {
+        match self {
+            Value::Integer(n) if *n >= 0 => {
+                write_head(out, MAJOR_UNSIGNED, u64::try_from(*n).expect("CBOR integer out of range"))
+            }
+            Value::Integer(n) => {
+                write_head(out, MAJOR_NEGATIVE, u64::try_from(-1 - *n).expect("CBOR integer out of range"))
+            }
+            Value::Bytes(bytes) => {
+                write_head(out, MAJOR_BYTES, bytes.len() as u64);
+                out.extend_from_slice(bytes);
+            }
+            Value::Text(text) => {
+                write_head(out, MAJOR_TEXT, text.len() as u64);
+                out.extend_from_slice(text.as_bytes());
+            }
+            Value::Array(items) => {
+                write_head(out, MAJOR_ARRAY, items.len() as u64);
+                for item in items {
+                    item.encode_into(out);
+                }
+            }
+            Value::Map(entries) => {
+                let mut encoded: Vec<(Vec<u8>, Vec<u8>)> =
+                    entries.iter().map(|(k, v)| (k.to_vec(), v.to_vec())).collect();
+                encoded.sort();
+                write_head(out, MAJOR_MAP, encoded.len() as u64);
+                for (key, value) in encoded {
+                    out.extend_from_slice(&key);
+                    out.extend_from_slice(&value);
+                }
+            }
+            Value::Tag(tag, item) => {
+                write_head(out, MAJOR_TAG, *tag);
+                item.encode_into(out);
+            }
+            Value::Bool(false) => out.push(MAJOR_SIMPLE << 5 | SIMPLE_FALSE),
+            Value::Bool(true) => out.push(MAJOR_SIMPLE << 5 | SIMPLE_TRUE),
+            Value::Null => out.push(MAJOR_SIMPLE << 5 | SIMPLE_NULL),
+        }
+    }
+
+    /// Decodes a single data item spanning all of `data`
+    pub fn from_slice(data: &[u8]) -> VAZ256Result<Value> {
+        let mut decoder = Decoder { data, pos: 0 };
+        let value = decoder.item(0)?;
+        if decoder.pos != data.len() {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        Ok(value)
+    }
+
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Value::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Value::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Value::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn as_map(&self) -> Option<&[(Value, Value)]> {
+        match self {
+            Value::Map(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    /// Returns the value stored under `key` in a map
+    pub fn get(&self, key: &Value) -> Option<&Value> {
+        self.as_map()?.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(n: i64) -> Self {
+        Value::Integer(n as i128)
+    }
+}
+
+impl From<&[u8]> for Value {
+    fn from(bytes: &[u8]) -> Self {
+        Value::Bytes(bytes.to_vec())
+    }
+}
+
+impl From<&str> for Value {
+    fn from(text: &str) -> Self {
+        Value::Text(text.to_string())
+    }
+}
+
+fn write_head(out: &mut Vec<u8>, major: u8, arg: u64) {
+    let major = major << 5;
+    match arg {
+        0..=23 => out.push(major | arg as u8),
+        24..=0xFF => out.extend_from_slice(&[major | 24, arg as u8]),
+        0x100..=0xFFFF => {
+            out.push(major | 25);
+            out.extend_from_slice(&(arg as u16).to_be_bytes());
+        }
+        0x1_0000..=0xFFFF_FFFF => {
+            out.push(major | 26);
+            out.extend_from_slice(&(arg as u32).to_be_bytes());
+        }
+        _ => {
+            out.push(major | 27);
+            out.extend_from_slice(&arg.to_be_bytes());
+        }
+    }
+}
+
+struct Decoder<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn take(&mut self, len: usize) -> VAZ256Result<&'a [u8]> {
+        let end = self.pos.checked_add(len).ok_or(VAZ256Error::DeserializationError)?;
+        let bytes = self.data.get(self.pos..end).ok_or(VAZ256Error::DeserializationError)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn head(&mut self) -> VAZ256Result<(u8, u8, u64)> {
+        let initial = self.take(1)?[0];
+        let (major, info) = (initial >> 5, initial & 0x1F);
+        let (arg, min) = match info {
+            0..=23 => return Ok((major, info, info as u64)),
+            24 => (self.take(1)?[0] as u64, 24),
+            25 => (u16::from_be_bytes(self.take(2)?.try_into().unwrap()) as u64, 0x100),
+            26 => (u32::from_be_bytes(self.take(4)?.try_into().unwrap()) as u64, 0x1_0000),
+            27 => (u64::from_be_bytes(self.take(8)?.try_into().unwrap()), 0x1_0000_0000),
+            // Reserved values and indefinite lengths
+            _ => return Err(VAZ256Error::DeserializationError),
+        };
+        if arg < min {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        Ok((major, info, arg))
+    }
+
+    fn length(&self, arg: u64) -> VAZ256Result<usize> {
+        // Every element takes at least one byte, which bounds allocations
+        match usize::try_from(arg) {
+            Ok(len) if len <= self.data.len() - self.pos => Ok(len),
+            _ => Err(VAZ256Error::DeserializationError),
+        }
+    }
+
+    fn item(&mut self, depth: usize) -> VAZ256Result<Value> {
+        if depth > MAX_DEPTH {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let (major, info, arg) = self.head()?;
+        match major {
+            MAJOR_UNSIGNED => Ok(Value::Integer(arg as i128)),
+            MAJOR_NEGATIVE => Ok(Value::Integer(-1 - arg as i128)),
+            MAJOR_BYTES => {
+                let len = self.length(arg)?;
+                Ok(Value::Bytes(self.take(len)?.to_vec()))
+            }
+            MAJOR_TEXT => {
+                let len = self.length(arg)?;
+                let text = core::str::from_utf8(self.take(len)?).map_err(|_| VAZ256Error::DeserializationError)?;
+                Ok(Value::Text(text.to_string()))
+            }
+            MAJOR_ARRAY => {
+                let len = self.length(arg)?;
+                let items = (0..len).map(|_| self.item(depth + 1)).collect::<VAZ256Result<_>>()?;
+                Ok(Value::Array(items))
+            }
+            MAJOR_MAP => {
+                let len = self.length(arg)?;
+                let mut entries: Vec<(Value, Value)> = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let key = self.item(depth + 1)?;
+                    if entries.iter().any(|(k, _)| *k == key) {
+                        return Err(VAZ256Error::DeserializationError);
+                    }
+                    let value = self.item(depth + 1)?;
+                    entries.push((key, value));
+                }
+                Ok(Value::Map(entries))
+            }
+            MAJOR_TAG => Ok(Value::Tag(arg, Box::new(self.item(depth + 1)?))),
+            _ => match info {
+                SIMPLE_FALSE => Ok(Value::Bool(false)),
+                SIMPLE_TRUE => Ok(Value::Bool(true)),
+                SIMPLE_NULL => Ok(Value::Null),
+                _ => Err(VAZ256Error::DeserializationError),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+    use alloc::vec;
+
+    fn check(value: Value, encoded: &str) {
+        assert_eq!(hex::encode(value.to_vec()), encoded);
+        assert_eq!(Value::from_slice(&hex::decode(encoded).unwrap()).unwrap(), value);
+    }
+
+    #[test]
+    fn test_rfc8949_vectors() {
+        check(Value::from(0), "00");
+        check(Value::from(23), "17");
+        check(Value::from(24), "1818");
+        check(Value::from(1000), "1903e8");
+        check(Value::from(1_000_000_000_000), "1b000000e8d4a51000");
+        check(Value::Integer(u64::MAX as i128), "1bffffffffffffffff");
+        check(Value::from(-1), "20");
+        check(Value::from(-1000), "3903e7");
+        check(Value::Integer(-1 - u64::MAX as i128), "3bffffffffffffffff");
+        check(Value::from(&[1u8, 2, 3, 4][..]), "4401020304");
+        check(Value::from("\u{00fc}"), "62c3bc");
+        check(
+            Value::Array(vec![
+                Value::from(1),
+                Value::Array(vec![Value::from(2), Value::from(3)]),
+                Value::Array(vec![Value::from(4), Value::from(5)]),
+            ]),
+            "8301820203820405",
+        );
+        check(Value::Tag(1, Box::new(Value::from(1363896240))), "c11a514b67b0");
+        check(Value::Array(vec![Value::Bool(false), Value::Bool(true), Value::Null]), "83f4f5f6");
+    }
+
+    #[test]
+    fn test_map_ordering() {
+        let map = Value::Map(vec![
+            (Value::from("b"), Value::from(2)),
+            (Value::from(-1), Value::from(1)),
+            (Value::from(10), Value::from(0)),
+            (Value::from("a"), Value::from(1)),
+        ]);
+        assert_eq!(hex::encode(map.to_vec()), "a40a002001616101616202");
+    }
+
+    #[test]
+    fn test_rejects_non_deterministic() {
+        for encoded in ["1817", "190017", "5f4100ff", "9f01ff", "a201020103", "f93c00", "f7", "4201", "c1"] {
+            assert!(Value::from_slice(&hex::decode(encoded).unwrap()).is_err(), "{}", encoded);
+        }
+        assert!(Value::from_slice(&hex::decode("0000").unwrap()).is_err());
+        assert!(Value::from_slice(&hex::decode("9bffffffffffffffff").unwrap()).is_err());
+    }
+}
@@ -0,0 +1,406 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! CMS SignedData (RFC 5652) with VAZ256 signers.
+//!
+//! Every SignerInfo carries signed attributes (content-type, message-digest
+//! and optionally signing-time) and is identified by a SubjectKeyIdentifier
+//! equal to the signer's compact 32-byte public key. Message digests use
+//! SHAKE256 with 512-bit output as profiled for CMS in RFC 8702.
+
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::der::{self, ObjectIdentifier, Reader};
+use crate::fips202::shake256;
+use crate::pem;
+use crate::pkcs8::{decode_algorithm, encode_algorithm, OidArc};
+use crate::{sign, verify, PublicKey, SecretKey, Signature, VAZ256Error, VAZ256Result};
+
+/// Size of the SHAKE256 message digest
+pub const DIGEST_SIZE: usize = 64;
+
+const OID_DATA: &[u64] = &[1, 2, 840, 113549, 1, 7, 1];
+const OID_SIGNED_DATA: &[u64] = &[1, 2, 840, 113549, 1, 7, 2];
+const OID_CONTENT_TYPE: &[u64] = &[1, 2, 840, 113549, 1, 9, 3];
+const OID_MESSAGE_DIGEST: &[u64] = &[1, 2, 840, 113549, 1, 9, 4];
+const OID_SIGNING_TIME: &[u64] = &[1, 2, 840, 113549, 1, 9, 5];
+const OID_SHAKE256: &[u64] = &[2, 16, 840, 1, 101, 3, 4, 2, 12];
+
+fn oid(arcs: &[u64]) -> ObjectIdentifier {
+    ObjectIdentifier::new(arcs).expect("valid constant identifier")
+}
+
+fn digest(content: &[u8]) -> [u8; DIGEST_SIZE] {
+    let mut out = [0u8; DIGEST_SIZE];
+    shake256(&mut out, DIGEST_SIZE, content, content.len());
+    out
+}
+
+/// A signer taking part in [`SignedData::sign`]
+pub struct Signer<'a> {
+    pub key: &'a SecretKey,
+    /// Signing time in seconds since the Unix epoch, if it should be attested
+    pub signing_time: Option<i64>,
+}
+
+/// Parsed SignerInfo
+#[derive(Clone, Debug)]
+pub struct SignerInfo {
+    public_key: PublicKey,
+    signing_time: Option<i64>,
+    message_digest: [u8; DIGEST_SIZE],
+    content_type: ObjectIdentifier,
+    signed_attributes: Vec<u8>,
+    signature: Vec<u8>,
+}
+
+impl SignerInfo {
+    /// Compact public key identifying the signer
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Signing time attested by the signer, in seconds since the Unix epoch
+    pub fn signing_time(&self) -> Option<i64> {
+        self.signing_time
+    }
+
+    fn new(signer: &Signer, content_type: &ObjectIdentifier, digest: &[u8; DIGEST_SIZE]) -> VAZ256Result<Self> {
+        let mut attributes = vec![
+            encode_attribute(OID_CONTENT_TYPE, &der::encode_oid(content_type)),
+            encode_attribute(OID_MESSAGE_DIGEST, &der::encode_octet_string(digest)),
+        ];
+        if let Some(time) = signer.signing_time {
+            attributes.push(encode_attribute(OID_SIGNING_TIME, &der::encode_time(time)));
+        }
+        // The signature covers the attributes encoded as an explicit SET OF
+        let signed_attributes = der::encode_set_of(&attributes.iter().map(Vec::as_slice).collect::<Vec<_>>());
+        let signature = sign(&signed_attributes, signer.key)?;
+        Ok(Self {
+            public_key: signer.key.public_key(),
+            signing_time: signer.signing_time,
+            message_digest: *digest,
+            content_type: content_type.clone(),
+            signed_attributes,
+            signature: signature.to_bytes().to_vec(),
+        })
+    }
+
+    fn to_der(&self, arc: &OidArc) -> Vec<u8> {
+        let mut signed_attributes = self.signed_attributes.clone();
+        signed_attributes[0] = der::context(0, true);
+        der::encode_sequence(&[
+            &der::encode_integer_u64(3),
+            &der::encode_tlv(der::context(0, false), self.public_key.as_bytes()),
+            &encode_algorithm(&oid(OID_SHAKE256)),
+            &signed_attributes,
+            &encode_algorithm(&arc.vaz256()),
+            &der::encode_octet_string(&self.signature),
+        ])
+    }
+
+    fn decode(reader: &mut Reader, arc: &OidArc) -> VAZ256Result<Self> {
+        let mut fields = reader.read_sequence()?;
+        if fields.read_integer_u64()? != 3 {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let public_key = PublicKey::from_bytes(fields.read(der::context(0, false))?)?;
+        decode_algorithm(&mut fields, &oid(OID_SHAKE256))?;
+        let mut signed_attributes = fields.read_raw(der::context(0, true))?.to_vec();
+        decode_algorithm(&mut fields, &arc.vaz256())?;
+        let signature = fields.read_octet_string()?.to_vec();
+        fields.finish()?;
+
+        // Signed attributes are hashed with their universal SET OF tag
+        signed_attributes[0] = der::TAG_SET;
+        let mut set = Reader::new(&signed_attributes);
+        let mut attributes = set.read_constructed(der::TAG_SET)?;
+        let (mut content_type, mut message_digest, mut signing_time) = (None, None, None);
+        while !attributes.is_empty() {
+            let mut attribute = attributes.read_sequence()?;
+            let id = attribute.read_oid()?;
+            let mut values = attribute.read_constructed(der::TAG_SET)?;
+            attribute.finish()?;
+            if id.arcs() == OID_CONTENT_TYPE && content_type.is_none() {
+                content_type = Some(values.read_oid()?);
+            } else if id.arcs() == OID_MESSAGE_DIGEST && message_digest.is_none() {
+                let digest: [u8; DIGEST_SIZE] = values
+                    .read_octet_string()?
+                    .try_into()
+                    .map_err(|_| VAZ256Error::InvalidLength)?;
+                message_digest = Some(digest);
+            } else if id.arcs() == OID_SIGNING_TIME && signing_time.is_none() {
+                signing_time = Some(values.read_time()?);
+            } else if [OID_CONTENT_TYPE, OID_MESSAGE_DIGEST, OID_SIGNING_TIME].contains(&id.arcs()) {
+                // RFC 5652 forbids repeating these attributes
+                return Err(VAZ256Error::DeserializationError);
+            } else {
+                continue;
+            }
+            values.finish()?;
+        }
+
+        Ok(Self {
+            public_key,
+            signing_time,
+            message_digest: message_digest.ok_or(VAZ256Error::DeserializationError)?,
+            content_type: content_type.ok_or(VAZ256Error::DeserializationError)?,
+            signed_attributes,
+            signature,
+        })
+    }
+
+    /// Checks the signed attributes against `content` and the signature against `public_key`
+    fn verify(&self, content_type: &ObjectIdentifier, content: &[u8], public_key: &PublicKey) -> VAZ256Result<()> {
+        if self.content_type != *content_type || self.message_digest != digest(content) {
+            return Err(VAZ256Error::VerificationFailed);
+        }
+        let signature = Signature::from_bytes(&self.signature)?;
+        verify(&self.signed_attributes, &signature, public_key)
+    }
+}
+
+fn encode_attribute(id: &[u64], value: &[u8]) -> Vec<u8> {
+    der::encode_sequence(&[&der::encode_oid(&oid(id)), &der::encode_set_of(&[value])])
+}
+
+/// Parsed CMS SignedData wrapped in a ContentInfo
+pub struct SignedData {
+    content_type: ObjectIdentifier,
+    content: Option<Vec<u8>>,
+    certificates: Vec<Vec<u8>>,
+    signer_infos: Vec<SignerInfo>,
+}
+
+impl SignedData {
+    /// Signs `content` (of type id-data) once for every signer. Detached
+    /// signatures leave the content out of the encoding.
+    pub fn sign(
+        content: &[u8],
+        signers: &[Signer],
+        certificates: &[Vec<u8>],
+        detached: bool,
+    ) -> VAZ256Result<Self> {
+        if signers.is_empty() {
+            return Err(VAZ256Error::SigningFailed);
+        }
+        let content_type = oid(OID_DATA);
+        let digest = digest(content);
+        let signer_infos = signers
+            .iter()
+            .map(|signer| SignerInfo::new(signer, &content_type, &digest))
+            .collect::<VAZ256Result<Vec<_>>>()?;
+        Ok(Self {
+            content_type,
+            content: (!detached).then(|| content.to_vec()),
+            certificates: certificates.to_vec(),
+            signer_infos,
+        })
+    }
+
+    /// Encodes the ContentInfo
+    pub fn to_der(&self, arc: &OidArc) -> Vec<u8> {
+        let encap = match &self.content {
+            Some(content) => der::encode_sequence(&[
+                &der::encode_oid(&self.content_type),
+                &der::encode_tlv(der::context(0, true), &der::encode_octet_string(content)),
+            ]),
+            None => der::encode_sequence(&[&der::encode_oid(&self.content_type)]),
+        };
+        let signer_infos: Vec<Vec<u8>> = self.signer_infos.iter().map(|info| info.to_der(arc)).collect();
+
+        let mut fields = vec![
+            der::encode_integer_u64(3),
+            der::encode_set_of(&[&encode_algorithm(&oid(OID_SHAKE256))]),
+            encap,
+        ];
+        if !self.certificates.is_empty() {
+            let certificates: Vec<&[u8]> = self.certificates.iter().map(Vec::as_slice).collect();
+            let mut set = der::encode_set_of(&certificates);
+            set[0] = der::context(0, true);
+            fields.push(set);
+        }
+        fields.push(der::encode_set_of(&signer_infos.iter().map(Vec::as_slice).collect::<Vec<_>>()));
+
+        let signed_data = der::encode_sequence(&fields.iter().map(Vec::as_slice).collect::<Vec<_>>());
+        der::encode_sequence(&[
+            &der::encode_oid(&oid(OID_SIGNED_DATA)),
+            &der::encode_tlv(der::context(0, true), &signed_data),
+        ])
+    }
+
+    /// Encodes the ContentInfo in PEM armor
+    pub fn to_pem(&self, arc: &OidArc) -> String {
+        pem::encode(pem::CMS_LABEL, &self.to_der(arc))
+    }
+
+    /// Parses a DER encoded ContentInfo holding SignedData. Signatures are not checked.
+    pub fn from_der(der: &[u8], arc: &OidArc) -> VAZ256Result<Self> {
+        let mut outer = Reader::new(der);
+        let mut content_info = outer.read_sequence()?;
+        outer.finish()?;
+        if content_info.read_oid()?.arcs() != OID_SIGNED_DATA {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let mut explicit = content_info.read_constructed(der::context(0, true))?;
+        content_info.finish()?;
+        let mut fields = explicit.read_sequence()?;
+        explicit.finish()?;
+
+        if fields.read_integer_u64()? > 5 {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let mut digest_algorithms = fields.read_constructed(der::TAG_SET)?;
+        while !digest_algorithms.is_empty() {
+            digest_algorithms.read_sequence()?;
+        }
+
+        let mut encap = fields.read_sequence()?;
+        let content_type = encap.read_oid()?;
+        let content = match encap.read_optional(der::context(0, true))? {
+            Some(explicit) => {
+                let mut explicit = Reader::new(explicit);
+                let content = explicit.read_octet_string()?.to_vec();
+                explicit.finish()?;
+                Some(content)
+            }
+            None => None,
+        };
+        encap.finish()?;
+
+        let mut certificates = Vec::new();
+        if let Some(set) = fields.read_optional(der::context(0, true))? {
+            let mut set = Reader::new(set);
+            while !set.is_empty() {
+                certificates.push(set.read_any()?.2.to_vec());
+            }
+        }
+        // Revocation information is not supported
+        fields.read_optional(der::context(1, true))?;
+
+        let mut set = fields.read_constructed(der::TAG_SET)?;
+        fields.finish()?;
+        let mut signer_infos = Vec::new();
+        while !set.is_empty() {
+            signer_infos.push(SignerInfo::decode(&mut set, arc)?);
+        }
+
+        Ok(Self {
+            content_type,
+            content,
+            certificates,
+            signer_infos,
+        })
+    }
+
+    /// Parses a PEM armored ContentInfo holding SignedData. Signatures are not checked.
+    pub fn from_pem(pem: &str, arc: &OidArc) -> VAZ256Result<Self> {
+        Self::from_der(&pem::decode_label(pem, pem::CMS_LABEL)?, arc)
+    }
+
+    /// Encapsulated content, or None for detached signatures
+    pub fn content(&self) -> Option<&[u8]> {
+        self.content.as_deref()
+    }
+
+    /// Certificates carried along, as raw DER blobs
+    pub fn certificates(&self) -> &[Vec<u8>] {
+        &self.certificates
+    }
+
+    pub fn signer_infos(&self) -> &[SignerInfo] {
+        &self.signer_infos
+    }
+
+    /// Verifies every SignerInfo. Each signer must be one of `public_keys`.
+    /// `detached_content` must be given if and only if the content is not encapsulated.
+    pub fn verify(&self, detached_content: Option<&[u8]>, public_keys: &[PublicKey]) -> VAZ256Result<()> {
+        let content = match (&self.content, detached_content) {
+            (Some(content), None) => content.as_slice(),
+            (None, Some(content)) => content,
+            _ => return Err(VAZ256Error::VerificationFailed),
+        };
+        if self.signer_infos.is_empty() {
+            return Err(VAZ256Error::VerificationFailed);
+        }
+        for info in &self.signer_infos {
+            let public_key = public_keys
+                .iter()
+                .find(|key| *key == info.public_key())
+                .ok_or(VAZ256Error::PublicKeyMismatch)?;
+            info.verify(&self.content_type, content, public_key)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::keygen;
+
+    #[test]
+    fn test_attached_round_trip() {
+        let arc = OidArc::test();
+        let (sk, pk) = keygen().unwrap();
+        let signers = [Signer { key: &sk, signing_time: Some(1_750_000_000) }];
+        let cert = vec![0x30, 0x03, 0x02, 0x01, 0x00];
+
+        let signed = SignedData::sign(b"document", &signers, std::slice::from_ref(&cert), false).unwrap();
+        let parsed = SignedData::from_pem(&signed.to_pem(&arc), &arc).unwrap();
+        assert_eq!(parsed.content(), Some(&b"document"[..]));
+        assert_eq!(parsed.certificates(), &[cert]);
+        assert_eq!(parsed.signer_infos()[0].public_key(), &pk);
+        assert_eq!(parsed.signer_infos()[0].signing_time(), Some(1_750_000_000));
+        assert!(parsed.verify(None, std::slice::from_ref(&pk)).is_ok());
+        // Content is already encapsulated
+        assert!(parsed.verify(Some(b"document"), &[pk]).is_err());
+    }
+
+    #[test]
+    fn test_detached_multiple_signers() {
+        let arc = OidArc::test();
+        let (sk1, pk1) = keygen().unwrap();
+        let (sk2, pk2) = keygen().unwrap();
+        let signers = [
+            Signer { key: &sk1, signing_time: None },
+            Signer { key: &sk2, signing_time: Some(0) },
+        ];
+
+        let signed = SignedData::sign(b"release.tar", &signers, &[], true).unwrap();
+        let parsed = SignedData::from_der(&signed.to_der(&arc), &arc).unwrap();
+        assert_eq!(parsed.content(), None);
+        assert_eq!(parsed.signer_infos().len(), 2);
+
+        let keys = [pk1.clone(), pk2];
+        assert!(parsed.verify(Some(b"release.tar"), &keys).is_ok());
+        assert_eq!(parsed.verify(Some(b"release.tgz"), &keys), Err(VAZ256Error::VerificationFailed));
+        assert_eq!(parsed.verify(Some(b"release.tar"), &[pk1]), Err(VAZ256Error::PublicKeyMismatch));
+    }
+
+    #[test]
+    fn test_tampered_signed_attributes() {
+        let arc = OidArc::test();
+        let (sk, pk) = keygen().unwrap();
+        let signers = [Signer { key: &sk, signing_time: Some(1_750_000_000) }];
+        let der = SignedData::sign(b"document", &signers, &[], false).unwrap().to_der(&arc);
+
+        // Move the attested signing time forward by one decade
+        let time = b"250615150640Z";
+        let pos = der.windows(time.len()).position(|w| w == time).unwrap();
+        let mut tampered = der.clone();
+        tampered[pos] = b'3';
+        let parsed = SignedData::from_der(&tampered, &arc).unwrap();
+        assert_eq!(parsed.verify(None, &[pk]), Err(VAZ256Error::VerificationFailed));
+    }
+}
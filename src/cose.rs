@@ -0,0 +1,627 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! COSE (RFC 9052) COSE_Sign1 and COSE_Sign messages and COSE_Key public keys.
+//!
+//! VAZ256 has no registered COSE identifiers, so the algorithm and key type
+//! use values from the private-use range below -65536. A COSE_Key holds the
+//! compact public key under label -1 and the optional full Dilithium5 key
+//! under label -2.
+
+use alloc::{boxed::Box, vec, vec::Vec};
+
+use crate::cbor::Value;
+use crate::{sign, verify, FullPublicKey, PublicKey, SecretKey, Signature, VAZ256Error, VAZ256Result};
+
+/// Private-use COSE algorithm identifier of VAZ256
+pub const ALG: i64 = -65537;
+/// Private-use COSE key type of VAZ256 keys
+pub const KEY_TYPE: i64 = -65537;
+
+/// CBOR tag of COSE_Sign1
+pub const TAG_SIGN1: u64 = 18;
+/// CBOR tag of COSE_Sign
+pub const TAG_SIGN: u64 = 98;
+
+const LABEL_ALG: i64 = 1;
+const LABEL_CRIT: i64 = 2;
+const LABEL_CONTENT_TYPE: i64 = 3;
+const LABEL_KID: i64 = 4;
+
+const KEY_LABEL_KTY: i64 = 1;
+const KEY_LABEL_KID: i64 = 2;
+const KEY_LABEL_ALG: i64 = 3;
+const KEY_LABEL_PUBLIC_KEY: i64 = -1;
+const KEY_LABEL_FULL_PUBLIC_KEY: i64 = -2;
+
+/// Header parameters other than the algorithm, which is set when signing
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Headers {
+    /// Content type, either an unsigned integer or a text string
+    pub content_type: Option<Value>,
+    /// Key identifier
+    pub kid: Option<Vec<u8>>,
+    /// Any other parameters
+    pub extra: Vec<(Value, Value)>,
+}
+
+impl Headers {
+    fn to_map(&self, alg: bool) -> Vec<(Value, Value)> {
+        let mut map = Vec::new();
+        if alg {
+            map.push((Value::from(LABEL_ALG), Value::from(ALG)));
+        }
+        if let Some(content_type) = &self.content_type {
+            map.push((Value::from(LABEL_CONTENT_TYPE), content_type.clone()));
+        }
+        if let Some(kid) = &self.kid {
+            map.push((Value::from(LABEL_KID), Value::Bytes(kid.clone())));
+        }
+        map.extend(self.extra.iter().cloned());
+        map
+    }
+
+    /// Parses a header map, returning the algorithm separately
+    fn from_map(map: &[(Value, Value)]) -> VAZ256Result<(Self, Option<i128>)> {
+        let mut headers = Headers::default();
+        let mut alg = None;
+        for (label, value) in map {
+            match label.as_integer().and_then(|n| i64::try_from(n).ok()) {
+                Some(LABEL_ALG) => alg = Some(value.as_integer().ok_or(VAZ256Error::DeserializationError)?),
+                // No header extensions are understood, so any critical one is fatal
+                Some(LABEL_CRIT) => return Err(VAZ256Error::DeserializationError),
+                Some(LABEL_CONTENT_TYPE) => match value {
+                    Value::Integer(n) if *n >= 0 => headers.content_type = Some(value.clone()),
+                    Value::Text(_) => headers.content_type = Some(value.clone()),
+                    _ => return Err(VAZ256Error::DeserializationError),
+                },
+                Some(LABEL_KID) => {
+                    headers.kid = Some(value.as_bytes().ok_or(VAZ256Error::DeserializationError)?.to_vec())
+                }
+                _ => headers.extra.push((label.clone(), value.clone())),
+            }
+        }
+        Ok((headers, alg))
+    }
+}
+
+fn encode_protected(map: Vec<(Value, Value)>) -> Vec<u8> {
+    // An empty protected header is a zero-length byte string
+    if map.is_empty() {
+        Vec::new()
+    } else {
+        Value::Map(map).to_vec()
+    }
+}
+
+fn decode_protected(bytes: &[u8]) -> VAZ256Result<Vec<(Value, Value)>> {
+    if bytes.is_empty() {
+        return Ok(Vec::new());
+    }
+    match Value::from_slice(bytes)? {
+        Value::Map(map) => Ok(map),
+        _ => Err(VAZ256Error::DeserializationError),
+    }
+}
+
+/// Parses a protected/unprotected header pair, requiring the VAZ256
+/// algorithm in the protected bucket when `alg` is set and no algorithm
+/// otherwise
+fn decode_headers(protected: &[u8], unprotected: &Value, alg: bool) -> VAZ256Result<(Headers, Headers)> {
+    let protected_map = decode_protected(protected)?;
+    let unprotected_map = unprotected.as_map().ok_or(VAZ256Error::DeserializationError)?;
+    // A label must not appear in both buckets
+    if unprotected_map.iter().any(|(label, _)| protected_map.iter().any(|(l, _)| l == label)) {
+        return Err(VAZ256Error::DeserializationError);
+    }
+    let (protected_headers, protected_alg) = Headers::from_map(&protected_map)?;
+    let (unprotected_headers, unprotected_alg) = Headers::from_map(unprotected_map)?;
+    let expected = if alg { Some(ALG as i128) } else { None };
+    if protected_alg != expected || unprotected_alg.is_some() {
+        return Err(VAZ256Error::DeserializationError);
+    }
+    Ok((protected_headers, unprotected_headers))
+}
+
+fn sig_structure(body_protected: &[u8], sign_protected: Option<&[u8]>, external_aad: &[u8], payload: &[u8]) -> Vec<u8> {
+    let mut items = vec![Value::from(if sign_protected.is_some() { "Signature" } else { "Signature1" })];
+    items.push(Value::from(body_protected));
+    if let Some(sign_protected) = sign_protected {
+        items.push(Value::from(sign_protected));
+    }
+    items.push(Value::from(external_aad));
+    items.push(Value::from(payload));
+    Value::Array(items).to_vec()
+}
+
+/// Strips the expected tag, if present, and returns the message array
+fn message_items(data: &[u8], tag: u64, len: usize) -> VAZ256Result<Vec<Value>> {
+    let items = match Value::from_slice(data)? {
+        Value::Tag(t, item) if t == tag => *item,
+        Value::Tag(..) => return Err(VAZ256Error::DeserializationError),
+        item => item,
+    };
+    match items {
+        Value::Array(items) if items.len() == len => Ok(items),
+        _ => Err(VAZ256Error::DeserializationError),
+    }
+}
+
+fn payload_value(payload: &Option<Vec<u8>>) -> Value {
+    match payload {
+        Some(payload) => Value::Bytes(payload.clone()),
+        None => Value::Null,
+    }
+}
+
+fn decode_payload(value: &Value) -> VAZ256Result<Option<Vec<u8>>> {
+    match value {
+        Value::Bytes(payload) => Ok(Some(payload.clone())),
+        Value::Null => Ok(None),
+        _ => Err(VAZ256Error::DeserializationError),
+    }
+}
+
+/// Selects the attached or the detached payload; exactly one must be present
+fn select_payload<'a>(attached: &'a Option<Vec<u8>>, detached: Option<&'a [u8]>) -> VAZ256Result<&'a [u8]> {
+    match (attached, detached) {
+        (Some(payload), None) => Ok(payload),
+        (None, Some(payload)) => Ok(payload),
+        _ => Err(VAZ256Error::DeserializationError),
+    }
+}
+
+fn bytes(value: &Value) -> VAZ256Result<Vec<u8>> {
+    value.as_bytes().map(<[u8]>::to_vec).ok_or(VAZ256Error::DeserializationError)
+}
+
+/// COSE_Sign1 message
+#[derive(Clone, Debug)]
+pub struct CoseSign1 {
+    protected: Vec<u8>,
+    headers: Headers,
+    unprotected: Headers,
+    payload: Option<Vec<u8>>,
+    signature: Vec<u8>,
+}
+
+impl CoseSign1 {
+    /// Signs `payload`, binding the protected headers and `external_aad`
+    pub fn sign(
+        payload: &[u8],
+        protected: &Headers,
+        unprotected: &Headers,
+        external_aad: &[u8],
+        key: &SecretKey,
+        detached: bool,
+    ) -> VAZ256Result<Self> {
+        let protected_bytes = encode_protected(protected.to_map(true));
+        let signature = sign(&sig_structure(&protected_bytes, None, external_aad, payload), key)?;
+        Ok(CoseSign1 {
+            protected: protected_bytes,
+            headers: protected.clone(),
+            unprotected: unprotected.clone(),
+            payload: if detached { None } else { Some(payload.to_vec()) },
+            signature: signature.to_bytes().to_vec(),
+        })
+    }
+
+    /// Returns the tagged CBOR encoding
+    pub fn to_cbor(&self) -> Vec<u8> {
+        Value::Tag(
+            TAG_SIGN1,
+            Box::new(Value::Array(vec![
+                Value::Bytes(self.protected.clone()),
+                Value::Map(self.unprotected.to_map(false)),
+                payload_value(&self.payload),
+                Value::Bytes(self.signature.clone()),
+            ])),
+        )
+        .to_vec()
+    }
+
+    /// Parses a tagged or untagged COSE_Sign1 message
+    pub fn from_cbor(data: &[u8]) -> VAZ256Result<Self> {
+        let items = message_items(data, TAG_SIGN1, 4)?;
+        let protected = bytes(&items[0])?;
+        let (headers, unprotected) = decode_headers(&protected, &items[1], true)?;
+        Ok(CoseSign1 {
+            protected,
+            headers,
+            unprotected,
+            payload: decode_payload(&items[2])?,
+            signature: bytes(&items[3])?,
+        })
+    }
+
+    /// Returns the attached payload
+    pub fn payload(&self) -> Option<&[u8]> {
+        self.payload.as_deref()
+    }
+
+    pub fn protected_headers(&self) -> &Headers {
+        &self.headers
+    }
+
+    pub fn unprotected_headers(&self) -> &Headers {
+        &self.unprotected
+    }
+
+    /// Verifies the signature over the attached payload, or over
+    /// `detached_payload` for a detached message
+    pub fn verify(&self, detached_payload: Option<&[u8]>, external_aad: &[u8], public_key: &PublicKey) -> VAZ256Result<()> {
+        let payload = select_payload(&self.payload, detached_payload)?;
+        let signature = Signature::from_bytes(&self.signature)?;
+        verify(&sig_structure(&self.protected, None, external_aad, payload), &signature, public_key)
+    }
+}
+
+/// Signer of a COSE_Sign message
+pub struct CoseSigner<'a> {
+    pub key: &'a SecretKey,
+    pub protected: Headers,
+    pub unprotected: Headers,
+}
+
+/// COSE_Signature of a COSE_Sign message
+#[derive(Clone, Debug)]
+pub struct CoseSignature {
+    protected: Vec<u8>,
+    headers: Headers,
+    unprotected: Headers,
+    signature: Vec<u8>,
+}
+
+impl CoseSignature {
+    pub fn protected_headers(&self) -> &Headers {
+        &self.headers
+    }
+
+    pub fn unprotected_headers(&self) -> &Headers {
+        &self.unprotected
+    }
+
+    /// Returns the compact public key of the signer, taken from the signature
+    pub fn public_key(&self) -> VAZ256Result<PublicKey> {
+        Ok(Signature::from_bytes(&self.signature)?.full_public_key().public_key())
+    }
+}
+
+/// COSE_Sign message with one or more signatures
+#[derive(Clone, Debug)]
+pub struct CoseSign {
+    protected: Vec<u8>,
+    headers: Headers,
+    unprotected: Headers,
+    payload: Option<Vec<u8>>,
+    signatures: Vec<CoseSignature>,
+}
+
+impl CoseSign {
+    /// Signs `payload` once per signer
+    pub fn sign(
+        payload: &[u8],
+        protected: &Headers,
+        unprotected: &Headers,
+        signers: &[CoseSigner],
+        external_aad: &[u8],
+        detached: bool,
+    ) -> VAZ256Result<Self> {
+        if signers.is_empty() {
+            return Err(VAZ256Error::SigningFailed);
+        }
+        let body_protected = encode_protected(protected.to_map(false));
+        let signatures = signers
+            .iter()
+            .map(|signer| {
+                let sign_protected = encode_protected(signer.protected.to_map(true));
+                let to_sign = sig_structure(&body_protected, Some(&sign_protected), external_aad, payload);
+                Ok(CoseSignature {
+                    signature: sign(&to_sign, signer.key)?.to_bytes().to_vec(),
+                    protected: sign_protected,
+                    headers: signer.protected.clone(),
+                    unprotected: signer.unprotected.clone(),
+                })
+            })
+            .collect::<VAZ256Result<_>>()?;
+        Ok(CoseSign {
+            protected: body_protected,
+            headers: protected.clone(),
+            unprotected: unprotected.clone(),
+            payload: if detached { None } else { Some(payload.to_vec()) },
+            signatures,
+        })
+    }
+
+    /// Returns the tagged CBOR encoding
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let signatures = self
+            .signatures
+            .iter()
+            .map(|s| {
+                Value::Array(vec![
+                    Value::Bytes(s.protected.clone()),
+                    Value::Map(s.unprotected.to_map(false)),
+                    Value::Bytes(s.signature.clone()),
+                ])
+            })
+            .collect();
+        Value::Tag(
+            TAG_SIGN,
+            Box::new(Value::Array(vec![
+                Value::Bytes(self.protected.clone()),
+                Value::Map(self.unprotected.to_map(false)),
+                payload_value(&self.payload),
+                Value::Array(signatures),
+            ])),
+        )
+        .to_vec()
+    }
+
+    /// Parses a tagged or untagged COSE_Sign message
+    pub fn from_cbor(data: &[u8]) -> VAZ256Result<Self> {
+        let items = message_items(data, TAG_SIGN, 4)?;
+        let protected = bytes(&items[0])?;
+        let (headers, unprotected) = decode_headers(&protected, &items[1], false)?;
+        let signatures = match items[3].as_array() {
+            Some(signatures) if !signatures.is_empty() => signatures,
+            _ => return Err(VAZ256Error::DeserializationError),
+        };
+        let signatures = signatures
+            .iter()
+            .map(|s| match s.as_array() {
+                Some([sign_protected, sign_unprotected, signature]) => {
+                    let sign_protected = bytes(sign_protected)?;
+                    let (headers, unprotected) = decode_headers(&sign_protected, sign_unprotected, true)?;
+                    Ok(CoseSignature {
+                        protected: sign_protected,
+                        headers,
+                        unprotected,
+                        signature: bytes(signature)?,
+                    })
+                }
+                _ => Err(VAZ256Error::DeserializationError),
+            })
+            .collect::<VAZ256Result<_>>()?;
+        Ok(CoseSign {
+            protected,
+            headers,
+            unprotected,
+            payload: decode_payload(&items[2])?,
+            signatures,
+        })
+    }
+
+    /// Returns the attached payload
+    pub fn payload(&self) -> Option<&[u8]> {
+        self.payload.as_deref()
+    }
+
+    pub fn protected_headers(&self) -> &Headers {
+        &self.headers
+    }
+
+    pub fn unprotected_headers(&self) -> &Headers {
+        &self.unprotected
+    }
+
+    pub fn signatures(&self) -> &[CoseSignature] {
+        &self.signatures
+    }
+
+    /// Verifies every signature
+    ///
+    /// Each signer must be one of `public_keys`, otherwise verification
+    /// fails with `PublicKeyMismatch`.
+    pub fn verify(&self, detached_payload: Option<&[u8]>, external_aad: &[u8], public_keys: &[PublicKey]) -> VAZ256Result<()> {
+        let payload = select_payload(&self.payload, detached_payload)?;
+        for s in &self.signatures {
+            let signature = Signature::from_bytes(&s.signature)?;
+            let public_key = signature.full_public_key().public_key();
+            if !public_keys.contains(&public_key) {
+                return Err(VAZ256Error::PublicKeyMismatch);
+            }
+            let to_verify = sig_structure(&self.protected, Some(&s.protected), external_aad, payload);
+            verify(&to_verify, &signature, &public_key)?;
+        }
+        Ok(())
+    }
+}
+
+/// VAZ256 public key as a COSE_Key
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoseKey {
+    pub public_key: PublicKey,
+    pub full_public_key: Option<FullPublicKey>,
+    pub kid: Option<Vec<u8>>,
+}
+
+impl CoseKey {
+    /// Creates a key holding only the compact public key
+    pub fn new(public_key: PublicKey) -> Self {
+        CoseKey { public_key, full_public_key: None, kid: None }
+    }
+
+    /// Creates a key holding the full Dilithium5 key and its compact hash
+    pub fn from_full(full_public_key: FullPublicKey) -> Self {
+        CoseKey {
+            public_key: full_public_key.public_key(),
+            full_public_key: Some(full_public_key),
+            kid: None,
+        }
+    }
+
+    /// Sets the key identifier
+    pub fn with_kid(mut self, kid: &[u8]) -> Self {
+        self.kid = Some(kid.to_vec());
+        self
+    }
+
+    /// Returns the CBOR encoding
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let mut map = vec![
+            (Value::from(KEY_LABEL_KTY), Value::from(KEY_TYPE)),
+            (Value::from(KEY_LABEL_ALG), Value::from(ALG)),
+            (Value::from(KEY_LABEL_PUBLIC_KEY), Value::from(&self.public_key.as_bytes()[..])),
+        ];
+        if let Some(kid) = &self.kid {
+            map.push((Value::from(KEY_LABEL_KID), Value::Bytes(kid.clone())));
+        }
+        if let Some(full) = &self.full_public_key {
+            map.push((Value::from(KEY_LABEL_FULL_PUBLIC_KEY), Value::from(&full.as_bytes()[..])));
+        }
+        Value::Map(map).to_vec()
+    }
+
+    /// Parses a COSE_Key
+    ///
+    /// A full key that does not hash to the compact key fails with
+    /// `PublicKeyMismatch`. Unknown key type parameters, such as private
+    /// key material, are rejected.
+    pub fn from_cbor(data: &[u8]) -> VAZ256Result<Self> {
+        let value = Value::from_slice(data)?;
+        let map = value.as_map().ok_or(VAZ256Error::DeserializationError)?;
+        let integer = |label: i64| value.get(&Value::from(label)).and_then(Value::as_integer);
+        if integer(KEY_LABEL_KTY) != Some(KEY_TYPE as i128)
+            || value.get(&Value::from(KEY_LABEL_ALG)).is_some_and(|alg| alg.as_integer() != Some(ALG as i128))
+            || map.iter().any(|(label, _)| {
+                label.as_integer().is_some_and(|l| l < 0 && l != KEY_LABEL_PUBLIC_KEY as i128 && l != KEY_LABEL_FULL_PUBLIC_KEY as i128)
+            })
+        {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let field = |label: i64| -> VAZ256Result<Option<Vec<u8>>> {
+            value.get(&Value::from(label)).map(bytes).transpose()
+        };
+        let public_key = PublicKey::from_bytes(&field(KEY_LABEL_PUBLIC_KEY)?.ok_or(VAZ256Error::DeserializationError)?)?;
+        let full_public_key = match field(KEY_LABEL_FULL_PUBLIC_KEY)? {
+            Some(full) => {
+                let full = FullPublicKey::from_bytes(&full)?;
+                if full.public_key() != public_key {
+                    return Err(VAZ256Error::PublicKeyMismatch);
+                }
+                Some(full)
+            }
+            None => None,
+        };
+        Ok(CoseKey {
+            public_key,
+            full_public_key,
+            kid: field(KEY_LABEL_KID)?,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{hex, keygen, SIGNATURE_SIZE};
+
+    // {1: -65537} as a byte string
+    const PROTECTED: &str = "47a1013a00010000";
+
+    #[test]
+    fn test_sign1_vector() {
+        assert_eq!(
+            hex::encode(sig_structure(&hex::decode(&PROTECTED[2..]).unwrap(), None, b"", b"hi")),
+            format!("846a5369676e617475726531{}40426869", PROTECTED)
+        );
+
+        let (sk, pk) = keygen().unwrap();
+        let unprotected = Headers { kid: Some(b"k1".to_vec()), ..Default::default() };
+        let message = CoseSign1::sign(b"hi", &Headers::default(), &unprotected, b"", &sk, false).unwrap();
+        let encoded = message.to_cbor();
+
+        // 18([protected, {4: 'k1'}, 'hi', signature])
+        let prefix = format!("d284{}a104426b3142686959{:04x}", PROTECTED, SIGNATURE_SIZE);
+        assert_eq!(hex::encode(&encoded[..prefix.len() / 2]), prefix);
+        assert_eq!(encoded.len(), prefix.len() / 2 + SIGNATURE_SIZE);
+
+        let hand_built = [hex::decode(&prefix).unwrap(), encoded[prefix.len() / 2..].to_vec()].concat();
+        let parsed = CoseSign1::from_cbor(&hand_built).unwrap();
+        assert_eq!(parsed.unprotected_headers(), &unprotected);
+        assert_eq!(parsed.payload(), Some(&b"hi"[..]));
+        assert!(parsed.verify(None, b"", &pk).is_ok());
+        assert!(parsed.verify(None, b"aad", &pk).is_err());
+        assert_eq!(parsed.to_cbor(), encoded);
+    }
+
+    #[test]
+    fn test_sign1_detached_aad() {
+        let (sk, pk) = keygen().unwrap();
+        let protected = Headers { content_type: Some(Value::from(60)), ..Default::default() };
+        let message = CoseSign1::sign(b"payload", &protected, &Headers::default(), b"aad", &sk, true).unwrap();
+        let parsed = CoseSign1::from_cbor(&message.to_cbor()).unwrap();
+        assert_eq!(parsed.protected_headers(), &protected);
+        assert_eq!(parsed.payload(), None);
+        assert!(parsed.verify(Some(b"payload"), b"aad", &pk).is_ok());
+        assert!(parsed.verify(Some(b"payloaD"), b"aad", &pk).is_err());
+        assert!(parsed.verify(None, b"aad", &pk).is_err());
+    }
+
+    #[test]
+    fn test_rejected_headers() {
+        let tail = "a04268694100";
+        // Wrong algorithm, critical parameters, and alg in the unprotected bucket
+        for message in [
+            format!("d28443a10127{}", tail),
+            format!("d2844aa2013a00010000028104{}", tail),
+            "d28440a1013a000100004268694100".to_string(),
+        ] {
+            let message = hex::decode(message).unwrap();
+            assert!(CoseSign1::from_cbor(&message).is_err());
+        }
+        assert!(CoseSign1::from_cbor(&hex::decode(format!("d884{}{}", PROTECTED, tail)).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_cose_sign() {
+        let (sk1, pk1) = keygen().unwrap();
+        let (sk2, pk2) = keygen().unwrap();
+        let signers = [&sk1, &sk2].map(|key| CoseSigner {
+            key,
+            protected: Headers::default(),
+            unprotected: Headers { kid: Some(key.public_key().as_bytes().to_vec()), ..Default::default() },
+        });
+        let body = Headers { content_type: Some(Value::from("text/plain")), ..Default::default() };
+        let message = CoseSign::sign(b"hello", &body, &Headers::default(), &signers, b"", false).unwrap();
+        let encoded = message.to_cbor();
+        assert_eq!(&encoded[..2], [0xd8, 0x62]);
+
+        let parsed = CoseSign::from_cbor(&encoded).unwrap();
+        assert_eq!(parsed.signatures().len(), 2);
+        assert_eq!(parsed.signatures()[1].public_key().unwrap(), pk2);
+        assert!(parsed.verify(None, b"", &[pk1.clone(), pk2]).is_ok());
+        assert_eq!(parsed.verify(None, b"", &[pk1]).unwrap_err(), VAZ256Error::PublicKeyMismatch);
+    }
+
+    #[test]
+    fn test_cose_key() {
+        let pk = PublicKey::from_bytes(&[7u8; 32]).unwrap();
+        let key = CoseKey::new(pk).with_kid(b"k");
+        // {1: -65537, 2: 'k', 3: -65537, -1: h'0707...'}
+        let expected = format!("a4013a0001000002416b033a00010000205820{}", "07".repeat(32));
+        assert_eq!(hex::encode(key.to_cbor()), expected);
+        assert_eq!(CoseKey::from_cbor(&key.to_cbor()).unwrap(), key);
+
+        let (sk, _) = keygen().unwrap();
+        let full = CoseKey::from_full(sk.full_public_key());
+        assert_eq!(CoseKey::from_cbor(&full.to_cbor()).unwrap(), full);
+        let mut mismatched = full.clone();
+        mismatched.public_key = PublicKey::from_bytes(&[7u8; 32]).unwrap();
+        assert_eq!(CoseKey::from_cbor(&mismatched.to_cbor()).unwrap_err(), VAZ256Error::PublicKeyMismatch);
+
+        let private = hex::decode(format!("a3013a00010000205820{0}235820{0}", "07".repeat(32))).unwrap();
+        assert!(CoseKey::from_cbor(&private).is_err());
+    }
+}
@@ -0,0 +1,137 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The AES-256 CTR_DRBG behind `randombytes` in the NIST PQC known-answer
+//! test generator (`rng.c` of the submission package).
+//!
+//! It reproduces that code exactly, including a state update after every
+//! call, so the output depends on how requests are split into calls. It
+//! has no reseeding or prediction resistance and is only meant for
+//! generating and checking known-answer tests.
+
+use crate::aes::Aes256;
+use crate::entropy::EntropySource;
+use crate::VAZ256Result;
+
+/// Length of the entropy input and personalization string
+pub const SEED_SIZE: usize = 48;
+
+/// State of the NIST `randombytes` generator
+#[derive(Clone)]
+pub struct CtrDrbg {
+    key: [u8; 32],
+    v: [u8; 16],
+}
+
+impl CtrDrbg {
+    /// Instantiates the generator, like `randombytes_init(entropy_input, personalization, 256)`
+    pub fn new(entropy_input: &[u8; SEED_SIZE], personalization: Option<&[u8; SEED_SIZE]>) -> Self {
+        let mut seed_material = *entropy_input;
+        if let Some(personalization) = personalization {
+            for (s, p) in seed_material.iter_mut().zip(personalization) {
+                *s ^= p;
+            }
+        }
+        let mut drbg = CtrDrbg { key: [0u8; 32], v: [0u8; 16] };
+        drbg.update(Some(&seed_material));
+        drbg
+    }
+
+    fn increment_v(&mut self) {
+        for byte in self.v.iter_mut().rev() {
+            *byte = byte.wrapping_add(1);
+            if *byte != 0 {
+                break;
+            }
+        }
+    }
+
+    /// `AES256_CTR_DRBG_Update`
+    fn update(&mut self, provided_data: Option<&[u8; SEED_SIZE]>) {
+        let aes = Aes256::new(&self.key);
+        let mut temp = [0u8; SEED_SIZE];
+        for block in temp.chunks_exact_mut(16) {
+            self.increment_v();
+            let block: &mut [u8; 16] = block.try_into().unwrap();
+            *block = self.v;
+            aes.encrypt_block(block);
+        }
+        if let Some(data) = provided_data {
+            for (t, d) in temp.iter_mut().zip(data) {
+                *t ^= d;
+            }
+        }
+        self.key.copy_from_slice(&temp[..32]);
+        self.v.copy_from_slice(&temp[32..]);
+    }
+
+    /// Fills `out`, like one call of `randombytes(out, out.len())`
+    pub fn randombytes(&mut self, out: &mut [u8]) {
+        let aes = Aes256::new(&self.key);
+        for chunk in out.chunks_mut(16) {
+            self.increment_v();
+            let mut block = self.v;
+            aes.encrypt_block(&mut block);
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+        self.update(None);
+    }
+}
+
+impl EntropySource for CtrDrbg {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> VAZ256Result<()> {
+        self.randombytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+
+    #[test]
+    fn test_kat_request_seeds() {
+        // The first seed and message of every NIST PQC signature KAT
+        let entropy_input: [u8; SEED_SIZE] = core::array::from_fn(|i| i as u8);
+        let mut drbg = CtrDrbg::new(&entropy_input, None);
+        let mut seed = [0u8; SEED_SIZE];
+        drbg.randombytes(&mut seed);
+        assert_eq!(
+            hex::encode(seed),
+            "061550234d158c5ec95595fe04ef7a25767f2e24cc2bc479d09d86dc9abcfde7056a8c266f9ef97ed08541dbd2e1ffa1"
+        );
+        let mut msg = [0u8; 33];
+        drbg.randombytes(&mut msg);
+        assert_eq!(hex::encode(msg), "d81c4d8d734fcbfbeade3d3f8a039faa2a2c9957e835ad55b22e75bf57bb556ac8");
+    }
+
+    #[test]
+    fn test_call_boundaries_matter() {
+        let seed = [7u8; SEED_SIZE];
+        let mut whole = [0u8; 40];
+        CtrDrbg::new(&seed, None).randombytes(&mut whole);
+
+        let mut drbg = CtrDrbg::new(&seed, None);
+        let mut split = [0u8; 40];
+        let (first, second) = split.split_at_mut(16);
+        drbg.randombytes(first);
+        drbg.randombytes(second);
+        assert_eq!(whole[..16], split[..16]);
+        assert_ne!(whole[16..], split[16..]);
+
+        let mut personalized = [0u8; 40];
+        CtrDrbg::new(&seed, Some(&[1u8; SEED_SIZE])).randombytes(&mut personalized);
+        assert_ne!(whole, personalized);
+    }
+}
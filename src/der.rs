@@ -0,0 +1,560 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Minimal ASN.1 DER encoder and strict decoder.
+//!
+//! Only the subset needed for key, certificate and message formats is
+//! supported: single-byte tags and definite lengths up to four bytes.
+
+use core::fmt;
+use alloc::{format, vec, vec::Vec};
+
+use crate::{VAZ256Error, VAZ256Result};
+
+pub const TAG_BOOLEAN: u8 = 0x01;
+pub const TAG_INTEGER: u8 = 0x02;
+pub const TAG_BIT_STRING: u8 = 0x03;
+pub const TAG_OCTET_STRING: u8 = 0x04;
+pub const TAG_NULL: u8 = 0x05;
+pub const TAG_OID: u8 = 0x06;
+pub const TAG_UTF8_STRING: u8 = 0x0C;
+pub const TAG_PRINTABLE_STRING: u8 = 0x13;
+pub const TAG_IA5_STRING: u8 = 0x16;
+pub const TAG_UTC_TIME: u8 = 0x17;
+pub const TAG_GENERALIZED_TIME: u8 = 0x18;
+pub const TAG_SEQUENCE: u8 = 0x30;
+pub const TAG_SET: u8 = 0x31;
+
+/// Returns the tag byte of a context-specific field `[n]`
+pub const fn context(n: u8, constructed: bool) -> u8 {
+    0x80 | if constructed { 0x20 } else { 0 } | n
+}
+
+/// ASN.1 object identifier
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ObjectIdentifier {
+    arcs: Vec<u64>,
+}
+
+impl ObjectIdentifier {
+    /// Creates an object identifier from its arcs
+    pub fn new(arcs: &[u64]) -> VAZ256Result<Self> {
+        if arcs.len() < 2 || arcs[0] > 2 || (arcs[0] < 2 && arcs[1] >= 40) {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        if arcs[0] == 2 && arcs[1] > u64::MAX - 80 {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        Ok(Self { arcs: arcs.to_vec() })
+    }
+
+    /// Parses dotted decimal notation such as `1.3.6.1.4.1.99999.7`
+    pub fn parse(s: &str) -> VAZ256Result<Self> {
+        let arcs = s
+            .split('.')
+            .map(|arc| arc.parse::<u64>().map_err(|_| VAZ256Error::DeserializationError))
+            .collect::<VAZ256Result<Vec<u64>>>()?;
+        Self::new(&arcs)
+    }
+
+    /// Returns the arcs of the identifier
+    pub fn arcs(&self) -> &[u64] {
+        &self.arcs
+    }
+
+    /// Returns a new identifier with `arc` appended
+    pub fn child(&self, arc: u64) -> Self {
+        let mut arcs = self.arcs.clone();
+        arcs.push(arc);
+        Self { arcs }
+    }
+
+    /// Encodes the identifier body (without tag and length)
+    fn to_content(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        push_base128(&mut out, self.arcs[0] * 40 + self.arcs[1]);
+        for &arc in &self.arcs[2..] {
+            push_base128(&mut out, arc);
+        }
+        out
+    }
+
+    /// Decodes the identifier body (without tag and length)
+    fn from_content(content: &[u8]) -> VAZ256Result<Self> {
+        let mut arcs = Vec::new();
+        let mut value: u64 = 0;
+        let mut start = true;
+        for (i, &b) in content.iter().enumerate() {
+            // Leading 0x80 bytes are not minimal
+            if start && b == 0x80 {
+                return Err(VAZ256Error::DeserializationError);
+            }
+            if value > u64::MAX >> 7 {
+                return Err(VAZ256Error::DeserializationError);
+            }
+            value = (value << 7) | (b & 0x7F) as u64;
+            start = b & 0x80 == 0;
+            if start {
+                if arcs.is_empty() {
+                    let first = (value / 40).min(2);
+                    arcs.push(first);
+                    arcs.push(value - first * 40);
+                } else {
+                    arcs.push(value);
+                }
+                value = 0;
+            } else if i == content.len() - 1 {
+                return Err(VAZ256Error::DeserializationError);
+            }
+        }
+        Self::new(&arcs)
+    }
+}
+
+impl fmt::Display for ObjectIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, arc) in self.arcs.iter().enumerate() {
+            if i > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{}", arc)?;
+        }
+        Ok(())
+    }
+}
+
+fn push_base128(out: &mut Vec<u8>, value: u64) {
+    let mut groups = [0u8; 10];
+    let mut n = 0;
+    let mut v = value;
+    loop {
+        groups[n] = (v & 0x7F) as u8;
+        n += 1;
+        v >>= 7;
+        if v == 0 {
+            break;
+        }
+    }
+    for i in (0..n).rev() {
+        out.push(groups[i] | if i > 0 { 0x80 } else { 0 });
+    }
+}
+
+/// Encodes a tag-length-value triple
+pub fn encode_tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(content.len() + 6);
+    out.push(tag);
+    let len = content.len();
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let bytes = (len as u32).to_be_bytes();
+        let skip = bytes.iter().take_while(|&&b| b == 0).count();
+        out.push(0x80 | (4 - skip) as u8);
+        out.extend_from_slice(&bytes[skip..]);
+    }
+    out.extend_from_slice(content);
+    out
+}
+
+/// Encodes a constructed value from already encoded elements
+pub fn encode_constructed(tag: u8, items: &[&[u8]]) -> Vec<u8> {
+    encode_tlv(tag, &items.concat())
+}
+
+/// Encodes a SEQUENCE from already encoded elements
+pub fn encode_sequence(items: &[&[u8]]) -> Vec<u8> {
+    encode_constructed(TAG_SEQUENCE, items)
+}
+
+/// Encodes a SET OF from already encoded elements, sorted as DER requires
+pub fn encode_set_of(items: &[&[u8]]) -> Vec<u8> {
+    let mut sorted = items.to_vec();
+    sorted.sort();
+    encode_constructed(TAG_SET, &sorted)
+}
+
+/// Encodes a non-negative INTEGER
+pub fn encode_integer_u64(value: u64) -> Vec<u8> {
+    encode_integer_bytes(&value.to_be_bytes())
+}
+
+/// Encodes a non-negative INTEGER from its unsigned big-endian magnitude
+pub fn encode_integer_bytes(magnitude: &[u8]) -> Vec<u8> {
+    let skip = magnitude.iter().take_while(|&&b| b == 0).count();
+    let digits = &magnitude[skip..];
+    let mut content = Vec::with_capacity(digits.len() + 1);
+    if digits.is_empty() || digits[0] & 0x80 != 0 {
+        content.push(0);
+    }
+    content.extend_from_slice(digits);
+    encode_tlv(TAG_INTEGER, &content)
+}
+
+/// Encodes a BOOLEAN
+pub fn encode_boolean(value: bool) -> Vec<u8> {
+    encode_tlv(TAG_BOOLEAN, &[if value { 0xFF } else { 0x00 }])
+}
+
+/// Encodes a NULL
+pub fn encode_null() -> Vec<u8> {
+    encode_tlv(TAG_NULL, &[])
+}
+
+/// Encodes an OBJECT IDENTIFIER
+pub fn encode_oid(oid: &ObjectIdentifier) -> Vec<u8> {
+    encode_tlv(TAG_OID, &oid.to_content())
+}
+
+/// Encodes an OCTET STRING
+pub fn encode_octet_string(bytes: &[u8]) -> Vec<u8> {
+    encode_tlv(TAG_OCTET_STRING, bytes)
+}
+
+/// Encodes a BIT STRING with no unused bits
+pub fn encode_bit_string(bytes: &[u8]) -> Vec<u8> {
+    let mut content = Vec::with_capacity(bytes.len() + 1);
+    content.push(0);
+    content.extend_from_slice(bytes);
+    encode_tlv(TAG_BIT_STRING, &content)
+}
+
+/// Encodes a named BIT STRING from its bits, most significant first, trimming trailing zeros
+pub fn encode_named_bits(bits: u16) -> Vec<u8> {
+    let bytes = bits.to_be_bytes();
+    let used = if bits == 0 { 0 } else if bits & 0xFF == 0 { 1 } else { 2 };
+    let unused = if used == 0 { 0 } else { bytes[used - 1].trailing_zeros() as u8 };
+    let mut content = vec![unused];
+    content.extend_from_slice(&bytes[..used]);
+    encode_tlv(TAG_BIT_STRING, &content)
+}
+
+/// Encodes a point in time, given in seconds since the Unix epoch, as UTCTime
+/// for the years 1950 to 2049 and as GeneralizedTime otherwise (RFC 5280)
+pub fn encode_time(unix: i64) -> Vec<u8> {
+    let (year, month, day) = civil_from_days(unix.div_euclid(86400));
+    let secs = unix.rem_euclid(86400);
+    let clock = format!("{:02}{:02}{:02}{:02}{:02}Z", month, day, secs / 3600, secs / 60 % 60, secs % 60);
+    if (1950..2050).contains(&year) {
+        encode_tlv(TAG_UTC_TIME, format!("{:02}{}", year % 100, clock).as_bytes())
+    } else {
+        encode_tlv(TAG_GENERALIZED_TIME, format!("{:04}{}", year, clock).as_bytes())
+    }
+}
+
+/// Days since the Unix epoch to (year, month, day) in the proleptic Gregorian calendar
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// (year, month, day) in the proleptic Gregorian calendar to days since the Unix epoch
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+fn parse_digits(digits: &[u8]) -> VAZ256Result<u32> {
+    digits.iter().try_fold(0u32, |acc, &d| match d {
+        b'0'..=b'9' => Ok(acc * 10 + (d - b'0') as u32),
+        _ => Err(VAZ256Error::DeserializationError),
+    })
+}
+
+/// Strict DER reader over a byte slice
+#[derive(Clone, Copy)]
+pub struct Reader<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(input: &'a [u8]) -> Self {
+        Self { input }
+    }
+
+    /// Returns true if all input has been consumed
+    pub fn is_empty(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    /// Fails unless all input has been consumed
+    pub fn finish(&self) -> VAZ256Result<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(VAZ256Error::DeserializationError)
+        }
+    }
+
+    /// Returns the tag of the next element without consuming it
+    pub fn peek_tag(&self) -> Option<u8> {
+        self.input.first().copied()
+    }
+
+    /// Reads one element, returning its tag, content and complete encoding
+    pub fn read_any(&mut self) -> VAZ256Result<(u8, &'a [u8], &'a [u8])> {
+        let input = self.input;
+        if input.len() < 2 {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let tag = input[0];
+        // High tag numbers are not used by any supported structure
+        if tag & 0x1F == 0x1F {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let (len, header) = match input[1] {
+            n if n < 0x80 => (n as usize, 2),
+            0x81..=0x84 => {
+                let count = (input[1] & 0x7F) as usize;
+                if input.len() < 2 + count || input[2] == 0 {
+                    return Err(VAZ256Error::DeserializationError);
+                }
+                let len = input[2..2 + count]
+                    .iter()
+                    .fold(0usize, |acc, &b| (acc << 8) | b as usize);
+                if len < 0x80 {
+                    return Err(VAZ256Error::DeserializationError);
+                }
+                (len, 2 + count)
+            }
+            _ => return Err(VAZ256Error::DeserializationError),
+        };
+        if input.len() - header < len {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        self.input = &input[header + len..];
+        Ok((tag, &input[header..header + len], &input[..header + len]))
+    }
+
+    /// Reads one element with the expected tag and returns its content
+    pub fn read(&mut self, tag: u8) -> VAZ256Result<&'a [u8]> {
+        match self.read_any()? {
+            (t, content, _) if t == tag => Ok(content),
+            _ => Err(VAZ256Error::DeserializationError),
+        }
+    }
+
+    /// Reads one element with the expected tag and returns its complete encoding
+    pub fn read_raw(&mut self, tag: u8) -> VAZ256Result<&'a [u8]> {
+        match self.read_any()? {
+            (t, _, raw) if t == tag => Ok(raw),
+            _ => Err(VAZ256Error::DeserializationError),
+        }
+    }
+
+    /// Reads an element if the next tag matches
+    pub fn read_optional(&mut self, tag: u8) -> VAZ256Result<Option<&'a [u8]>> {
+        if self.peek_tag() == Some(tag) {
+            self.read(tag).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads a SEQUENCE and returns a reader over its elements
+    pub fn read_sequence(&mut self) -> VAZ256Result<Reader<'a>> {
+        self.read(TAG_SEQUENCE).map(Reader::new)
+    }
+
+    /// Reads a constructed element with the given tag and returns a reader over its elements
+    pub fn read_constructed(&mut self, tag: u8) -> VAZ256Result<Reader<'a>> {
+        self.read(tag).map(Reader::new)
+    }
+
+    /// Reads a non-negative INTEGER and returns its minimal unsigned magnitude
+    pub fn read_integer_bytes(&mut self) -> VAZ256Result<&'a [u8]> {
+        let content = self.read(TAG_INTEGER)?;
+        match content {
+            [] => Err(VAZ256Error::DeserializationError),
+            [b, ..] if b & 0x80 != 0 => Err(VAZ256Error::DeserializationError),
+            [0, b, ..] if b & 0x80 == 0 => Err(VAZ256Error::DeserializationError),
+            [0, rest @ ..] => Ok(rest),
+            _ => Ok(content),
+        }
+    }
+
+    /// Reads a non-negative INTEGER that fits into 64 bits
+    pub fn read_integer_u64(&mut self) -> VAZ256Result<u64> {
+        let magnitude = self.read_integer_bytes()?;
+        if magnitude.len() > 8 {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        Ok(magnitude.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64))
+    }
+
+    /// Reads a BOOLEAN
+    pub fn read_boolean(&mut self) -> VAZ256Result<bool> {
+        match self.read(TAG_BOOLEAN)? {
+            [0x00] => Ok(false),
+            [0xFF] => Ok(true),
+            _ => Err(VAZ256Error::DeserializationError),
+        }
+    }
+
+    /// Reads a NULL
+    pub fn read_null(&mut self) -> VAZ256Result<()> {
+        match self.read(TAG_NULL)? {
+            [] => Ok(()),
+            _ => Err(VAZ256Error::DeserializationError),
+        }
+    }
+
+    /// Reads an OBJECT IDENTIFIER
+    pub fn read_oid(&mut self) -> VAZ256Result<ObjectIdentifier> {
+        ObjectIdentifier::from_content(self.read(TAG_OID)?)
+    }
+
+    /// Reads an OCTET STRING
+    pub fn read_octet_string(&mut self) -> VAZ256Result<&'a [u8]> {
+        self.read(TAG_OCTET_STRING)
+    }
+
+    /// Reads a BIT STRING that must not have unused bits
+    pub fn read_bit_string(&mut self) -> VAZ256Result<&'a [u8]> {
+        match self.read(TAG_BIT_STRING)? {
+            [0, bytes @ ..] => Ok(bytes),
+            _ => Err(VAZ256Error::DeserializationError),
+        }
+    }
+
+    /// Reads a UTCTime or GeneralizedTime in the RFC 5280 profile and returns
+    /// seconds since the Unix epoch
+    pub fn read_time(&mut self) -> VAZ256Result<i64> {
+        let (tag, content, _) = self.read_any()?;
+        let (year, rest) = match (tag, content.len()) {
+            (TAG_UTC_TIME, 13) => {
+                let yy = parse_digits(&content[..2])? as i64;
+                (if yy < 50 { 2000 + yy } else { 1900 + yy }, &content[2..])
+            }
+            (TAG_GENERALIZED_TIME, 15) => (parse_digits(&content[..4])? as i64, &content[4..]),
+            _ => return Err(VAZ256Error::DeserializationError),
+        };
+        if rest[10] != b'Z' {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let month = parse_digits(&rest[0..2])?;
+        let day = parse_digits(&rest[2..4])?;
+        let hour = parse_digits(&rest[4..6])?;
+        let minute = parse_digits(&rest[6..8])?;
+        let second = parse_digits(&rest[8..10])?;
+        let days = days_from_civil(year, month, day);
+        // Reject out of range fields such as February 30th
+        if !(1..=12).contains(&month) || civil_from_days(days) != (year, month, day) {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        Ok(days * 86400 + (hour * 3600 + minute * 60 + second) as i64)
+    }
+
+    /// Reads a named BIT STRING of at most 16 bits, most significant first
+    pub fn read_named_bits(&mut self) -> VAZ256Result<u16> {
+        let content = self.read(TAG_BIT_STRING)?;
+        let (unused, bytes) = match content {
+            [unused, bytes @ ..] if *unused < 8 && bytes.len() <= 2 => (*unused, bytes),
+            _ => return Err(VAZ256Error::DeserializationError),
+        };
+        if bytes.is_empty() && unused != 0 {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let mut padded = [0u8; 2];
+        padded[..bytes.len()].copy_from_slice(bytes);
+        if let Some(last) = bytes.last() {
+            if last & ((1u8 << unused) - 1) != 0 {
+                return Err(VAZ256Error::DeserializationError);
+            }
+        }
+        Ok(u16::from_be_bytes(padded))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_oid_round_trip() {
+        let oid = ObjectIdentifier::parse("1.2.840.113549.1.1.11").unwrap();
+        let der = encode_oid(&oid);
+        assert_eq!(der, [0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x0B]);
+        assert_eq!(Reader::new(&der).read_oid().unwrap(), oid);
+        assert_eq!(oid.to_string(), "1.2.840.113549.1.1.11");
+    }
+
+    #[test]
+    fn test_long_length() {
+        let content = vec![0xAB; 300];
+        let der = encode_octet_string(&content);
+        assert_eq!(&der[..4], &[0x04, 0x82, 0x01, 0x2C]);
+        let mut reader = Reader::new(&der);
+        assert_eq!(reader.read_octet_string().unwrap(), &content[..]);
+        assert!(reader.finish().is_ok());
+    }
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(encode_integer_u64(0), [0x02, 0x01, 0x00]);
+        assert_eq!(encode_integer_u64(128), [0x02, 0x02, 0x00, 0x80]);
+        assert_eq!(Reader::new(&[0x02, 0x02, 0x00, 0x80]).read_integer_u64().unwrap(), 128);
+        // Non-minimal and negative encodings are rejected
+        assert!(Reader::new(&[0x02, 0x02, 0x00, 0x01]).read_integer_u64().is_err());
+        assert!(Reader::new(&[0x02, 0x01, 0xFF]).read_integer_u64().is_err());
+    }
+
+    #[test]
+    fn test_non_minimal_length() {
+        assert!(Reader::new(&[0x04, 0x81, 0x01, 0x00]).read_octet_string().is_err());
+        assert!(Reader::new(&[0x04, 0x80]).read_octet_string().is_err());
+        assert!(Reader::new(&[0x04, 0x05, 0x00]).read_octet_string().is_err());
+    }
+
+    #[test]
+    fn test_time() {
+        // 2025-03-01 12:34:56 UTC
+        let der = encode_time(1740832496);
+        assert_eq!(der, encode_tlv(TAG_UTC_TIME, b"250301123456Z"));
+        assert_eq!(Reader::new(&der).read_time().unwrap(), 1740832496);
+
+        // 9999-12-31 23:59:59 UTC, the RFC 5280 "no expiration" value
+        let der = encode_tlv(TAG_GENERALIZED_TIME, b"99991231235959Z");
+        assert_eq!(Reader::new(&der).read_time().unwrap(), 253402300799);
+        assert_eq!(encode_time(253402300799), der);
+
+        let invalid = encode_tlv(TAG_UTC_TIME, b"250230000000Z");
+        assert!(Reader::new(&invalid).read_time().is_err());
+    }
+
+    #[test]
+    fn test_named_bits() {
+        // digitalSignature | keyCertSign
+        let bits = 0x8400;
+        let der = encode_named_bits(bits);
+        assert_eq!(der, [0x03, 0x02, 0x02, 0x84]);
+        assert_eq!(Reader::new(&der).read_named_bits().unwrap(), bits);
+    }
+}
@@ -0,0 +1,273 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Randomized differential tests against `pqcrypto-dilithium` (PQClean).
+//!
+//! This crate implements Dilithium 3.1, with a 32-byte `tr` and `c~`.
+//! pqcrypto-dilithium 0.5 wraps PQClean's later revision, where both are
+//! 64 bytes as in the FIPS 204 draft: its secret keys are 4896 bytes and
+//! its signatures 4627. Both derive `tr` and `mu` with SHAKE256, so they
+//! agree on the prefix of any hash output they share but not on `mu`
+//! itself, which absorbs `tr`. A signature from one side therefore never
+//! verifies against the same message on the other. The checks below work
+//! at the level of `mu` instead, through
+//! [`sign_dilithium5::signature_internal`] and
+//! [`sign_dilithium5::verify_internal`]:
+//!
+//! - public keys have the same encoding and `t1`, `t0` come from the same
+//!   arithmetic;
+//! - secret keys convert by truncating or extending `tr`;
+//! - given the reference's `mu`, and its `rhoprime` as `rnd` (it signs
+//!   with all-zero randomness), our signer produces the reference
+//!   signature byte for byte, and the reference verifies it;
+//! - our verifier accepts every reference signature;
+//! - with a random bit flipped in the signature or the message, both
+//!   verifiers reject.
+//!
+//! Keys are generated on both sides, and each key signs on both sides.
+
+use pqcrypto_dilithium::dilithium5 as reference;
+use pqcrypto_traits::sign::{DetachedSignature as _, PublicKey as _, SecretKey as _};
+
+use crate::dilithium5::{Dilithium5PublicKey, Dilithium5SecretKey, PUBLICKEYBYTES, SECRETKEYBYTES, SIGNBYTES};
+use crate::entropy::{EntropySource, TestEntropy};
+use crate::fips202::shake256;
+use crate::packing_dilithium5::pack_pk;
+use crate::params_dilithium5::{CRHBYTES, K, POLYT0_PACKEDBYTES, SEEDBYTES};
+use crate::polyvec_dilithium5::{self, Polyveck, Polyvecl};
+use crate::{hex, packing_dilithium5, poly_dilithium5, sign_dilithium5};
+
+/// Length of `tr` in the reference
+const TR_REFERENCE: usize = 64;
+/// Length of `c~` in the reference
+const CTILDE_REFERENCE: usize = 64;
+/// Length of the all-zero `rnd` the reference absorbs into `rhoprime`
+const RND_REFERENCE: usize = 32;
+const SECRETKEYBYTES_REFERENCE: usize = SECRETKEYBYTES + TR_REFERENCE - SEEDBYTES;
+const SIGNBYTES_REFERENCE: usize = SIGNBYTES + CTILDE_REFERENCE - SEEDBYTES;
+/// Offset of `tr` in both secret key layouts
+const TR_OFFSET: usize = 2 * SEEDBYTES;
+
+/// Number of messages signed in each direction
+const MESSAGES: usize = 1500;
+/// Messages signed with each key before generating a new one
+const MESSAGES_PER_KEY: usize = 50;
+const MAX_MESSAGE_LEN: usize = 4096;
+
+fn random_u32(entropy: &mut TestEntropy) -> u32 {
+    let mut bytes = [0u8; 4];
+    entropy.fill_bytes(&mut bytes).unwrap();
+    u32::from_le_bytes(bytes)
+}
+
+fn random_message(entropy: &mut TestEntropy) -> Vec<u8> {
+    let mut message = vec![0u8; random_u32(entropy) as usize % (MAX_MESSAGE_LEN + 1)];
+    entropy.fill_bytes(&mut message).unwrap();
+    message
+}
+
+/// Flips one random bit of `bytes`
+fn flip_bit(bytes: &mut [u8], entropy: &mut TestEntropy) -> usize {
+    let bit = random_u32(entropy) as usize % (8 * bytes.len());
+    bytes[bit / 8] ^= 1 << (bit % 8);
+    bit
+}
+
+/// Drops the second half of the reference `tr`, leaving ours
+fn secret_key_from_reference(sk: &[u8]) -> [u8; SECRETKEYBYTES] {
+    let mut converted = [0u8; SECRETKEYBYTES];
+    converted[..TR_OFFSET + SEEDBYTES].copy_from_slice(&sk[..TR_OFFSET + SEEDBYTES]);
+    converted[TR_OFFSET + SEEDBYTES..].copy_from_slice(&sk[TR_OFFSET + TR_REFERENCE..]);
+    converted
+}
+
+/// Recomputes the 64-byte reference `tr` from `pk`
+fn secret_key_to_reference(sk: &[u8], pk: &[u8]) -> [u8; SECRETKEYBYTES_REFERENCE] {
+    let mut converted = [0u8; SECRETKEYBYTES_REFERENCE];
+    converted[..TR_OFFSET].copy_from_slice(&sk[..TR_OFFSET]);
+    shake256(&mut converted[TR_OFFSET..TR_OFFSET + TR_REFERENCE], TR_REFERENCE, pk, PUBLICKEYBYTES);
+    converted[TR_OFFSET + TR_REFERENCE..].copy_from_slice(&sk[TR_OFFSET + SEEDBYTES..]);
+    converted
+}
+
+/// Recomputes `(pk, packed t0)` from the `rho`, `s1` and `s2` of one of our secret keys
+fn public_key_of(sk: &[u8]) -> ([u8; PUBLICKEYBYTES], Vec<u8>) {
+    let (mut rho, mut tr, mut key) = ([0u8; SEEDBYTES], [0u8; SEEDBYTES], [0u8; SEEDBYTES]);
+    let (mut t0, mut s1, mut s2) = (Polyveck::default(), Polyvecl::default(), Polyveck::default());
+    packing_dilithium5::unpack_sk(&mut rho, &mut tr, &mut key, &mut t0, &mut s1, &mut s2, sk);
+
+    let mut mat = [Polyvecl::default(); K];
+    polyvec_dilithium5::matrix_expand(&mut mat, &rho);
+    polyvec_dilithium5::l_ntt(&mut s1);
+    let mut t1 = Polyveck::default();
+    polyvec_dilithium5::matrix_pointwise_montgomery(&mut t1, &mat, &s1);
+    polyvec_dilithium5::k_reduce(&mut t1);
+    polyvec_dilithium5::k_invntt_tomont(&mut t1);
+    polyvec_dilithium5::k_add(&mut t1, &s2);
+    polyvec_dilithium5::k_caddq(&mut t1);
+    polyvec_dilithium5::k_power2round(&mut t1, &mut t0);
+
+    let mut pk = [0u8; PUBLICKEYBYTES];
+    pack_pk(&mut pk, &rho, &t1);
+    let mut packed_t0 = vec![0u8; K * POLYT0_PACKEDBYTES];
+    for (chunk, poly) in packed_t0.chunks_mut(POLYT0_PACKEDBYTES).zip(&t0.vec) {
+        poly_dilithium5::t0_pack(chunk, poly);
+    }
+    (pk, packed_t0)
+}
+
+/// The reference's `mu = SHAKE256(tr || message)` with its 64-byte `tr`
+fn reference_mu(pk: &[u8], message: &[u8]) -> [u8; CRHBYTES] {
+    let mut input = vec![0u8; TR_REFERENCE + message.len()];
+    shake256(&mut input[..TR_REFERENCE], TR_REFERENCE, pk, PUBLICKEYBYTES);
+    input[TR_REFERENCE..].copy_from_slice(message);
+    let mut mu = [0u8; CRHBYTES];
+    shake256(&mut mu, CRHBYTES, &input, input.len());
+    mu
+}
+
+/// Signs `mu` with our secret key in the reference format. The reference's
+/// `rhoprime = SHAKE256(key || 0^32 || mu)` is passed as `rnd`, so the
+/// signature must equal the reference one.
+fn sign_as_reference(sk: &[u8], mu: &[u8; CRHBYTES]) -> Vec<u8> {
+    let mut input = [0u8; SEEDBYTES + RND_REFERENCE + CRHBYTES];
+    input[..SEEDBYTES].copy_from_slice(&sk[SEEDBYTES..2 * SEEDBYTES]);
+    input[SEEDBYTES + RND_REFERENCE..].copy_from_slice(mu);
+    let mut rhoprime = [0u8; CRHBYTES];
+    shake256(&mut rhoprime, CRHBYTES, &input, input.len());
+
+    let (mut sig, mut ctilde) = ([0u8; SIGNBYTES], [0u8; CTILDE_REFERENCE]);
+    sign_dilithium5::signature_internal(&mut sig, &mut ctilde, mu, sk, Some(&rhoprime));
+    let mut converted = ctilde.to_vec();
+    converted.extend_from_slice(&sig[SEEDBYTES..]);
+    converted
+}
+
+/// Verifies a reference-format signature of `mu` with our verifier
+fn verify_as_reference(sig: &[u8], mu: &[u8; CRHBYTES], pk: &[u8]) -> bool {
+    if sig.len() != SIGNBYTES_REFERENCE {
+        return false;
+    }
+    let mut converted = [0u8; SIGNBYTES];
+    converted[..SEEDBYTES].copy_from_slice(&sig[..SEEDBYTES]);
+    converted[SEEDBYTES..].copy_from_slice(&sig[CTILDE_REFERENCE..]);
+    sign_dilithium5::verify_internal(&converted, &sig[..CTILDE_REFERENCE], mu, pk)
+}
+
+fn reference_verify(sig: &[u8], message: &[u8], pk: &reference::PublicKey) -> bool {
+    let sig = reference::DetachedSignature::from_bytes(sig).unwrap();
+    reference::verify_detached_signature(&sig, message, pk).is_ok()
+}
+
+/// Signs `message` on both sides and verifies each signature on both sides,
+/// then flips a bit in the reference signature or the message and checks
+/// that both verifiers reject
+fn check_message(
+    sk: &[u8],
+    pk: &[u8],
+    reference_sk: &reference::SecretKey,
+    reference_pk: &reference::PublicKey,
+    message: &[u8],
+    entropy: &mut TestEntropy,
+) {
+    let context = || format!("pk {} message {}", hex::encode(pk), hex::encode(message));
+    let mu = reference_mu(pk, message);
+
+    let reference_sig = reference::detached_sign(message, reference_sk).as_bytes().to_vec();
+    assert_eq!(reference_sig.len(), SIGNBYTES_REFERENCE);
+    assert!(reference_verify(&reference_sig, message, reference_pk), "reference rejects itself, {}", context());
+    assert!(verify_as_reference(&reference_sig, &mu, pk), "reference signature rejected, {}", context());
+
+    let sig = sign_as_reference(sk, &mu);
+    assert!(sig == reference_sig, "signatures differ, {}", context());
+    assert!(reference_verify(&sig, message, reference_pk), "reference rejects our signature, {}", context());
+
+    let mut flipped = reference_sig;
+    let mut flipped_message = message.to_vec();
+    let bit = if message.is_empty() || random_u32(entropy).is_multiple_of(2) {
+        flip_bit(&mut flipped, entropy)
+    } else {
+        flip_bit(&mut flipped_message, entropy)
+    };
+    let ours = verify_as_reference(&flipped, &reference_mu(pk, &flipped_message), pk);
+    let theirs = reference_verify(&flipped, &flipped_message, reference_pk);
+    assert_eq!(ours, theirs, "verdicts differ with bit {} flipped, {}", bit, context());
+    assert!(!ours, "accepted with bit {} flipped, {}", bit, context());
+}
+
+#[test]
+fn test_reference_keys() {
+    let mut entropy = TestEntropy::new(b"differential reference keys");
+    for _ in 0..MESSAGES / MESSAGES_PER_KEY {
+        let (pk, sk) = reference::keypair();
+        let (pk, sk) = (pk.as_bytes(), sk.as_bytes());
+        assert_eq!(sk.len(), SECRETKEYBYTES_REFERENCE);
+
+        // Our arithmetic and hashing must reproduce the key
+        let mut tr = [0u8; TR_REFERENCE];
+        shake256(&mut tr, TR_REFERENCE, pk, PUBLICKEYBYTES);
+        assert_eq!(sk[TR_OFFSET..TR_OFFSET + TR_REFERENCE], tr, "tr differs for key {}", hex::encode(sk));
+        let ours = secret_key_from_reference(sk);
+        let (recomputed_pk, t0) = public_key_of(&ours);
+        assert!(recomputed_pk == pk, "public key differs for key {}", hex::encode(sk));
+        assert!(ours[SECRETKEYBYTES - t0.len()..] == t0[..], "t0 differs for key {}", hex::encode(sk));
+
+        let public = Dilithium5PublicKey::from_bytes(pk);
+        let secret = Dilithium5SecretKey::from_bytes(&ours);
+        let reference_pk = reference::PublicKey::from_bytes(pk).unwrap();
+        let reference_sk = reference::SecretKey::from_bytes(sk).unwrap();
+        for _ in 0..MESSAGES_PER_KEY {
+            let message = random_message(&mut entropy);
+            let mut sig = secret.sign(&message);
+            assert!(public.verify(&message, &sig), "pk {} message {}", hex::encode(pk), hex::encode(&message));
+            let bit = flip_bit(&mut sig, &mut entropy);
+            assert!(!public.verify(&message, &sig), "accepted with bit {} flipped", bit);
+
+            check_message(&ours, pk, &reference_sk, &reference_pk, &message, &mut entropy);
+        }
+    }
+}
+
+#[test]
+fn test_our_keys() {
+    let mut entropy = TestEntropy::new(b"differential our keys");
+    for _ in 0..MESSAGES / MESSAGES_PER_KEY {
+        let mut seed = [0u8; SEEDBYTES];
+        entropy.fill_bytes(&mut seed).unwrap();
+        let (mut pk, mut sk) = ([0u8; PUBLICKEYBYTES], [0u8; SECRETKEYBYTES]);
+        sign_dilithium5::keypair(&mut pk, &mut sk, &seed);
+
+        let reference_pk = reference::PublicKey::from_bytes(&pk).unwrap();
+        let reference_sk = reference::SecretKey::from_bytes(&secret_key_to_reference(&sk, &pk)).unwrap();
+        let public = Dilithium5PublicKey::from_bytes(&pk);
+        for _ in 0..MESSAGES_PER_KEY {
+            let message = random_message(&mut entropy);
+            let mut rnd = [0u8; CRHBYTES];
+            entropy.fill_bytes(&mut rnd).unwrap();
+            let mut sig = [0u8; SIGNBYTES];
+            sign_dilithium5::signature(&mut sig, &message, &sk, Some(&rnd));
+            assert!(public.verify(&message, &sig), "seed {} message {}", hex::encode(seed), hex::encode(&message));
+            let bit = flip_bit(&mut sig, &mut entropy);
+            assert!(!public.verify(&message, &sig), "accepted with bit {} flipped", bit);
+
+            check_message(&sk, &pk, &reference_sk, &reference_pk, &message, &mut entropy);
+        }
+    }
+}
+
+#[test]
+fn test_key_conversion_round_trips() {
+    let (pk, sk) = reference::keypair();
+    let ours = secret_key_from_reference(sk.as_bytes());
+    assert!(secret_key_to_reference(&ours, pk.as_bytes())[..] == sk.as_bytes()[..]);
+}
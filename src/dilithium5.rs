@@ -19,6 +19,8 @@
 // signature scheme.
 
 
+use crate::zeroize::Zeroize;
+
 pub const SECRETKEYBYTES: usize = crate::params_dilithium5::SECRETKEYBYTES;
 pub const PUBLICKEYBYTES: usize = crate::params_dilithium5::PUBLICKEYBYTES;
 pub const SIGNBYTES: usize = crate::params_dilithium5::SIGNBYTES;
@@ -36,17 +38,19 @@ impl Dilithium5Keypair {
     /// 
     /// # Arguments
     /// 
-    /// * 'entropy' - optional bytes for determining the generation process
+    /// * 'entropy' - SEEDBYTES of entropy determining the generation process
     /// 
     /// Returns an instance of Dilithium 5 Keypair
-    pub fn generate(entropy: Option<&[u8]>) -> Dilithium5Keypair {
+    pub fn generate(entropy: &[u8]) -> Dilithium5Keypair {
         let mut pk = [0u8; PUBLICKEYBYTES];
         let mut sk = [0u8; SECRETKEYBYTES];
         crate::sign_dilithium5::keypair(&mut pk, &mut sk, entropy);
-        Dilithium5Keypair {
+        let keypair = Dilithium5Keypair {
             secret: Dilithium5SecretKey::from_bytes(&sk),
             public: Dilithium5PublicKey::from_bytes(&pk)
-        }
+        };
+        sk.zeroize();
+        keypair
     }
     /// Compute a signature for a given message.
     ///
@@ -65,6 +69,12 @@ pub struct Dilithium5SecretKey {
     pub bytes: [u8; SECRETKEYBYTES]
 }
 
+impl Drop for Dilithium5SecretKey {
+    fn drop(&mut self) {
+        self.bytes.zeroize(); // Wipe the expanded secret key, which is as sensitive as the seed
+    }
+}
+
 impl Dilithium5SecretKey {
     /// Create a Dilithium 5 SecretKey from bytes.
     /// 
@@ -88,7 +98,21 @@ impl Dilithium5SecretKey {
     /// Returns a Dilithium 5 Signature
     pub fn sign(&self, msg: &[u8]) -> Dilithium5Signature {
         let mut sig: Dilithium5Signature = [0u8; SIGNBYTES];
-        crate::sign_dilithium5::signature(&mut sig, msg, &self.bytes, false);
+        crate::sign_dilithium5::signature(&mut sig, msg, &self.bytes, None);
+        sig
+    }
+
+    /// Compute a randomized signature for a given message.
+    ///
+    /// # Arguments
+    ///
+    /// * 'msg' - message to sign
+    /// * 'rnd' - fresh random bytes replacing the deterministic signing seed
+    ///
+    /// Returns a Dilithium 5 Signature
+    pub fn sign_randomized(&self, msg: &[u8], rnd: &[u8; crate::params_dilithium5::CRHBYTES]) -> Dilithium5Signature {
+        let mut sig: Dilithium5Signature = [0u8; SIGNBYTES];
+        crate::sign_dilithium5::signature(&mut sig, msg, &self.bytes, Some(rnd));
         sig
     }
 }
@@ -147,7 +171,7 @@ mod tests {
             0x1A, 0xDD, 0x2F, 0xD8, 0x1A, 0x25, 0xCC, 0xB1, 0x48, 0x03, 0x2D, 0xCD, 0x73, 0x99,
             0x36, 0x73, 0x7F, 0x2D,
         ];
-        let test = super::Dilithium5Keypair::generate(Some(&seed));
+        let test = super::Dilithium5Keypair::generate(&seed);
         assert_eq!(test.public.to_bytes(), TEST_PK);
         assert_eq!(test.sign(&TEST_MSG), TEST_SIG);
     }
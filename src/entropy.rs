@@ -0,0 +1,133 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Sources of randomness for key generation, randomized signing and salts.
+//!
+//! Every function of the crate that needs random bytes draws them from an
+//! [`EntropySource`]. [`OsEntropy`] asks the operating system and is what
+//! the convenience functions such as [`crate::keygen`] use; `TestEntropy`,
+//! built only for tests and the `test-vectors` feature, expands a seed with
+//! SHAKE256 so that tests get reproducible keys and signatures. Other backends, such as a hardware RNG on a microcontroller,
+//! only have to implement [`EntropySource::fill_bytes`].
+
+#[cfg(any(test, feature = "test-vectors"))]
+use crate::fips202::{keccak_absorb, KeccakState, SHAKE256_RATE};
+#[cfg(any(test, feature = "test-vectors"))]
+use crate::shake::XofReader;
+use crate::VAZ256Result;
+
+#[cfg(any(test, feature = "test-vectors"))]
+const SHAKE_PADDING: u8 = 0x1F;
+
+/// A source of random bytes
+pub trait EntropySource {
+    /// Fills `dest` completely, or fails with [`crate::VAZ256Error::EntropyUnavailable`]
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> VAZ256Result<()>;
+}
+
+/// Randomness of the operating system: the `getrandom` system call on Linux,
+/// the platform source of `rand::rngs::OsRng` elsewhere
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsEntropy;
+
+#[cfg(all(feature = "std", target_os = "linux"))]
+impl EntropySource for OsEntropy {
+    fn fill_bytes(&mut self, mut dest: &mut [u8]) -> VAZ256Result<()> {
+        extern "C" {
+            fn getrandom(buf: *mut u8, buflen: usize, flags: core::ffi::c_uint) -> isize;
+        }
+        while !dest.is_empty() {
+            // SAFETY: `dest` is writable for `dest.len()` bytes
+            let filled = unsafe { getrandom(dest.as_mut_ptr(), dest.len(), 0) };
+            if filled < 0 {
+                if std::io::Error::last_os_error().kind() == std::io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return Err(crate::VAZ256Error::EntropyUnavailable);
+            }
+            dest = &mut dest[filled as usize..];
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(feature = "std", not(target_os = "linux")))]
+impl EntropySource for OsEntropy {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> VAZ256Result<()> {
+        use rand::RngCore;
+        rand::rngs::OsRng.try_fill_bytes(dest).map_err(|_| crate::VAZ256Error::EntropyUnavailable)
+    }
+}
+
+/// Deterministic bytes expanded from a seed with SHAKE256, for tests and
+/// known-answer checks. Anyone who knows the seed knows every byte, so it
+/// must never back real keys, which is why it only exists in tests and with
+/// the `test-vectors` feature.
+#[cfg(any(test, feature = "test-vectors"))]
+#[derive(Clone)]
+pub struct TestEntropy {
+    reader: XofReader,
+}
+
+#[cfg(any(test, feature = "test-vectors"))]
+impl TestEntropy {
+    /// Creates a stream determined entirely by `seed`
+    pub fn new(seed: &[u8]) -> Self {
+        let mut state = KeccakState::default();
+        keccak_absorb(&mut state, SHAKE256_RATE, seed, seed.len());
+        TestEntropy { reader: XofReader::new(state, SHAKE256_RATE, SHAKE_PADDING) }
+    }
+}
+
+#[cfg(any(test, feature = "test-vectors"))]
+impl EntropySource for TestEntropy {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> VAZ256Result<()> {
+        self.reader.squeeze(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shake::Shake256;
+
+    #[test]
+    fn test_test_entropy_is_shake256() {
+        let mut expected = [0u8; 100];
+        let mut shake = Shake256::new();
+        shake.update(b"seed");
+        shake.finalize(&mut expected);
+
+        let mut entropy = TestEntropy::new(b"seed");
+        let (mut a, mut b) = ([0u8; 33], [0u8; 67]);
+        entropy.fill_bytes(&mut a).unwrap();
+        entropy.fill_bytes(&mut b).unwrap();
+        assert_eq!([&a[..], &b[..]].concat(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_os_entropy() {
+        let (mut a, mut b) = ([0u8; 64], [0u8; 64]);
+        OsEntropy.fill_bytes(&mut a).unwrap();
+        OsEntropy.fill_bytes(&mut b).unwrap();
+        assert_ne!(a, b);
+        assert_ne!(a, [0u8; 64]);
+
+        let mut large = vec![0u8; 1 << 20];
+        OsEntropy.fill_bytes(&mut large).unwrap();
+        assert!(large[large.len() - 64..].iter().any(|&byte| byte != 0));
+    }
+}
@@ -70,7 +70,7 @@ pub fn store64(x: &mut [u8], u: u64) {
 }
 
 /// Keccak round constants
-const KECCAKF_ROUNDCONSTANTS: [u64; NROUNDS] = [
+pub(crate) const KECCAKF_ROUNDCONSTANTS: [u64; NROUNDS] = [
     0x0000000000000001u64,
     0x0000000000008082u64,
     0x800000000000808au64,
@@ -341,7 +341,7 @@ pub fn keccakf1600_statepermute(state: &mut [u64]) {
 }
 
 /// Absorb step of Keccak; incremental.
-fn keccak_absorb(state: &mut KeccakState, r: usize, input: &[u8], mut inlen: usize) {
+pub(crate) fn keccak_absorb(state: &mut KeccakState, r: usize, input: &[u8], mut inlen: usize) {
     let mut idx = 0;
     let mut pos = state.pos;
     while pos + inlen >= r {
@@ -363,7 +363,7 @@ fn keccak_absorb(state: &mut KeccakState, r: usize, input: &[u8], mut inlen: usi
 }
 
 /// Finalize absorb step.
-fn keccak_finalize(s: &mut [u64; 25], pos: usize, r: usize, p: u8) {
+pub(crate) fn keccak_finalize(s: &mut [u64; 25], pos: usize, r: usize, p: u8) {
     s[pos / 8] ^= (p as u64) << 8 * (pos % 8);
     s[r / 8 - 1] ^= 1u64 << 63;
 }
@@ -372,25 +372,22 @@ fn keccak_finalize(s: &mut [u64; 25], pos: usize, r: usize, p: u8) {
 /// Modifies the state. Can be called multiple times to keep squeezing, i.e., is incremental.
 ///
 /// Returns new position pos in current block
-fn keccak_squeeze(
+pub(crate) fn keccak_squeeze(
     out: &mut [u8],
     mut outlen: usize,
     s: &mut [u64; 25],
     mut pos: usize,
     r: usize,
 ) -> usize {
+    let mut idx = 0;
     while outlen != 0 {
         if pos == r {
             keccakf1600_statepermute(s);
             pos = 0;
         }
         let mut i = pos;
-        let mut idx = 0;
         while i < r && i < pos + outlen {
-            // println!("i = {}", i);
-            // println!("out[idx] przed przypisaniem: {}", out[idx]);
             out[idx] = (s[i / 8] >> 8 * (i % 8)) as u8;
-            // println!("out[idx] po przypisaniu: {}", out[idx]);
             idx += 1;
             i += 1;
         }
@@ -512,3 +509,27 @@ pub fn shake256_stream_init(state: &mut KeccakState, seed: &[u8], nonce: u16) {
     shake256_absorb(state, &t, 2);
     shake256_finalize(state);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shake256_squeeze_across_blocks() {
+        let mut out = [0u8; 300];
+        shake256(&mut out, 300, b"abc", 3);
+        assert_eq!(crate::hex::encode(&out[..16]), "483366601360a8771c6863080cc4114d");
+        assert_eq!(crate::hex::encode(&out[284..]), "ddcbec7da52b42215c11d5f8ee57f341");
+
+        // Squeezing in uneven pieces must continue the same output stream
+        let mut state = KeccakState::default();
+        shake256_absorb(&mut state, b"abc", 3);
+        shake256_finalize(&mut state);
+        let mut pieces = [0u8; 300];
+        for chunk in pieces.chunks_mut(97) {
+            let len = chunk.len();
+            shake256_squeeze(chunk, len, &mut state);
+        }
+        assert_eq!(pieces, out);
+    }
+}
@@ -0,0 +1,264 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Four independent Keccak-f\[1600\] instances run side by side.
+//!
+//! Lane `i` of all four states is stored in `s[i]`, so one AVX2 register
+//! holds the same lane of every instance. On x86_64 the AVX2 permutation is
+//! picked whenever [`crate::avx2::available`]; elsewhere, or without AVX2,
+//! each instance is permuted with the scalar code from [`crate::fips202`].
+
+use crate::fips202::{keccakf1600_statepermute, load64, store64, SHAKE128_RATE, SHAKE256_RATE};
+use crate::params_dilithium5::{CRHBYTES, SEEDBYTES};
+
+const SHAKE_PADDING: u8 = 0x1F;
+
+/// Four interleaved 1600-bit states
+#[derive(Clone, Copy, Default)]
+pub struct KeccakStateX4 {
+    s: [[u64; 4]; 25],
+}
+
+impl KeccakStateX4 {
+    /// Applies the permutation to all four states
+    fn permute(&mut self) {
+        #[cfg(target_arch = "x86_64")]
+        if crate::avx2::available() {
+            // SAFETY: AVX2 support was just checked
+            unsafe { avx2::keccakf1600_x4(&mut self.s) };
+            return;
+        }
+        self.permute_portable();
+    }
+
+    /// Applies the scalar permutation to each state in turn
+    fn permute_portable(&mut self) {
+        for lane in 0..4 {
+            let mut s = [0u64; 25];
+            for (word, lanes) in s.iter_mut().zip(self.s.iter()) {
+                *word = lanes[lane];
+            }
+            keccakf1600_statepermute(&mut s);
+            for (lanes, word) in self.s.iter_mut().zip(s) {
+                lanes[lane] = word;
+            }
+        }
+    }
+
+    /// Absorbs `seed[..seedlen] | nonce` into each state and pads it, all in a single block.
+    /// Instances without a nonce absorb nonce 0.
+    fn stream_init(&mut self, seed: &[u8], seedlen: usize, nonces: &[u16], rate: usize) {
+        self.s = [[0u64; 4]; 25];
+        for lane in 0..4 {
+            let nonce = nonces.get(lane).copied().unwrap_or(0);
+            let mut block = [0u8; SHAKE128_RATE];
+            block[..seedlen].copy_from_slice(&seed[..seedlen]);
+            block[seedlen..seedlen + 2].copy_from_slice(&nonce.to_le_bytes());
+            block[seedlen + 2] ^= SHAKE_PADDING;
+            block[rate - 1] ^= 0x80;
+            for i in 0..rate / 8 {
+                self.s[i][lane] = load64(&block[8 * i..]);
+            }
+        }
+    }
+
+    /// Squeezes `nblocks` full blocks from each state into the matching output buffer
+    fn squeezeblocks(&mut self, out: [&mut [u8]; 4], nblocks: usize, rate: usize) {
+        let mut out = out;
+        for block in 0..nblocks {
+            self.permute();
+            for (lane, buf) in out.iter_mut().enumerate() {
+                for i in 0..rate / 8 {
+                    store64(&mut buf[block * rate + 8 * i..], self.s[i][lane]);
+                }
+            }
+        }
+    }
+}
+
+/// Initializes four SHAKE128 streams over `seed | nonces[i]`, as `shake128_stream_init` does for one
+pub fn shake128x4_stream_init(state: &mut KeccakStateX4, seed: &[u8], nonces: &[u16]) {
+    state.stream_init(seed, SEEDBYTES, nonces, SHAKE128_RATE);
+}
+
+/// Initializes four SHAKE256 streams over `seed | nonces[i]`, as `shake256_stream_init` does for one
+pub fn shake256x4_stream_init(state: &mut KeccakStateX4, seed: &[u8], nonces: &[u16]) {
+    state.stream_init(seed, CRHBYTES, nonces, SHAKE256_RATE);
+}
+
+/// Squeezes `nblocks` SHAKE128 blocks from each of the four streams
+pub fn shake128x4_squeezeblocks(out: [&mut [u8]; 4], nblocks: usize, state: &mut KeccakStateX4) {
+    state.squeezeblocks(out, nblocks, SHAKE128_RATE);
+}
+
+/// Squeezes `nblocks` SHAKE256 blocks from each of the four streams
+pub fn shake256x4_squeezeblocks(out: [&mut [u8]; 4], nblocks: usize, state: &mut KeccakStateX4) {
+    state.squeezeblocks(out, nblocks, SHAKE256_RATE);
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use core::arch::x86_64::*;
+
+    use crate::fips202::KECCAKF_ROUNDCONSTANTS;
+
+    /// Rotation offsets of the rho step, indexed by `x + 5 * y`
+    const RHO: [i32; 25] = [
+        0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+    ];
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    fn rol(x: __m256i, offset: i32) -> __m256i {
+        let left = _mm256_sll_epi64(x, _mm_cvtsi32_si128(offset));
+        let right = _mm256_srl_epi64(x, _mm_cvtsi32_si128(64 - offset));
+        _mm256_or_si256(left, right)
+    }
+
+    /// The Keccak-f\[1600\] permutation on four interleaved states
+    #[target_feature(enable = "avx2")]
+    pub fn keccakf1600_x4(s: &mut [[u64; 4]; 25]) {
+        let mut a = [_mm256_setzero_si256(); 25];
+        for (reg, lanes) in a.iter_mut().zip(s.iter()) {
+            // SAFETY: `lanes` is 32 readable bytes and the load is unaligned
+            *reg = unsafe { _mm256_loadu_si256(lanes.as_ptr().cast()) };
+        }
+
+        let mut b = [_mm256_setzero_si256(); 25];
+        let mut c = [_mm256_setzero_si256(); 5];
+        for rc in KECCAKF_ROUNDCONSTANTS {
+            // Theta
+            for x in 0..5 {
+                c[x] = _mm256_xor_si256(a[x], a[x + 5]);
+                c[x] = _mm256_xor_si256(c[x], a[x + 10]);
+                c[x] = _mm256_xor_si256(c[x], a[x + 15]);
+                c[x] = _mm256_xor_si256(c[x], a[x + 20]);
+            }
+            for x in 0..5 {
+                let d = _mm256_xor_si256(c[(x + 4) % 5], rol(c[(x + 1) % 5], 1));
+                for y in 0..5 {
+                    a[x + 5 * y] = _mm256_xor_si256(a[x + 5 * y], d);
+                }
+            }
+
+            // Rho and pi
+            for x in 0..5 {
+                for y in 0..5 {
+                    b[y + 5 * ((2 * x + 3 * y) % 5)] = rol(a[x + 5 * y], RHO[x + 5 * y]);
+                }
+            }
+
+            // Chi
+            for y in 0..5 {
+                for x in 0..5 {
+                    let t = _mm256_andnot_si256(b[(x + 1) % 5 + 5 * y], b[(x + 2) % 5 + 5 * y]);
+                    a[x + 5 * y] = _mm256_xor_si256(b[x + 5 * y], t);
+                }
+            }
+
+            // Iota
+            a[0] = _mm256_xor_si256(a[0], _mm256_set1_epi64x(rc as i64));
+        }
+
+        for (lanes, reg) in s.iter_mut().zip(a) {
+            // SAFETY: `lanes` is 32 writable bytes and the store is unaligned
+            unsafe { _mm256_storeu_si256(lanes.as_mut_ptr().cast(), reg) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fips202::{self, KeccakState};
+    use alloc::vec::Vec;
+
+    fn sample_state() -> KeccakStateX4 {
+        let mut state = KeccakStateX4::default();
+        for (i, lanes) in state.s.iter_mut().enumerate() {
+            for (lane, word) in lanes.iter_mut().enumerate() {
+                *word = (i as u64 + 1).wrapping_mul(0x9e3779b97f4a7c15) ^ ((lane as u64) << 59);
+            }
+        }
+        state
+    }
+
+    #[test]
+    fn test_permute_matches_scalar() {
+        let mut state = sample_state();
+        let mut expected = [[0u64; 25]; 4];
+        for (lane, s) in expected.iter_mut().enumerate() {
+            for (i, word) in s.iter_mut().enumerate() {
+                *word = state.s[i][lane];
+            }
+            keccakf1600_statepermute(s);
+        }
+
+        let mut portable = state;
+        portable.permute_portable();
+        state.permute();
+        for (lane, s) in expected.iter().enumerate() {
+            for (i, word) in s.iter().enumerate() {
+                assert_eq!(state.s[i][lane], *word);
+                assert_eq!(portable.s[i][lane], *word);
+            }
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_avx2_matches_portable() {
+        if !crate::avx2::available() {
+            return;
+        }
+        let mut state = sample_state();
+        let mut portable = state;
+        for _ in 0..3 {
+            // SAFETY: AVX2 support was just checked
+            unsafe { avx2::keccakf1600_x4(&mut state.s) };
+            portable.permute_portable();
+        }
+        assert_eq!(state.s, portable.s);
+    }
+
+    #[test]
+    fn test_streams_match_scalar() {
+        let seed: Vec<u8> = (0..CRHBYTES as u8).collect();
+        let nonces = [0u16, 1, 0x0102, 0xfffe];
+
+        let mut state = KeccakStateX4::default();
+        shake128x4_stream_init(&mut state, &seed, &nonces);
+        let mut out = [[0u8; 2 * SHAKE128_RATE]; 4];
+        let [o0, o1, o2, o3] = &mut out;
+        shake128x4_squeezeblocks([o0, o1, o2, o3], 2, &mut state);
+        for (nonce, lane) in nonces.iter().zip(&out) {
+            let mut scalar = KeccakState::default();
+            let mut expected = [0u8; 2 * SHAKE128_RATE];
+            fips202::shake128_stream_init(&mut scalar, &seed, *nonce);
+            fips202::shake128_squeezeblocks(&mut expected, 2, &mut scalar);
+            assert_eq!(lane, &expected);
+        }
+
+        shake256x4_stream_init(&mut state, &seed, &nonces[..3]);
+        let mut out = [[0u8; SHAKE256_RATE]; 4];
+        let [o0, o1, o2, o3] = &mut out;
+        shake256x4_squeezeblocks([o0, o1, o2, o3], 1, &mut state);
+        for (nonce, lane) in [0u16, 1, 0x0102, 0].iter().zip(&out) {
+            let mut scalar = KeccakState::default();
+            let mut expected = [0u8; SHAKE256_RATE];
+            fips202::shake256_stream_init(&mut scalar, &seed, *nonce);
+            fips202::shake256_squeezeblocks(&mut expected, 1, &mut scalar);
+            assert_eq!(lane, &expected);
+        }
+    }
+}
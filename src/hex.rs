@@ -1,7 +1,8 @@
 // This is a feature-reduced implementation of Zeroize. 
 // Created by the author to simplify the code and only work with necessary functions.
 
-use std::fmt;
+use core::fmt;
+use alloc::{format, string::String, vec::Vec};
 
 /// Error type for hexadecimal decoding
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -23,6 +24,7 @@ impl fmt::Display for FromHexError {
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for FromHexError {}
 
 /// Encode a slice of bytes as a hex string
@@ -72,6 +74,7 @@ fn decode_nibble(c: u8) -> Result<u8, FromHexError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec;
 
     #[test]
     fn test_encode() {
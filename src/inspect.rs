@@ -0,0 +1,241 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Structural dissection of signatures and keys for debugging.
+//!
+//! The reports expose the fields of the `pack_sig` layout
+//! (`c || z || hints`) and of the embedded Dilithium5 public key
+//! (`rho || t1`), together with the checks that verification performs
+//! before looking at the message.
+
+use alloc::{format, string::ToString, vec};
+
+use crate::json::Value;
+use crate::packing_dilithium5::unpack_sig;
+use crate::params_dilithium5::{BETA, GAMMA1, K, OMEGA, POLYZ_PACKEDBYTES, SEEDBYTES};
+use crate::polyvec_dilithium5::{Polyveck, Polyvecl};
+use crate::{hex, FullPublicKey, PublicKey, SecretKey, VAZ256Error, VAZ256Result};
+use crate::{DILITHIUM5_PUBLIC_KEY_SIZE, DILITHIUM5_SIGNATURE_SIZE, SIGNATURE_SIZE};
+
+/// Offset of the hint section within the Dilithium5 signature
+const HINT_OFFSET: usize = SEEDBYTES + crate::params_dilithium5::L * POLYZ_PACKEDBYTES;
+
+/// Dissected signature
+#[derive(Clone, Debug, PartialEq)]
+pub struct SignatureReport {
+    /// Challenge seed c
+    pub challenge: [u8; SEEDBYTES],
+    /// Largest absolute coefficient of z, unavailable if the signature doesn't unpack
+    pub z_max: Option<i32>,
+    /// Number of hints set in each of the K polynomials
+    pub hint_counts: [usize; K],
+    /// Whether the hint section is canonically encoded
+    pub canonical: bool,
+    /// Seed rho of the embedded Dilithium5 public key
+    pub rho: [u8; SEEDBYTES],
+    /// Compact public key computed from the embedded Dilithium5 key
+    pub public_key: PublicKey,
+    /// Error every verification of this signature fails with, if any
+    pub error: Option<VAZ256Error>,
+}
+
+/// Dissected public key, compact or full
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicKeyReport {
+    /// Compact public key
+    pub public_key: PublicKey,
+    /// Seed rho, known only for full Dilithium5 keys
+    pub rho: Option<[u8; SEEDBYTES]>,
+}
+
+/// Dissected secret key; the seed itself is never part of the report
+#[derive(Clone, Debug, PartialEq)]
+pub struct SecretKeyReport {
+    /// Seed rho of the derived Dilithium5 public key
+    pub rho: [u8; SEEDBYTES],
+    /// Compact public key derived from the seed
+    pub public_key: PublicKey,
+}
+
+fn rho_of(full: &FullPublicKey) -> [u8; SEEDBYTES] {
+    let mut rho = [0u8; SEEDBYTES];
+    rho.copy_from_slice(&full.as_bytes()[..SEEDBYTES]);
+    rho
+}
+
+/// Dissects a raw signature
+pub fn signature(bytes: &[u8]) -> VAZ256Result<SignatureReport> {
+    if bytes.len() != SIGNATURE_SIZE {
+        return Err(VAZ256Error::InvalidLength);
+    }
+    let (sig, pk) = bytes.split_at(DILITHIUM5_SIGNATURE_SIZE);
+
+    let mut challenge = [0u8; SEEDBYTES];
+    let mut z = Polyvecl::default();
+    let mut h = Polyveck::default();
+    let canonical = unpack_sig(&mut challenge, &mut z, &mut h, sig);
+    let z_max = canonical.then(|| z.vec.iter().flat_map(|poly| poly.coeffs.iter()).map(|c| c.abs()).max().unwrap_or(0));
+
+    // Hint positions end at the cumulative counts stored after the OMEGA slots
+    let mut hint_counts = [0usize; K];
+    let mut end = 0;
+    for (i, count) in hint_counts.iter_mut().enumerate() {
+        let next = sig[HINT_OFFSET + OMEGA + i] as usize;
+        *count = next.saturating_sub(end);
+        end = end.max(next);
+    }
+
+    let full = FullPublicKey::from_bytes(pk)?;
+    let error = if z_max.is_none_or(|z_max| z_max >= (GAMMA1 - BETA) as i32) {
+        Some(VAZ256Error::VerificationFailed)
+    } else {
+        None
+    };
+    Ok(SignatureReport {
+        challenge,
+        z_max,
+        hint_counts,
+        canonical,
+        rho: rho_of(&full),
+        public_key: full.public_key(),
+        error,
+    })
+}
+
+/// Dissects a compact (32-byte) or full Dilithium5 public key
+pub fn public_key(bytes: &[u8]) -> VAZ256Result<PublicKeyReport> {
+    if bytes.len() == DILITHIUM5_PUBLIC_KEY_SIZE {
+        let full = FullPublicKey::from_bytes(bytes)?;
+        return Ok(PublicKeyReport { public_key: full.public_key(), rho: Some(rho_of(&full)) });
+    }
+    Ok(PublicKeyReport { public_key: PublicKey::from_bytes(bytes)?, rho: None })
+}
+
+/// Dissects a secret key
+pub fn secret_key(key: &SecretKey) -> SecretKeyReport {
+    let full = key.full_public_key();
+    SecretKeyReport { rho: rho_of(&full), public_key: full.public_key() }
+}
+
+fn error_value(error: Option<VAZ256Error>) -> Value {
+    match error {
+        Some(error) => Value::from(format!("{:?}", error)),
+        None => Value::Null,
+    }
+}
+
+impl SignatureReport {
+    /// Error verifying against `public_key` fails with regardless of the message
+    pub fn error_for(&self, public_key: &PublicKey) -> Option<VAZ256Error> {
+        if &self.public_key != public_key {
+            return Some(VAZ256Error::PublicKeyMismatch);
+        }
+        self.error
+    }
+
+    pub fn to_json(&self) -> Value {
+        Value::Object(vec![
+            ("type".to_string(), Value::from("signature")),
+            ("challenge".to_string(), Value::from(hex::encode(self.challenge))),
+            ("z_max".to_string(), self.z_max.map_or(Value::Null, |z_max| Value::from(z_max as i64))),
+            ("z_bound".to_string(), Value::from((GAMMA1 - BETA) as i64)),
+            ("hint_counts".to_string(), Value::Array(self.hint_counts.iter().map(|&n| Value::from(n)).collect())),
+            ("hint_total".to_string(), Value::from(self.hint_counts.iter().sum::<usize>())),
+            ("canonical".to_string(), Value::from(self.canonical)),
+            ("rho".to_string(), Value::from(hex::encode(self.rho))),
+            ("public_key".to_string(), Value::from(self.public_key.to_hex())),
+            ("error".to_string(), error_value(self.error)),
+        ])
+    }
+}
+
+impl PublicKeyReport {
+    pub fn to_json(&self) -> Value {
+        let rho = self.rho.map_or(Value::Null, |rho| Value::from(hex::encode(rho)));
+        Value::Object(vec![
+            ("type".to_string(), Value::from("public_key")),
+            ("full".to_string(), Value::from(self.rho.is_some())),
+            ("rho".to_string(), rho),
+            ("public_key".to_string(), Value::from(self.public_key.to_hex())),
+        ])
+    }
+}
+
+impl SecretKeyReport {
+    pub fn to_json(&self) -> Value {
+        Value::Object(vec![
+            ("type".to_string(), Value::from("secret_key")),
+            ("rho".to_string(), Value::from(hex::encode(self.rho))),
+            ("public_key".to_string(), Value::from(self.public_key.to_hex())),
+        ])
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{keygen, sign};
+
+    #[test]
+    fn test_signature_report() {
+        let (sk, pk) = keygen().unwrap();
+        let bytes = sign(b"message", &sk).unwrap().to_bytes();
+        let report = signature(&bytes).unwrap();
+        assert!(report.canonical);
+        assert_eq!(report.error, None);
+        assert_eq!(report.error_for(&pk), None);
+        assert_eq!(report.public_key, pk);
+        assert_eq!(&report.challenge[..], &bytes[..SEEDBYTES]);
+        assert!(report.z_max.unwrap() < (GAMMA1 - BETA) as i32);
+        assert!(report.hint_counts.iter().sum::<usize>() <= OMEGA);
+        assert_eq!(report.rho, secret_key(&sk).rho);
+
+        let other = PublicKey::from_bytes(&[0u8; 32]).unwrap();
+        assert_eq!(report.error_for(&other), Some(VAZ256Error::PublicKeyMismatch));
+        assert_eq!(signature(&bytes[1..]).unwrap_err(), VAZ256Error::InvalidLength);
+    }
+
+    #[test]
+    fn test_malformed_signature() {
+        let (sk, _) = keygen().unwrap();
+        let mut bytes = sign(b"message", &sk).unwrap().to_bytes();
+        // An unused hint slot that is not zero breaks the canonical encoding
+        bytes[HINT_OFFSET + OMEGA - 1] = 1;
+        let report = signature(&bytes).unwrap();
+        assert!(!report.canonical);
+        assert_eq!(report.z_max, None);
+        assert_eq!(report.error, Some(VAZ256Error::VerificationFailed));
+
+        // z = GAMMA1 - 0 is the largest encodable coefficient and out of range
+        let mut bytes = sign(b"message", &sk).unwrap().to_bytes();
+        bytes[SEEDBYTES..SEEDBYTES + 3].fill(0);
+        let report = signature(&bytes).unwrap();
+        assert_eq!(report.z_max, Some(GAMMA1 as i32));
+        assert_eq!(report.error, Some(VAZ256Error::VerificationFailed));
+    }
+
+    #[test]
+    fn test_key_reports() {
+        let (sk, pk) = keygen().unwrap();
+        let full = sk.full_public_key();
+        let report = public_key(full.as_bytes()).unwrap();
+        assert_eq!(report.public_key, pk);
+        assert_eq!(report.rho, Some(secret_key(&sk).rho));
+        assert_eq!(public_key(pk.as_bytes()).unwrap().rho, None);
+        assert_eq!(public_key(&[0u8; 33]).unwrap_err(), VAZ256Error::InvalidLength);
+
+        let json = secret_key(&sk).to_json().to_string();
+        assert!(json.contains(&pk.to_hex()));
+        assert!(!json.contains(&sk.to_hex()));
+    }
+}
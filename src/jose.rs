@@ -0,0 +1,509 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! JOSE support: JWS (RFC 7515) in compact and flattened JSON serialization,
+//! JWK/JWKS (RFC 7517) public keys with thumbprints (RFC 7638) and a minimal
+//! JWT (RFC 7519) claims validator.
+//!
+//! VAZ256 has no registered JOSE identifiers, so the private values
+//! `"alg": "VAZ256"` and `"kty": "OKP", "crv": "VAZ256"` are used. The
+//! compact public key is carried in `x` and the optional full Dilithium5 key
+//! in `pub`.
+
+use alloc::{format, string::{String, ToString}, vec, vec::Vec};
+
+use crate::json::Value;
+use crate::sha2::sha256;
+use crate::{base64, sign, verify, FullPublicKey, PublicKey, SecretKey, Signature, VAZ256Error, VAZ256Result};
+
+/// JWS `alg` header value
+pub const ALG: &str = "VAZ256";
+/// JWK `kty` value
+pub const KEY_TYPE: &str = "OKP";
+/// JWK `crv` value
+pub const CURVE: &str = "VAZ256";
+/// Size of a JWK thumbprint
+pub const THUMBPRINT_SIZE: usize = 32;
+
+fn b64_decode(s: &str) -> VAZ256Result<Vec<u8>> {
+    base64::decode_url(s).map_err(|_| VAZ256Error::DeserializationError)
+}
+
+fn member_str<'a>(value: &'a Value, key: &str) -> VAZ256Result<Option<&'a str>> {
+    match value.get(key) {
+        None => Ok(None),
+        Some(v) => v.as_str().map(Some).ok_or(VAZ256Error::DeserializationError),
+    }
+}
+
+/// Protected header parameters of a JWS
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Header {
+    /// Key ID
+    pub kid: Option<String>,
+    /// Media type of the complete JWS, `"JWT"` for tokens
+    pub typ: Option<String>,
+    /// Media type of the payload
+    pub cty: Option<String>,
+}
+
+impl Header {
+    fn to_json(&self) -> Value {
+        let mut members = vec![("alg".to_string(), Value::from(ALG))];
+        for (name, value) in [("kid", &self.kid), ("typ", &self.typ), ("cty", &self.cty)] {
+            if let Some(value) = value {
+                members.push((name.to_string(), Value::from(value.as_str())));
+            }
+        }
+        Value::Object(members)
+    }
+
+    fn from_json(value: &Value) -> VAZ256Result<Self> {
+        if value.as_object().is_none() || member_str(value, "alg")? != Some(ALG) {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        // No header extensions are understood, so any critical one is fatal
+        if value.get("crit").is_some() {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        Ok(Header {
+            kid: member_str(value, "kid")?.map(str::to_string),
+            typ: member_str(value, "typ")?.map(str::to_string),
+            cty: member_str(value, "cty")?.map(str::to_string),
+        })
+    }
+}
+
+/// Signed JWS object
+#[derive(Clone, Debug)]
+pub struct Jws {
+    header: Header,
+    protected: String,
+    unprotected: Option<Value>,
+    payload: String,
+    signature: Vec<u8>,
+}
+
+impl Jws {
+    /// Signs `payload` under the given protected header
+    pub fn sign(payload: &[u8], header: &Header, key: &SecretKey) -> VAZ256Result<Self> {
+        let protected = base64::encode_url(header.to_json().to_string());
+        let payload = base64::encode_url(payload);
+        let signature = sign(format!("{}.{}", protected, payload).as_bytes(), key)?;
+        Ok(Jws {
+            header: header.clone(),
+            protected,
+            unprotected: None,
+            payload,
+            signature: signature.to_bytes().to_vec(),
+        })
+    }
+
+    /// Parses the compact serialization `header.payload.signature`
+    pub fn from_compact(token: &str) -> VAZ256Result<Self> {
+        let mut parts = token.split('.');
+        let (Some(protected), Some(payload), Some(signature), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(VAZ256Error::DeserializationError);
+        };
+        Self::from_parts(protected, None, payload, signature)
+    }
+
+    /// Parses the flattened JSON serialization
+    pub fn from_json(text: &str) -> VAZ256Result<Self> {
+        let value = Value::parse(text)?;
+        let unprotected = value.get("header").cloned();
+        let (Some(protected), Some(payload), Some(signature)) = (
+            member_str(&value, "protected")?,
+            member_str(&value, "payload")?,
+            member_str(&value, "signature")?,
+        ) else {
+            return Err(VAZ256Error::DeserializationError);
+        };
+        Self::from_parts(protected, unprotected, payload, signature)
+    }
+
+    fn from_parts(protected: &str, unprotected: Option<Value>, payload: &str, signature: &str) -> VAZ256Result<Self> {
+        let header_json = String::from_utf8(b64_decode(protected)?).map_err(|_| VAZ256Error::DeserializationError)?;
+        let header_value = Value::parse(&header_json)?;
+        let header = Header::from_json(&header_value)?;
+        if let Some(unprotected) = &unprotected {
+            // Protected and unprotected header parameter names must be disjoint
+            let members = unprotected.as_object().ok_or(VAZ256Error::DeserializationError)?;
+            if members.iter().any(|(name, _)| header_value.get(name).is_some()) {
+                return Err(VAZ256Error::DeserializationError);
+            }
+        }
+        b64_decode(payload)?;
+        Ok(Jws {
+            header,
+            protected: protected.to_string(),
+            unprotected,
+            payload: payload.to_string(),
+            signature: b64_decode(signature)?,
+        })
+    }
+
+    /// Returns the compact serialization
+    pub fn to_compact(&self) -> String {
+        format!("{}.{}.{}", self.protected, self.payload, base64::encode_url(&self.signature))
+    }
+
+    /// Returns the flattened JSON serialization
+    pub fn to_json(&self) -> String {
+        let mut members = vec![("protected".to_string(), Value::from(self.protected.as_str()))];
+        if let Some(unprotected) = &self.unprotected {
+            members.push(("header".to_string(), unprotected.clone()));
+        }
+        members.push(("payload".to_string(), Value::from(self.payload.as_str())));
+        members.push(("signature".to_string(), Value::from(base64::encode_url(&self.signature))));
+        Value::Object(members).to_string()
+    }
+
+    /// Returns the protected header
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the key ID from the protected or, failing that, the unprotected header
+    pub fn kid(&self) -> Option<&str> {
+        self.header
+            .kid
+            .as_deref()
+            .or_else(|| self.unprotected.as_ref()?.get("kid")?.as_str())
+    }
+
+    /// Returns the payload without verifying the signature
+    pub fn unverified_payload(&self) -> Vec<u8> {
+        // Validated when the object was parsed or created
+        base64::decode_url(&self.payload).unwrap()
+    }
+
+    /// Verifies the signature and returns the payload
+    pub fn verify(&self, public_key: &PublicKey) -> VAZ256Result<Vec<u8>> {
+        let signature = Signature::from_bytes(&self.signature)?;
+        verify(format!("{}.{}", self.protected, self.payload).as_bytes(), &signature, public_key)?;
+        Ok(self.unverified_payload())
+    }
+
+    /// Verifies the signature against the key of `keys` named by the `kid`
+    /// header, or against every key when no `kid` is present
+    pub fn verify_with_set(&self, keys: &JwkSet) -> VAZ256Result<Vec<u8>> {
+        match self.kid() {
+            Some(kid) => self.verify(&keys.find(kid).ok_or(VAZ256Error::PublicKeyMismatch)?.public_key),
+            None => keys
+                .keys
+                .iter()
+                .find_map(|jwk| self.verify(&jwk.public_key).ok())
+                .ok_or(VAZ256Error::PublicKeyMismatch),
+        }
+    }
+}
+
+/// VAZ256 public key as a JWK
+#[derive(Clone, Debug, PartialEq)]
+pub struct Jwk {
+    pub public_key: PublicKey,
+    pub full_public_key: Option<FullPublicKey>,
+    pub kid: Option<String>,
+}
+
+impl Jwk {
+    /// Creates a JWK holding only the compact public key
+    pub fn new(public_key: PublicKey) -> Self {
+        Jwk { public_key, full_public_key: None, kid: None }
+    }
+
+    /// Creates a JWK holding the full Dilithium5 key and its compact hash
+    pub fn from_full(full_public_key: FullPublicKey) -> Self {
+        Jwk {
+            public_key: full_public_key.public_key(),
+            full_public_key: Some(full_public_key),
+            kid: None,
+        }
+    }
+
+    /// Sets the key ID
+    pub fn with_kid(mut self, kid: &str) -> Self {
+        self.kid = Some(kid.to_string());
+        self
+    }
+
+    /// Returns the RFC 7638 thumbprint, the SHA-256 digest of the required members
+    pub fn thumbprint(&self) -> [u8; THUMBPRINT_SIZE] {
+        let canonical = Value::Object(vec![
+            ("crv".to_string(), Value::from(CURVE)),
+            ("kty".to_string(), Value::from(KEY_TYPE)),
+            ("x".to_string(), Value::from(base64::encode_url(self.public_key.as_bytes()))),
+        ])
+        .to_string();
+        sha256(canonical.as_bytes())
+    }
+
+    /// Returns the JSON object of the key
+    pub fn to_json(&self) -> Value {
+        let mut members = vec![
+            ("kty".to_string(), Value::from(KEY_TYPE)),
+            ("crv".to_string(), Value::from(CURVE)),
+            ("alg".to_string(), Value::from(ALG)),
+        ];
+        if let Some(kid) = &self.kid {
+            members.push(("kid".to_string(), Value::from(kid.as_str())));
+        }
+        members.push(("x".to_string(), Value::from(base64::encode_url(self.public_key.as_bytes()))));
+        if let Some(full) = &self.full_public_key {
+            members.push(("pub".to_string(), Value::from(base64::encode_url(full.as_bytes()))));
+        }
+        Value::Object(members)
+    }
+
+    /// Reads a key from its JSON object
+    ///
+    /// A full key that does not hash to `x` fails with `PublicKeyMismatch`.
+    /// Private key members are rejected.
+    pub fn from_json(value: &Value) -> VAZ256Result<Self> {
+        if !Self::is_vaz256(value)
+            || member_str(value, "alg")?.is_some_and(|alg| alg != ALG)
+            || value.get("d").is_some()
+        {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let x = member_str(value, "x")?.ok_or(VAZ256Error::DeserializationError)?;
+        let public_key = PublicKey::from_bytes(&b64_decode(x)?)?;
+        let full_public_key = match member_str(value, "pub")? {
+            Some(full) => {
+                let full = FullPublicKey::from_bytes(&b64_decode(full)?)?;
+                if full.public_key() != public_key {
+                    return Err(VAZ256Error::PublicKeyMismatch);
+                }
+                Some(full)
+            }
+            None => None,
+        };
+        Ok(Jwk {
+            public_key,
+            full_public_key,
+            kid: member_str(value, "kid")?.map(str::to_string),
+        })
+    }
+
+    /// Parses a JWK from JSON text
+    pub fn parse(text: &str) -> VAZ256Result<Self> {
+        Self::from_json(&Value::parse(text)?)
+    }
+
+    fn is_vaz256(value: &Value) -> bool {
+        value.get("kty").and_then(Value::as_str) == Some(KEY_TYPE)
+            && value.get("crv").and_then(Value::as_str) == Some(CURVE)
+    }
+}
+
+/// JWK set
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JwkSet {
+    pub keys: Vec<Jwk>,
+}
+
+impl JwkSet {
+    /// Returns the key with the given key ID
+    pub fn find(&self, kid: &str) -> Option<&Jwk> {
+        self.keys.iter().find(|jwk| jwk.kid.as_deref() == Some(kid))
+    }
+
+    /// Returns the JSON text of the set
+    pub fn to_json(&self) -> String {
+        let keys = self.keys.iter().map(Jwk::to_json).collect();
+        Value::Object(vec![("keys".to_string(), Value::Array(keys))]).to_string()
+    }
+
+    /// Parses a JWK set, skipping keys of other types so that mixed sets
+    /// can be used during a migration
+    pub fn parse(text: &str) -> VAZ256Result<Self> {
+        let value = Value::parse(text)?;
+        let keys = value
+            .get("keys")
+            .and_then(Value::as_array)
+            .ok_or(VAZ256Error::DeserializationError)?;
+        let keys = keys
+            .iter()
+            .filter(|key| Jwk::is_vaz256(key))
+            .map(Jwk::from_json)
+            .collect::<VAZ256Result<_>>()?;
+        Ok(JwkSet { keys })
+    }
+}
+
+/// Parameters of JWT claims validation
+#[derive(Clone, Debug, Default)]
+pub struct Validation {
+    /// Current time as seconds since the Unix epoch
+    pub now: i64,
+    /// Allowed clock skew in seconds
+    pub leeway: i64,
+    /// Audience this verifier identifies as
+    pub audience: Option<String>,
+    /// Whether tokens without `exp` are rejected
+    pub require_exp: bool,
+}
+
+/// Validates the `exp`, `nbf` and `aud` claims of a verified JWT payload
+/// and returns the claims set
+///
+/// A token carrying an `aud` claim is rejected unless it names the
+/// configured audience.
+pub fn validate_claims(payload: &[u8], validation: &Validation) -> VAZ256Result<Value> {
+    let text = core::str::from_utf8(payload).map_err(|_| VAZ256Error::DeserializationError)?;
+    let claims = Value::parse(text)?;
+    if claims.as_object().is_none() {
+        return Err(VAZ256Error::DeserializationError);
+    }
+    let now = validation.now as f64;
+    let leeway = validation.leeway as f64;
+
+    match claims.get("exp") {
+        Some(exp) => {
+            let exp = exp.as_f64().ok_or(VAZ256Error::DeserializationError)?;
+            if now >= exp + leeway {
+                return Err(VAZ256Error::ClaimsValidationFailed);
+            }
+        }
+        None if validation.require_exp => return Err(VAZ256Error::ClaimsValidationFailed),
+        None => {}
+    }
+    if let Some(nbf) = claims.get("nbf") {
+        let nbf = nbf.as_f64().ok_or(VAZ256Error::DeserializationError)?;
+        if now + leeway < nbf {
+            return Err(VAZ256Error::ClaimsValidationFailed);
+        }
+    }
+    if let Some(aud) = claims.get("aud") {
+        let matches = |value: &Value| value.as_str().is_some() && value.as_str() == validation.audience.as_deref();
+        let accepted = match aud {
+            Value::String(_) => matches(aud),
+            Value::Array(items) => items.iter().any(matches),
+            _ => return Err(VAZ256Error::DeserializationError),
+        };
+        if !accepted {
+            return Err(VAZ256Error::ClaimsValidationFailed);
+        }
+    } else if validation.audience.is_some() {
+        return Err(VAZ256Error::ClaimsValidationFailed);
+    }
+    Ok(claims)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::keygen;
+
+    #[test]
+    fn test_compact_and_json() {
+        let (sk, pk) = keygen().unwrap();
+        let header = Header { kid: Some("k1".to_string()), ..Default::default() };
+        let jws = Jws::sign(b"hello", &header, &sk).unwrap();
+
+        let compact = Jws::from_compact(&jws.to_compact()).unwrap();
+        assert_eq!(compact.header(), &header);
+        assert_eq!(compact.verify(&pk).unwrap(), b"hello");
+        let flattened = Jws::from_json(&jws.to_json()).unwrap();
+        assert_eq!(flattened.verify(&pk).unwrap(), b"hello");
+
+        let (_, other) = keygen().unwrap();
+        assert!(compact.verify(&other).is_err());
+
+        let token = jws.to_compact();
+        let (head, rest) = token.split_once('.').unwrap();
+        let (_, signature) = rest.split_once('.').unwrap();
+        let tampered = format!("{}.{}.{}", head, base64::encode_url(b"hellO"), signature);
+        assert!(Jws::from_compact(&tampered).unwrap().verify(&pk).is_err());
+    }
+
+    #[test]
+    fn test_rejected_headers() {
+        let (sk, _) = keygen().unwrap();
+        let jws = Jws::sign(b"x", &Header::default(), &sk).unwrap();
+        let token = jws.to_compact();
+        let rest = token.split_once('.').unwrap().1;
+        for header in [r#"{"alg":"none"}"#, r#"{"alg":"VAZ256","crit":["exp"]}"#, "[]"] {
+            let forged = format!("{}.{}", base64::encode_url(header), rest);
+            assert!(Jws::from_compact(&forged).is_err());
+        }
+        assert!(Jws::from_compact("a.b").is_err());
+    }
+
+    #[test]
+    fn test_thumbprint() {
+        // SHA-256 of {"crv":"VAZ256","kty":"OKP","x":"AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8"}
+        let public_key = PublicKey::from_bytes(&core::array::from_fn::<u8, 32, _>(|i| i as u8)).unwrap();
+        assert_eq!(
+            crate::hex::encode(Jwk::new(public_key).thumbprint()),
+            "276be18ebfa50d34ee68cb441d19d0038a2a92c2aa1f95abed3f132ff496bb0a"
+        );
+    }
+
+    #[test]
+    fn test_jwk_and_set() {
+        let (sk, pk) = keygen().unwrap();
+        let jwk = Jwk::from_full(sk.full_public_key()).with_kid("k1");
+        assert_eq!(jwk.public_key, pk);
+        assert_eq!(Jwk::parse(&jwk.to_json().to_string()).unwrap(), jwk);
+        assert_eq!(jwk.thumbprint(), Jwk::new(pk.clone()).thumbprint());
+
+        let (_, other) = keygen().unwrap();
+        let mut mismatched = jwk.clone();
+        mismatched.public_key = other;
+        assert_eq!(
+            Jwk::parse(&mismatched.to_json().to_string()).unwrap_err(),
+            VAZ256Error::PublicKeyMismatch
+        );
+
+        let set = JwkSet { keys: vec![jwk.clone()] };
+        let text = set.to_json().replacen('[', r#"[{"kty":"OKP","crv":"Ed25519","x":"AA"},"#, 1);
+        let parsed = JwkSet::parse(&text).unwrap();
+        assert_eq!(parsed, set);
+
+        let header = Header { kid: Some("k1".to_string()), typ: Some("JWT".to_string()), cty: None };
+        let jws = Jws::sign(b"{}", &header, &sk).unwrap();
+        assert_eq!(jws.verify_with_set(&parsed).unwrap(), b"{}");
+        assert!(jws.verify_with_set(&JwkSet::default()).is_err());
+    }
+
+    #[test]
+    fn test_claims() {
+        let validation = Validation {
+            now: 1_000,
+            leeway: 10,
+            audience: Some("api".to_string()),
+            require_exp: true,
+        };
+        let ok = br#"{"sub":"a","exp":1005,"nbf":1005,"aud":["web","api"]}"#;
+        assert!(validate_claims(ok, &validation).is_ok());
+        for claims in [
+            r#"{"exp":990,"aud":"api"}"#,
+            r#"{"exp":2000,"nbf":1011,"aud":"api"}"#,
+            r#"{"exp":2000,"aud":"web"}"#,
+            r#"{"exp":2000}"#,
+            r#"{"aud":"api"}"#,
+        ] {
+            assert_eq!(
+                validate_claims(claims.as_bytes(), &validation).unwrap_err(),
+                VAZ256Error::ClaimsValidationFailed,
+                "{}",
+                claims
+            );
+        }
+        assert!(validate_claims(br#"{"exp":"soon","aud":"api"}"#, &validation).is_err());
+    }
+}
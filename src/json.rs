@@ -0,0 +1,417 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Minimal JSON (RFC 8259) value type, parser and serializer.
+//!
+//! Objects keep their members in insertion order so that serialization is
+//! deterministic. Numbers are stored as `f64`.
+
+use core::fmt;
+use alloc::{string::{String, ToString}, vec::Vec};
+
+use crate::{VAZ256Error, VAZ256Result};
+
+const MAX_DEPTH: usize = 128;
+
+/// JSON value
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Parses a complete JSON text
+    pub fn parse(text: &str) -> VAZ256Result<Value> {
+        let mut parser = Parser { input: text.as_bytes(), pos: 0 };
+        let value = parser.value(0)?;
+        parser.whitespace();
+        if parser.pos != parser.input.len() {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        Ok(value)
+    }
+
+    /// Returns the member `key` of an object
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Returns the number if it is an integer that `i64` represents exactly
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Number(n) if n.abs() <= (1u64 << 53) as f64 && (*n as i64) as f64 == *n => Some(*n as i64),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&[(String, Value)]> {
+        match self {
+            Value::Object(members) => Some(members),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::String(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::String(s)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Self {
+        Value::Bool(b)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(n: i64) -> Self {
+        Value::Number(n as f64)
+    }
+}
+
+impl From<u64> for Value {
+    fn from(n: u64) -> Self {
+        Value::Number(n as f64)
+    }
+}
+
+impl From<usize> for Value {
+    fn from(n: usize) -> Self {
+        Value::Number(n as f64)
+    }
+}
+
+/// Serializes without insignificant whitespace
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Number(n) if !n.is_finite() => write!(f, "null"),
+            Value::Number(n) if n.abs() < 1e17 && (*n as i64) as f64 == *n => write!(f, "{}", *n as i64),
+            Value::Number(n) => write!(f, "{}", n),
+            Value::String(s) => write_string(f, s),
+            Value::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+            Value::Object(members) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.input.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, literal: &[u8]) -> VAZ256Result<()> {
+        if self.input[self.pos..].starts_with(literal) {
+            self.pos += literal.len();
+            Ok(())
+        } else {
+            Err(VAZ256Error::DeserializationError)
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> VAZ256Result<Value> {
+        if depth > MAX_DEPTH {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        self.whitespace();
+        match self.input.get(self.pos) {
+            Some(b'n') => self.expect(b"null").map(|_| Value::Null),
+            Some(b't') => self.expect(b"true").map(|_| Value::Bool(true)),
+            Some(b'f') => self.expect(b"false").map(|_| Value::Bool(false)),
+            Some(b'"') => self.string().map(Value::String),
+            Some(b'[') => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                self.whitespace();
+                if self.input.get(self.pos) == Some(&b']') {
+                    self.pos += 1;
+                    return Ok(Value::Array(items));
+                }
+                loop {
+                    items.push(self.value(depth + 1)?);
+                    self.whitespace();
+                    match self.input.get(self.pos) {
+                        Some(b',') => self.pos += 1,
+                        Some(b']') => {
+                            self.pos += 1;
+                            return Ok(Value::Array(items));
+                        }
+                        _ => return Err(VAZ256Error::DeserializationError),
+                    }
+                }
+            }
+            Some(b'{') => {
+                self.pos += 1;
+                let mut members: Vec<(String, Value)> = Vec::new();
+                self.whitespace();
+                if self.input.get(self.pos) == Some(&b'}') {
+                    self.pos += 1;
+                    return Ok(Value::Object(members));
+                }
+                loop {
+                    self.whitespace();
+                    if self.input.get(self.pos) != Some(&b'"') {
+                        return Err(VAZ256Error::DeserializationError);
+                    }
+                    let key = self.string()?;
+                    self.whitespace();
+                    self.expect(b":")?;
+                    let value = self.value(depth + 1)?;
+                    // Duplicate member names are ambiguous, so they are rejected
+                    if members.iter().any(|(k, _)| *k == key) {
+                        return Err(VAZ256Error::DeserializationError);
+                    }
+                    members.push((key, value));
+                    self.whitespace();
+                    match self.input.get(self.pos) {
+                        Some(b',') => self.pos += 1,
+                        Some(b'}') => {
+                            self.pos += 1;
+                            return Ok(Value::Object(members));
+                        }
+                        _ => return Err(VAZ256Error::DeserializationError),
+                    }
+                }
+            }
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ => Err(VAZ256Error::DeserializationError),
+        }
+    }
+
+    fn number(&mut self) -> VAZ256Result<Value> {
+        let start = self.pos;
+        let digits = |p: &mut Self| {
+            let from = p.pos;
+            while let Some(b'0'..=b'9') = p.input.get(p.pos) {
+                p.pos += 1;
+            }
+            p.pos - from
+        };
+        if self.input[self.pos] == b'-' {
+            self.pos += 1;
+        }
+        let int_start = self.pos;
+        let n = digits(self);
+        if n == 0 || (n > 1 && self.input[int_start] == b'0') {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        if self.input.get(self.pos) == Some(&b'.') {
+            self.pos += 1;
+            if digits(self) == 0 {
+                return Err(VAZ256Error::DeserializationError);
+            }
+        }
+        if let Some(b'e' | b'E') = self.input.get(self.pos) {
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.input.get(self.pos) {
+                self.pos += 1;
+            }
+            if digits(self) == 0 {
+                return Err(VAZ256Error::DeserializationError);
+            }
+        }
+        // The scanned range is pure ASCII
+        core::str::from_utf8(&self.input[start..self.pos])
+            .ok()
+            .and_then(|s| s.parse::<f64>().ok())
+            .map(Value::Number)
+            .ok_or(VAZ256Error::DeserializationError)
+    }
+
+    fn hex4(&mut self) -> VAZ256Result<u32> {
+        let digits = self.input.get(self.pos..self.pos + 4).ok_or(VAZ256Error::DeserializationError)?;
+        self.pos += 4;
+        // `from_str_radix` alone would also accept a sign such as `+abc`
+        digits.iter().try_fold(0, |code, &digit| {
+            let value = (digit as char).to_digit(16).ok_or(VAZ256Error::DeserializationError)?;
+            Ok(code << 4 | value)
+        })
+    }
+
+    fn string(&mut self) -> VAZ256Result<String> {
+        self.pos += 1;
+        let mut out = Vec::new();
+        loop {
+            let b = *self.input.get(self.pos).ok_or(VAZ256Error::DeserializationError)?;
+            self.pos += 1;
+            match b {
+                b'"' => return String::from_utf8(out).map_err(|_| VAZ256Error::DeserializationError),
+                b'\\' => {
+                    let escape = *self.input.get(self.pos).ok_or(VAZ256Error::DeserializationError)?;
+                    self.pos += 1;
+                    let c = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let mut code = self.hex4()?;
+                            if (0xD800..0xDC00).contains(&code) {
+                                self.expect(b"\\u")?;
+                                let low = self.hex4()?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return Err(VAZ256Error::DeserializationError);
+                                }
+                                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            }
+                            char::from_u32(code).ok_or(VAZ256Error::DeserializationError)?
+                        }
+                        _ => return Err(VAZ256Error::DeserializationError),
+                    };
+                    let mut buf = [0u8; 4];
+                    out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                }
+                0x00..=0x1F => return Err(VAZ256Error::DeserializationError),
+                _ => out.push(b),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let text = r#"{"alg":"VAZ256","n":[1,-2.5,true,null],"s":"a\"b\\c\n\u0001"}"#;
+        let value = Value::parse(text).unwrap();
+        assert_eq!(value.get("alg").and_then(Value::as_str), Some("VAZ256"));
+        assert_eq!(value.get("n").and_then(Value::as_array).map(<[Value]>::len), Some(4));
+        assert_eq!(value.to_string(), text);
+    }
+
+    #[test]
+    fn test_unicode_escapes() {
+        let value = Value::parse(r#""é😀""#).unwrap();
+        assert_eq!(value.as_str(), Some("é😀"));
+        let value = Value::parse(r#""\u00e9\u20AC \ud83d\ude00""#).unwrap();
+        assert_eq!(value.as_str(), Some("é€ 😀"));
+        // Lone, reversed and unpaired surrogates, and non-hex digits
+        for text in [r#""\ud83d""#, r#""\ude00""#, r#""\ude00\ud83d""#, r#""\ud83d\u0041""#, r#""\ud83dx""#] {
+            assert!(Value::parse(text).is_err(), "{}", text);
+        }
+        for text in [r#""\u+abc""#, r#""\u-abc""#, r#""\u 123""#, r#""\u12g4""#, r#""\u12""#] {
+            assert!(Value::parse(text).is_err(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        for text in ["", "{", "[1,]", "01", "1.", "{\"a\":1,\"a\":2}", "\"\t\"", "[] []", "nul"] {
+            assert!(Value::parse(text).is_err(), "{}", text);
+        }
+        let deep = "[".repeat(MAX_DEPTH + 2) + &"]".repeat(MAX_DEPTH + 2);
+        assert!(Value::parse(&deep).is_err());
+    }
+
+    #[test]
+    fn test_integers() {
+        let value = Value::parse("[1700000000, 1.5, 1e3]").unwrap();
+        let items = value.as_array().unwrap();
+        assert_eq!(items[0].as_i64(), Some(1_700_000_000));
+        assert_eq!(items[1].as_i64(), None);
+        assert_eq!(items[2].as_i64(), Some(1000));
+    }
+}
@@ -0,0 +1,251 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! NIST PQC known-answer test files (`PQCsignKAT_*.rsp`).
+//!
+//! The files are generated like `PQCgenKAT_sign.c` does: an AES-256 CTR_DRBG
+//! seeded with the bytes 0 to 47 draws a 48-byte seed and a message of
+//! `33 * (count + 1)` bytes per entry, and each entry's keys come from a
+//! generator reseeded with its own seed.
+//!
+//! `kat/PQCsignKAT_Dilithium5.rsp` holds the 100 entries of the underlying
+//! Dilithium5 (version 3.1, with a 32-byte `tr` and `c~`) with
+//! `sm = signature || msg`. `kat/PQCsignKAT_VAZ256.rsp`
+//! holds the same seeds and messages for VAZ256: the 32-byte secret key drawn
+//! from the entry's generator, the compact public key, and the detached
+//! signature.
+//!
+//! Both files were generated by this crate and are regression snapshots,
+//! not vectors from an independent implementation: the tests pin their
+//! SHA-256 and check that regenerating them gives the same bytes, so any
+//! change to keys or signatures shows up, but a bug already present when
+//! they were generated would not.
+//!
+//! This module is only public with the `test-vectors` feature.
+
+use alloc::{string::{String, ToString}, vec, vec::Vec};
+use core::fmt::Write;
+
+use crate::ctr_drbg::{CtrDrbg, SEED_SIZE};
+use crate::dilithium5::{PUBLICKEYBYTES, SECRETKEYBYTES, SIGNBYTES};
+use crate::params_dilithium5::SEEDBYTES;
+use crate::{hex, keygen_with, sign, sign_dilithium5, VAZ256Error, VAZ256Result, SIGNATURE_SIZE};
+
+/// A parsed response file
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rsp {
+    /// Algorithm name from the `# name` header line
+    pub algorithm: String,
+    pub entries: Vec<Entry>,
+}
+
+/// One `count = ...` block of `name = value` lines
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    fields: Vec<(String, String)>,
+}
+
+impl Entry {
+    /// Raw value of field `name`
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.fields.iter().find(|(field, _)| field == name).map(|(_, value)| value.as_str())
+    }
+
+    /// Hex-decoded value of field `name`
+    pub fn bytes(&self, name: &str) -> VAZ256Result<Vec<u8>> {
+        let value = self.get(name).ok_or(VAZ256Error::DeserializationError)?;
+        hex::decode(value).map_err(|_| VAZ256Error::HexDecodingError)
+    }
+
+    /// Decimal value of field `name`
+    pub fn number(&self, name: &str) -> VAZ256Result<usize> {
+        let value = self.get(name).ok_or(VAZ256Error::DeserializationError)?;
+        value.parse().map_err(|_| VAZ256Error::DeserializationError)
+    }
+}
+
+/// Parses a `.req` or `.rsp` file. Every entry starts with a `count` line.
+pub fn parse(text: &str) -> VAZ256Result<Rsp> {
+    let mut algorithm = String::new();
+    let mut entries: Vec<Entry> = Vec::new();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            if algorithm.is_empty() && entries.is_empty() {
+                algorithm = comment.trim().to_string();
+            }
+            continue;
+        }
+        let (name, value) = line.split_once('=').ok_or(VAZ256Error::DeserializationError)?;
+        let (name, value) = (name.trim(), value.trim());
+        if name == "count" {
+            entries.push(Entry { fields: Vec::new() });
+        }
+        let entry = entries.last_mut().ok_or(VAZ256Error::DeserializationError)?;
+        entry.fields.push((name.to_string(), value.to_string()));
+    }
+    Ok(Rsp { algorithm, entries })
+}
+
+/// The `(seed, msg)` pairs of the first `count` entries
+pub fn requests(count: usize) -> Vec<([u8; SEED_SIZE], Vec<u8>)> {
+    let entropy_input: [u8; SEED_SIZE] = core::array::from_fn(|i| i as u8);
+    let mut drbg = CtrDrbg::new(&entropy_input, None);
+    (0..count)
+        .map(|i| {
+            let mut seed = [0u8; SEED_SIZE];
+            drbg.randombytes(&mut seed);
+            let mut msg = vec![0u8; 33 * (i + 1)];
+            drbg.randombytes(&mut msg);
+            (seed, msg)
+        })
+        .collect()
+}
+
+/// Appends `name = HEX`, printing an empty value as `00` like `fprintBstr`
+fn write_bytes(out: &mut String, name: &str, bytes: &[u8]) {
+    let value = if bytes.is_empty() { "00".to_string() } else { hex::encode(bytes).to_uppercase() };
+    writeln!(out, "{} = {}", name, value).unwrap();
+}
+
+/// Generates the Dilithium5 response file with `count` entries
+pub fn dilithium5_rsp(count: usize) -> String {
+    let mut out = String::from("# Dilithium5\n\n");
+    for (i, (seed, msg)) in requests(count).into_iter().enumerate() {
+        let mut key_seed = [0u8; SEEDBYTES];
+        CtrDrbg::new(&seed, None).randombytes(&mut key_seed);
+        let mut pk = [0u8; PUBLICKEYBYTES];
+        let mut sk = [0u8; SECRETKEYBYTES];
+        sign_dilithium5::keypair(&mut pk, &mut sk, &key_seed);
+        let mut sm = vec![0u8; SIGNBYTES + msg.len()];
+        sign_dilithium5::signature(&mut sm[..SIGNBYTES], &msg, &sk, None);
+        sm[SIGNBYTES..].copy_from_slice(&msg);
+
+        writeln!(out, "count = {}", i).unwrap();
+        write_bytes(&mut out, "seed", &seed);
+        writeln!(out, "mlen = {}", msg.len()).unwrap();
+        write_bytes(&mut out, "msg", &msg);
+        write_bytes(&mut out, "pk", &pk);
+        write_bytes(&mut out, "sk", &sk);
+        writeln!(out, "smlen = {}", sm.len()).unwrap();
+        write_bytes(&mut out, "sm", &sm);
+        out.push('\n');
+    }
+    out
+}
+
+/// Generates the VAZ256 response file with `count` entries
+pub fn vaz256_rsp(count: usize) -> String {
+    let mut out = String::from("# VAZ256\n\n");
+    for (i, (seed, msg)) in requests(count).into_iter().enumerate() {
+        let (secret, public) = keygen_with(&mut CtrDrbg::new(&seed, None)).unwrap();
+        let signature = sign(&msg, &secret).unwrap();
+
+        writeln!(out, "count = {}", i).unwrap();
+        write_bytes(&mut out, "seed", &seed);
+        writeln!(out, "mlen = {}", msg.len()).unwrap();
+        write_bytes(&mut out, "msg", &msg);
+        write_bytes(&mut out, "sk", secret.as_bytes());
+        write_bytes(&mut out, "pk", public.as_bytes());
+        writeln!(out, "siglen = {}", SIGNATURE_SIZE).unwrap();
+        write_bytes(&mut out, "sig", &signature.to_bytes());
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{verify, PublicKey, Signature};
+    use alloc::format;
+
+    const DILITHIUM5_RSP: &str = include_str!("../kat/PQCsignKAT_Dilithium5.rsp");
+    /// SHA-256 of the snapshots, so they can't be regenerated along with a change to the code
+    const DILITHIUM5_RSP_SHA256: &str = "7ded97a6e6c809b43b54c248171d7504fa6a0cab651bf288bb00034782667481";
+    const VAZ256_RSP: &str = include_str!("../kat/PQCsignKAT_VAZ256.rsp");
+    const VAZ256_RSP_SHA256: &str = "ee5164d22906230cff9a39ec9c93551bccf2ed448545ed19f49e199a90006d9e";
+
+    #[test]
+    fn test_entry_zero_matches_reference_vectors() {
+        // The keypair seed and message behind the vectors in `sign_dilithium5` and `dilithium5`
+        let rsp = parse(DILITHIUM5_RSP).unwrap();
+        let entry = &rsp.entries[0];
+        let seed: [u8; SEED_SIZE] = entry.bytes("seed").unwrap().try_into().unwrap();
+        let mut key_seed = [0u8; SEEDBYTES];
+        CtrDrbg::new(&seed, None).randombytes(&mut key_seed);
+        assert_eq!(hex::encode(key_seed), "7c9935a0b07694aa0c6d10e4db6b1add2fd81a25ccb148032dcd739936737f2d");
+        assert_eq!(entry.get("msg"), Some("D81C4D8D734FCBFBEADE3D3F8A039FAA2A2C9957E835AD55B22E75BF57BB556AC8"));
+        assert!(entry.get("pk").unwrap().starts_with("1C0EE1111B08003F28E65E8B3BDEB037CF8F221DFCDAF5950EDB38D506D85B"));
+        assert!(entry.get("sm").unwrap().starts_with("BBF85FFD0E01C80C8C1C1931CD64"));
+    }
+
+    #[test]
+    fn test_dilithium5_kat() {
+        assert_eq!(hex::encode(crate::sha2::sha256(DILITHIUM5_RSP.as_bytes())), DILITHIUM5_RSP_SHA256);
+        assert!(dilithium5_rsp(100) == DILITHIUM5_RSP, "kat/PQCsignKAT_Dilithium5.rsp differs");
+
+        let rsp = parse(DILITHIUM5_RSP).unwrap();
+        assert_eq!(rsp.algorithm, "Dilithium5");
+        assert_eq!(rsp.entries.len(), 100);
+        for (i, entry) in rsp.entries.iter().enumerate() {
+            let (msg, sm) = (entry.bytes("msg").unwrap(), entry.bytes("sm").unwrap());
+            assert_eq!(entry.number("count").unwrap(), i);
+            assert_eq!(entry.number("mlen").unwrap(), msg.len());
+            assert_eq!(entry.number("smlen").unwrap(), sm.len());
+            assert_eq!(sm[SIGNBYTES..], msg);
+            assert!(sign_dilithium5::verify(&sm[..SIGNBYTES], &msg, &entry.bytes("pk").unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_vaz256_kat() {
+        assert_eq!(hex::encode(crate::sha2::sha256(VAZ256_RSP.as_bytes())), VAZ256_RSP_SHA256);
+        assert!(vaz256_rsp(100) == VAZ256_RSP, "kat/PQCsignKAT_VAZ256.rsp differs");
+
+        let rsp = parse(VAZ256_RSP).unwrap();
+        let dilithium5 = parse(DILITHIUM5_RSP).unwrap();
+        assert_eq!(rsp.algorithm, "VAZ256");
+        assert_eq!(rsp.entries.len(), 100);
+        for (entry, reference) in rsp.entries.iter().zip(&dilithium5.entries) {
+            assert_eq!(entry.get("seed"), reference.get("seed"));
+            let msg = entry.bytes("msg").unwrap();
+            let public_key = PublicKey::from_bytes(&entry.bytes("pk").unwrap()).unwrap();
+            let signature = Signature::from_bytes(&entry.bytes("sig").unwrap()).unwrap();
+            assert_eq!(verify(&msg, &signature, &public_key), Ok(()));
+            // The detached signature is the Dilithium5 signature followed by the full public key
+            let sig = entry.get("sig").unwrap();
+            let sm = format!("{}{}", sig.get(..2 * SIGNBYTES).unwrap(), reference.get("msg").unwrap());
+            assert_eq!(Some(sm.as_str()), reference.get("sm"));
+            assert_eq!(sig.get(2 * SIGNBYTES..), reference.get("pk"));
+        }
+    }
+
+    #[test]
+    fn test_parse_request_file() {
+        let rsp = parse("# Dilithium5\n\ncount = 0\nseed = 00FF\nmlen = 33\npk =\n\ncount = 1\n").unwrap();
+        assert_eq!(rsp.entries.len(), 2);
+        assert_eq!(rsp.entries[0].bytes("seed").unwrap(), [0x00, 0xff]);
+        assert_eq!(rsp.entries[0].number("mlen").unwrap(), 33);
+        assert_eq!(rsp.entries[0].get("pk"), Some(""));
+        assert_eq!(rsp.entries[1].get("seed"), None);
+        assert_eq!(rsp.entries[1].bytes("seed"), Err(VAZ256Error::DeserializationError));
+
+        assert_eq!(parse("seed = 00\n"), Err(VAZ256Error::DeserializationError));
+        assert_eq!(parse("count = 0\nnot a field\n"), Err(VAZ256Error::DeserializationError));
+        let invalid = parse("count = 0\nseed = 0G\n").unwrap();
+        assert_eq!(invalid.entries[0].bytes("seed"), Err(VAZ256Error::HexDecodingError));
+    }
+}
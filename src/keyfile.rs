@@ -0,0 +1,472 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Passphrase-encrypted secret key files.
+//!
+//! The container is built on the Keccak-f\[1600\] permutation only:
+//!
+//! - the passphrase is stretched with Balloon hashing (Boneh, Corrigan-Gibbs
+//!   and Schechter) over SHAKE256, which fills `memory_kib` KiB with 64-byte
+//!   blocks and mixes them `iterations` times with data-independent reads;
+//! - the seed is sealed with a keyed duplex (SpongeWrap) of rate 136 bytes
+//!   that authenticates the whole header.
+//!
+//! The binary layout is
+//!
+//! ```text
+//! "VZEK" || version (1) || kdf (1) || memory_kib (u32 BE) || iterations (u32 BE)
+//!        || salt (32) || public key (32) || ciphertext (32) || tag (32)
+//! ```
+//!
+//! and the PEM label is `VAZ256 ENCRYPTED SECRET KEY`. As the salt is fresh
+//! for every file the derived key is never reused, so no nonce is stored.
+
+use crate::entropy::{EntropySource, OsEntropy};
+use crate::fips202::{keccakf1600_statepermute, shake256_absorb, shake256_finalize, shake256_squeeze, KeccakState};
+use crate::zeroize::Zeroize;
+use crate::{pem, PublicKey, SecretKey, VAZ256Error, VAZ256Result, PUBLIC_KEY_SIZE, SECRET_KEY_SIZE};
+
+/// PEM label of encrypted secret keys
+pub const PEM_LABEL: &str = "VAZ256 ENCRYPTED SECRET KEY";
+/// Container format version
+pub const VERSION: u8 = 1;
+/// Identifier of Balloon hashing over SHAKE256
+pub const KDF_BALLOON_SHAKE256: u8 = 1;
+/// Size of the KDF salt
+pub const SALT_SIZE: usize = 32;
+/// Size of the authentication tag
+pub const TAG_SIZE: usize = 32;
+/// Largest KDF memory, 1 GiB, that [`EncryptedSecretKey::from_bytes`] accepts; files asking for
+/// more need [`EncryptedSecretKey::from_bytes_with_max_memory`]
+pub const DEFAULT_MAX_MEMORY_KIB: u32 = 1 << 20;
+
+const MAGIC: &[u8; 4] = b"VZEK";
+const HEADER_SIZE: usize = 4 + 1 + 1 + 4 + 4 + SALT_SIZE + PUBLIC_KEY_SIZE;
+const ENCODED_SIZE: usize = HEADER_SIZE + SECRET_KEY_SIZE + TAG_SIZE;
+
+const BLOCK_SIZE: usize = 64;
+const DELTA: u64 = 3;
+const MIN_MEMORY_KIB: u32 = 8;
+const MAX_MEMORY_KIB: u32 = 1 << 22;
+const MAX_ITERATIONS: u32 = 64;
+
+const RATE: usize = 136;
+const DOMAIN_KEY: u8 = 0x01;
+const DOMAIN_AD: u8 = 0x02;
+const DOMAIN_TEXT: u8 = 0x03;
+const DOMAIN_TAG: u8 = 0x04;
+
+/// Cost parameters of the passphrase KDF; the default of 2 MiB and three
+/// passes takes about a second on a desktop CPU
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KdfParams {
+    /// Memory filled by the KDF, in KiB
+    pub memory_kib: u32,
+    /// Number of mixing passes over the memory
+    pub iterations: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        KdfParams { memory_kib: 2048, iterations: 3 }
+    }
+}
+
+impl KdfParams {
+    fn check(&self, max_memory_kib: u32) -> VAZ256Result<()> {
+        if !(MIN_MEMORY_KIB..=max_memory_kib.min(MAX_MEMORY_KIB)).contains(&self.memory_kib)
+            || !(1..=MAX_ITERATIONS).contains(&self.iterations)
+        {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        Ok(())
+    }
+}
+
+/// SHAKE256 with a 64-byte output over the concatenation of `parts`
+fn hash(counter: &mut u64, parts: &[&[u8]]) -> [u8; BLOCK_SIZE] {
+    let mut state = KeccakState::default();
+    shake256_absorb(&mut state, &counter.to_le_bytes(), 8);
+    for part in parts {
+        shake256_absorb(&mut state, part, part.len());
+    }
+    shake256_finalize(&mut state);
+    let mut out = [0u8; BLOCK_SIZE];
+    shake256_squeeze(&mut out, BLOCK_SIZE, &mut state);
+    *counter += 1;
+    out
+}
+
+/// Balloon hashing of `passphrase` into a 32-byte key, failing with
+/// [`VAZ256Error::OutOfMemory`] if the blocks can't be allocated
+fn derive_key(passphrase: &[u8], salt: &[u8; SALT_SIZE], params: &KdfParams) -> VAZ256Result<[u8; 32]> {
+    let blocks = params.memory_kib as usize * 1024 / BLOCK_SIZE;
+    let mut buffer = Vec::new();
+    buffer.try_reserve_exact(blocks).map_err(|_| VAZ256Error::OutOfMemory)?;
+    buffer.resize(blocks, [0u8; BLOCK_SIZE]);
+    let mut counter = 0u64;
+
+    buffer[0] = hash(&mut counter, &[passphrase, salt]);
+    for m in 1..blocks {
+        buffer[m] = hash(&mut counter, &[&buffer[m - 1]]);
+    }
+
+    for t in 0..params.iterations as u64 {
+        for m in 0..blocks {
+            let previous = buffer[(m + blocks - 1) % blocks];
+            buffer[m] = hash(&mut counter, &[&previous, &buffer[m]]);
+            for i in 0..DELTA {
+                let indices = [t.to_le_bytes(), (m as u64).to_le_bytes(), i.to_le_bytes()].concat();
+                let other = hash(&mut counter, &[salt, &indices]);
+                let other = (u64::from_le_bytes(other[..8].try_into().unwrap()) % blocks as u64) as usize;
+                let other = buffer[other];
+                buffer[m] = hash(&mut counter, &[&buffer[m], &other]);
+            }
+        }
+    }
+
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&buffer[blocks - 1][..32]);
+    for block in buffer.iter_mut() {
+        block.zeroize();
+    }
+    Ok(key)
+}
+
+/// Keyed Keccak duplex of rate 136 bytes with domain-separated phases
+struct Duplex {
+    state: [u64; 25],
+}
+
+impl Duplex {
+    fn new(key: &[u8; 32]) -> Self {
+        let mut duplex = Duplex { state: [0u64; 25] };
+        duplex.absorb(key, DOMAIN_KEY);
+        duplex
+    }
+
+    fn byte(&self, i: usize) -> u8 {
+        (self.state[i / 8] >> (8 * (i % 8))) as u8
+    }
+
+    fn xor_byte(&mut self, i: usize, b: u8) {
+        self.state[i / 8] ^= (b as u64) << (8 * (i % 8));
+    }
+
+    fn pad_and_permute(&mut self, len: usize, domain: u8) {
+        self.xor_byte(len, domain);
+        self.xor_byte(RATE - 1, 0x80);
+        keccakf1600_statepermute(&mut self.state);
+    }
+
+    /// Absorbs `data`, finishing with a padded block carrying `domain`
+    fn absorb(&mut self, data: &[u8], domain: u8) {
+        let mut chunks = data.chunks_exact(RATE);
+        for chunk in &mut chunks {
+            for (i, &b) in chunk.iter().enumerate() {
+                self.xor_byte(i, b);
+            }
+            keccakf1600_statepermute(&mut self.state);
+        }
+        let rest = chunks.remainder();
+        for (i, &b) in rest.iter().enumerate() {
+            self.xor_byte(i, b);
+        }
+        self.pad_and_permute(rest.len(), domain);
+    }
+
+    /// Encrypts or decrypts `data` in place; the ciphertext is what gets absorbed
+    fn crypt(&mut self, data: &mut [u8], encrypt: bool) {
+        let mut chunks = data.chunks_mut(RATE).peekable();
+        let mut last = 0;
+        while let Some(chunk) = chunks.next() {
+            for (i, b) in chunk.iter_mut().enumerate() {
+                let keystream = self.byte(i);
+                let ciphertext = if encrypt { *b ^ keystream } else { *b };
+                *b ^= keystream;
+                self.xor_byte(i, ciphertext ^ keystream);
+            }
+            last = chunk.len();
+            if chunks.peek().is_some() {
+                keccakf1600_statepermute(&mut self.state);
+            }
+        }
+        if last == RATE {
+            keccakf1600_statepermute(&mut self.state);
+            last = 0;
+        }
+        self.pad_and_permute(last, DOMAIN_TEXT);
+    }
+
+    fn tag(&mut self) -> [u8; TAG_SIZE] {
+        self.pad_and_permute(0, DOMAIN_TAG);
+        let mut tag = [0u8; TAG_SIZE];
+        for (i, b) in tag.iter_mut().enumerate() {
+            *b = self.byte(i);
+        }
+        tag
+    }
+}
+
+impl Drop for Duplex {
+    fn drop(&mut self) {
+        self.state.fill(0);
+    }
+}
+
+/// Secret key sealed under a passphrase
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncryptedSecretKey {
+    params: KdfParams,
+    salt: [u8; SALT_SIZE],
+    public_key: [u8; PUBLIC_KEY_SIZE],
+    ciphertext: [u8; SECRET_KEY_SIZE],
+    tag: [u8; TAG_SIZE],
+}
+
+impl EncryptedSecretKey {
+    /// Encrypts `key` under `passphrase` with a fresh random salt
+    pub fn encrypt(key: &SecretKey, passphrase: &[u8], params: KdfParams) -> VAZ256Result<Self> {
+        let mut salt = [0u8; SALT_SIZE];
+        OsEntropy.fill_bytes(&mut salt)?;
+        Self::encrypt_with_salt(key, passphrase, params, salt)
+    }
+
+    fn encrypt_with_salt(
+        key: &SecretKey,
+        passphrase: &[u8],
+        params: KdfParams,
+        salt: [u8; SALT_SIZE],
+    ) -> VAZ256Result<Self> {
+        params.check(MAX_MEMORY_KIB)?;
+        Self::seal(key.as_bytes(), *key.public_key().as_bytes(), passphrase, params, salt)
+    }
+
+    fn seal(
+        seed: &[u8; SECRET_KEY_SIZE],
+        public_key: [u8; PUBLIC_KEY_SIZE],
+        passphrase: &[u8],
+        params: KdfParams,
+        salt: [u8; SALT_SIZE],
+    ) -> VAZ256Result<Self> {
+        let mut sealed = EncryptedSecretKey {
+            params,
+            salt,
+            public_key,
+            ciphertext: *seed,
+            tag: [0u8; TAG_SIZE],
+        };
+        let mut derived = derive_key(passphrase, &salt, &params)?;
+        let mut duplex = Duplex::new(&derived);
+        derived.zeroize();
+        duplex.absorb(&sealed.header(), DOMAIN_AD);
+        duplex.crypt(&mut sealed.ciphertext, true);
+        sealed.tag = duplex.tag();
+        Ok(sealed)
+    }
+
+    /// Decrypts the secret key and checks that it regenerates the stored public key
+    pub fn decrypt(&self, passphrase: &[u8]) -> VAZ256Result<SecretKey> {
+        self.params.check(MAX_MEMORY_KIB)?;
+        let mut derived = derive_key(passphrase, &self.salt, &self.params)?;
+        let mut duplex = Duplex::new(&derived);
+        derived.zeroize();
+        duplex.absorb(&self.header(), DOMAIN_AD);
+        let mut seed = self.ciphertext;
+        duplex.crypt(&mut seed, false);
+
+        let tag = duplex.tag();
+        let difference = tag.iter().zip(self.tag.iter()).fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        if difference != 0 {
+            seed.zeroize();
+            return Err(VAZ256Error::DecryptionFailed);
+        }
+        let key = SecretKey::new(seed);
+        seed.zeroize();
+        if key.public_key().as_bytes() != &self.public_key {
+            return Err(VAZ256Error::PublicKeyMismatch);
+        }
+        Ok(key)
+    }
+
+    /// Returns the public key stored in the clear
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from_bytes(&self.public_key).expect("public key has a fixed size")
+    }
+
+    /// Returns the KDF cost parameters
+    pub fn params(&self) -> KdfParams {
+        self.params
+    }
+
+    fn header(&self) -> [u8; HEADER_SIZE] {
+        let mut header = [0u8; HEADER_SIZE];
+        header[..4].copy_from_slice(MAGIC);
+        header[4] = VERSION;
+        header[5] = KDF_BALLOON_SHAKE256;
+        header[6..10].copy_from_slice(&self.params.memory_kib.to_be_bytes());
+        header[10..14].copy_from_slice(&self.params.iterations.to_be_bytes());
+        header[14..14 + SALT_SIZE].copy_from_slice(&self.salt);
+        header[14 + SALT_SIZE..].copy_from_slice(&self.public_key);
+        header
+    }
+
+    /// Serializes the container
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(ENCODED_SIZE);
+        bytes.extend_from_slice(&self.header());
+        bytes.extend_from_slice(&self.ciphertext);
+        bytes.extend_from_slice(&self.tag);
+        bytes
+    }
+
+    /// Parses a container, rejecting unknown versions and out-of-range KDF costs, including
+    /// memory above [`DEFAULT_MAX_MEMORY_KIB`]
+    pub fn from_bytes(bytes: &[u8]) -> VAZ256Result<Self> {
+        Self::from_bytes_with_max_memory(bytes, DEFAULT_MAX_MEMORY_KIB)
+    }
+
+    /// Parses a container whose KDF may fill up to `max_memory_kib` KiB, which is capped at 4 GiB.
+    /// Decrypting allocates that much before the passphrase can be checked, so only raise the limit
+    /// for files from a trusted source.
+    pub fn from_bytes_with_max_memory(bytes: &[u8], max_memory_kib: u32) -> VAZ256Result<Self> {
+        if bytes.len() != ENCODED_SIZE {
+            return Err(VAZ256Error::InvalidLength);
+        }
+        if &bytes[..4] != MAGIC || bytes[4] != VERSION || bytes[5] != KDF_BALLOON_SHAKE256 {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let params = KdfParams {
+            memory_kib: u32::from_be_bytes(bytes[6..10].try_into().unwrap()),
+            iterations: u32::from_be_bytes(bytes[10..14].try_into().unwrap()),
+        };
+        params.check(max_memory_kib)?;
+        let field = |start: usize| -> [u8; 32] { bytes[start..start + 32].try_into().unwrap() };
+        Ok(EncryptedSecretKey {
+            params,
+            salt: field(14),
+            public_key: field(14 + SALT_SIZE),
+            ciphertext: field(HEADER_SIZE),
+            tag: field(HEADER_SIZE + SECRET_KEY_SIZE),
+        })
+    }
+
+    /// Encodes the container as PEM
+    pub fn to_pem(&self) -> String {
+        pem::encode(PEM_LABEL, &self.to_bytes())
+    }
+
+    /// Decodes a PEM container
+    pub fn from_pem(text: &str) -> VAZ256Result<Self> {
+        Self::from_bytes(&pem::decode_label(text, PEM_LABEL)?)
+    }
+
+    /// Decodes a PEM container with the KDF memory limit of [`Self::from_bytes_with_max_memory`]
+    pub fn from_pem_with_max_memory(text: &str, max_memory_kib: u32) -> VAZ256Result<Self> {
+        Self::from_bytes_with_max_memory(&pem::decode_label(text, PEM_LABEL)?, max_memory_kib)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hex, keygen};
+
+    const FAST: KdfParams = KdfParams { memory_kib: 8, iterations: 1 };
+
+    #[test]
+    fn test_derive_key() {
+        let salt = [0x5au8; SALT_SIZE];
+        let derive = |passphrase: &[u8], salt: &[u8; SALT_SIZE], params: &KdfParams| {
+            derive_key(passphrase, salt, params).unwrap()
+        };
+        let key = derive(b"passphrase", &salt, &FAST);
+        assert_eq!(key, derive(b"passphrase", &salt, &FAST));
+        assert_ne!(key, derive(b"passphrasf", &salt, &FAST));
+        assert_ne!(key, derive(b"passphrase", &[0x5bu8; SALT_SIZE], &FAST));
+        assert_ne!(key, derive(b"passphrase", &salt, &KdfParams { memory_kib: 8, iterations: 2 }));
+        assert_ne!(key, derive(b"passphrase", &salt, &KdfParams { memory_kib: 16, iterations: 1 }));
+    }
+
+    #[test]
+    fn test_duplex() {
+        let key = [7u8; 32];
+        for len in [0, 1, 32, RATE - 1, RATE, RATE + 1, 3 * RATE] {
+            let plaintext: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let mut sealing = Duplex::new(&key);
+            sealing.absorb(b"header", DOMAIN_AD);
+            let mut data = plaintext.clone();
+            sealing.crypt(&mut data, true);
+            let tag = sealing.tag();
+
+            let mut opening = Duplex::new(&key);
+            opening.absorb(b"header", DOMAIN_AD);
+            opening.crypt(&mut data, false);
+            assert_eq!(data, plaintext);
+            assert_eq!(opening.tag(), tag);
+
+            let mut other = Duplex::new(&key);
+            other.absorb(b"headeR", DOMAIN_AD);
+            other.crypt(&mut data.clone(), true);
+            assert_ne!(other.tag(), tag);
+        }
+    }
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let (sk, pk) = keygen().unwrap();
+        let sealed = EncryptedSecretKey::encrypt(&sk, b"correct horse", FAST).unwrap();
+        assert_eq!(sealed.public_key(), pk);
+        let pem = sealed.to_pem();
+        assert!(pem.starts_with("-----BEGIN VAZ256 ENCRYPTED SECRET KEY-----\n"));
+
+        let parsed = EncryptedSecretKey::from_pem(&pem).unwrap();
+        assert_eq!(parsed, sealed);
+        assert_eq!(parsed.decrypt(b"correct horse").unwrap().to_hex(), sk.to_hex());
+        assert!(matches!(parsed.decrypt(b"wrong horse"), Err(VAZ256Error::DecryptionFailed)));
+
+        let mut bytes = sealed.to_bytes();
+        bytes[14] ^= 1;
+        let tampered = EncryptedSecretKey::from_bytes(&bytes).unwrap();
+        assert!(matches!(tampered.decrypt(b"correct horse"), Err(VAZ256Error::DecryptionFailed)));
+    }
+
+    #[test]
+    fn test_header_validation() {
+        let sk = SecretKey::from_hex(&hex::encode([1u8; 32])).unwrap();
+        let sealed = EncryptedSecretKey::encrypt_with_salt(&sk, b"pw", FAST, [2u8; SALT_SIZE]).unwrap();
+        let bytes = sealed.to_bytes();
+        assert_eq!(bytes.len(), ENCODED_SIZE);
+        assert_eq!(hex::encode(&bytes[..14]), "565a454b01010000000800000001");
+
+        let mut wrong_version = bytes.clone();
+        wrong_version[4] = 2;
+        assert_eq!(EncryptedSecretKey::from_bytes(&wrong_version).unwrap_err(), VAZ256Error::DeserializationError);
+        let mut huge = bytes.clone();
+        huge[6..10].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert_eq!(EncryptedSecretKey::from_bytes(&huge).unwrap_err(), VAZ256Error::DeserializationError);
+        assert!(EncryptedSecretKey::from_bytes_with_max_memory(&huge, u32::MAX).is_err());
+        let mut large = bytes.clone();
+        large[6..10].copy_from_slice(&(DEFAULT_MAX_MEMORY_KIB * 2).to_be_bytes());
+        assert_eq!(EncryptedSecretKey::from_bytes(&large).unwrap_err(), VAZ256Error::DeserializationError);
+        let opted_in = EncryptedSecretKey::from_bytes_with_max_memory(&large, DEFAULT_MAX_MEMORY_KIB * 2).unwrap();
+        assert_eq!(opted_in.params().memory_kib, DEFAULT_MAX_MEMORY_KIB * 2);
+        assert_eq!(EncryptedSecretKey::from_bytes(&bytes[1..]).unwrap_err(), VAZ256Error::InvalidLength);
+        assert!(EncryptedSecretKey::encrypt(&sk, b"pw", KdfParams { memory_kib: 1, iterations: 1 }).is_err());
+
+        // A correctly sealed seed whose stored public key belongs to another seed
+        let mismatched =
+            EncryptedSecretKey::seal(sk.as_bytes(), [0u8; PUBLIC_KEY_SIZE], b"pw", FAST, [2u8; SALT_SIZE]).unwrap();
+        assert!(matches!(mismatched.decrypt(b"pw"), Err(VAZ256Error::PublicKeyMismatch)));
+    }
+}
@@ -1,9 +1,72 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Tests use `alloc` and the hex helpers even when the library is built without them
+#[cfg(any(feature = "alloc", test))]
+extern crate alloc;
+
 mod vaz256;
 pub use vaz256::*;
+mod self_test;
+pub use self_test::self_test;
+pub mod entropy;
+// Test harnesses, only public with the `test-vectors` feature. The CTR_DRBG is
+// deterministic and stays private so it can't be mistaken for an entropy source.
+#[cfg(any(feature = "test-vectors", test))]
+mod aes;
+#[cfg(any(feature = "test-vectors", test))]
+mod ctr_drbg;
+#[cfg(any(feature = "test-vectors", all(test, feature = "alloc")))]
+pub mod kat;
+#[cfg(any(feature = "test-vectors", all(test, feature = "alloc")))]
+pub mod wycheproof;
+#[cfg(any(feature = "test-vectors", all(test, feature = "alloc")))]
+pub mod acvp;
 mod dilithium5;
 mod fips202;
+mod fips202x4;
+#[cfg(target_arch = "x86_64")]
+mod avx2;
 mod zeroize;
-mod hex;
+#[cfg(any(feature = "alloc", test))]
+pub mod hex;
+#[cfg(feature = "alloc")]
+pub mod base64;
+#[cfg(feature = "alloc")]
+mod sha2;
+#[cfg(feature = "alloc")]
+pub mod der;
+#[cfg(feature = "alloc")]
+pub mod pem;
+#[cfg(feature = "alloc")]
+pub mod pkcs8;
+#[cfg(feature = "alloc")]
+pub mod x509;
+#[cfg(feature = "alloc")]
+pub mod cms;
+#[cfg(feature = "alloc")]
+pub mod json;
+#[cfg(feature = "alloc")]
+pub mod jose;
+#[cfg(feature = "alloc")]
+pub mod cbor;
+#[cfg(feature = "alloc")]
+pub mod cose;
+#[cfg(feature = "std")]
+pub mod ssh;
+#[cfg(feature = "std")]
+pub mod minisign;
+#[cfg(feature = "std")]
+pub mod openpgp;
+#[cfg(feature = "std")]
+pub mod manifest;
+#[cfg(feature = "alloc")]
+pub mod inspect;
+#[cfg(feature = "std")]
+pub mod keyfile;
+pub mod sp800_185;
+pub mod sha3;
+pub mod shake;
+pub mod low_stack;
 mod ntt;
 mod packing_dilithium5;
 mod params_dilithium5;
@@ -12,4 +75,6 @@ mod polyvec_dilithium5;
 mod rounding_dilithium5;
 mod reduce;
 mod sign_dilithium5;
-
+mod sign_dilithium5_small;
+#[cfg(all(test, feature = "std"))]
+mod differential_dilithium5;
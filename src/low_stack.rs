@@ -0,0 +1,150 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Key generation, signing and verification within a small stack.
+//!
+//! [`crate::sign`] and [`crate::verify`] expand the whole 8×7 matrix A
+//! (57 KB) and keep every intermediate vector, so they need a few hundred
+//! kilobytes of stack. The functions here return the same keys and
+//! signatures while staying under [`STACK_BOUND`], which fits the default
+//! thread stacks of async runtimes and many microcontrollers. They sample A
+//! one polynomial at a time and recompute intermediates instead of storing
+//! them, so signing is about eight times slower and verification about
+//! twice as slow.
+
+use crate::dilithium5::{PUBLICKEYBYTES, SECRETKEYBYTES};
+use crate::entropy::EntropySource;
+#[cfg(feature = "std")]
+use crate::entropy::OsEntropy;
+use crate::fips202::shake256;
+use crate::self_test::{check_small, self_test_small};
+use crate::sign_dilithium5_small;
+use crate::zeroize::Zeroize;
+use crate::{
+    FullPublicKey, PublicKey, SecretKey, Signature, VAZ256Error, VAZ256Result, PAIRWISE_TEST_MESSAGE, PUBLIC_KEY_SIZE,
+    SECRET_KEY_SIZE,
+};
+
+/// Thread stack size, in bytes, that is enough to generate a key, sign and verify with this module,
+/// including the first-use self-tests of the `power-on-self-test` feature. Optimized, the functions
+/// themselves peak at about 24 KB when signing. Unoptimized builds keep extra copies of every
+/// signature and come within a few KB of the bound.
+pub const STACK_BOUND: usize = 64 * 1024;
+
+/// Runs the known-answer self-tests against the functions of this module, like [`crate::self_test`]
+pub fn self_test() -> VAZ256Result<()> {
+    self_test_small()
+}
+
+/// Expands `secret` into Dilithium5 key bytes
+fn keypair(secret: &SecretKey, pk: &mut [u8; PUBLICKEYBYTES], sk: &mut [u8; SECRETKEYBYTES]) {
+    sign_dilithium5_small::keypair(pk, sk, secret.as_bytes());
+}
+
+/// Generates a new keypair using system randomness, like [`crate::keygen`]
+#[cfg(feature = "std")]
+pub fn keygen() -> VAZ256Result<(SecretKey, PublicKey)> {
+    keygen_with(&mut OsEntropy)
+}
+
+/// Generates a new keypair from the given entropy source, like [`crate::keygen_with`]
+pub fn keygen_with(entropy: &mut impl EntropySource) -> VAZ256Result<(SecretKey, PublicKey)> {
+    check_small()?;
+    let mut secret = [0u8; SECRET_KEY_SIZE];
+    entropy.fill_bytes(&mut secret)?;
+
+    let secret = SecretKey::new(secret);
+    let public = full_public_key(&secret).public_key();
+    // Pairwise consistency test, signing into a single buffer
+    let mut signature = Signature::zeroed();
+    sign_into(PAIRWISE_TEST_MESSAGE, &secret, &mut signature);
+    verify(PAIRWISE_TEST_MESSAGE, &signature, &public).map_err(|_| VAZ256Error::KeyGenerationFailed)?;
+    Ok((secret, public))
+}
+
+/// Derives the full Dilithium5 public key of `secret`, like [`SecretKey::full_public_key`]
+pub fn full_public_key(secret: &SecretKey) -> FullPublicKey {
+    let mut pk = [0u8; PUBLICKEYBYTES];
+    let mut sk = [0u8; SECRETKEYBYTES];
+    keypair(secret, &mut pk, &mut sk);
+    sk.zeroize();
+    FullPublicKey::new(pk)
+}
+
+/// Signs a message using the secret key, like [`crate::sign`]
+pub fn sign(message: &[u8], secret: &SecretKey) -> VAZ256Result<Signature> {
+    check_small()?;
+    let mut signature = Signature::zeroed();
+    sign_into(message, secret, &mut signature);
+    Ok(signature)
+}
+
+/// Fills `signature` in place, as every copy of a signature costs 7 KB of stack
+fn sign_into(message: &[u8], secret: &SecretKey, signature: &mut Signature) {
+    let (sig, pk) = signature.parts_mut();
+    let mut sk = [0u8; SECRETKEYBYTES];
+    keypair(secret, pk, &mut sk);
+    sign_dilithium5_small::signature(sig, message, &sk);
+    sk.zeroize();
+}
+
+/// Verifies a signature against a message and public key, like [`crate::verify`]
+pub fn verify(message: &[u8], signature: &Signature, public_key: &PublicKey) -> VAZ256Result<()> {
+    check_small()?;
+    let (sig, pk) = signature.parts();
+    let mut pk_hash = [0u8; PUBLIC_KEY_SIZE];
+    shake256(&mut pk_hash, PUBLIC_KEY_SIZE, pk, PUBLICKEYBYTES);
+    if &pk_hash != public_key.as_bytes() {
+        return Err(VAZ256Error::PublicKeyMismatch);
+    }
+    if !sign_dilithium5_small::verify(sig, message, pk) {
+        return Err(VAZ256Error::VerificationFailed);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entropy::TestEntropy;
+
+    #[test]
+    fn test_matches_regular_api() {
+        let secret = SecretKey::from_bytes(&[0x42; SECRET_KEY_SIZE]).unwrap();
+        assert_eq!(full_public_key(&secret), secret.full_public_key());
+
+        let signature = sign(b"message", &secret).unwrap();
+        assert_eq!(signature.to_bytes(), crate::sign(b"message", &secret).unwrap().to_bytes());
+
+        let public = secret.public_key();
+        assert_eq!(verify(b"message", &signature, &public), Ok(()));
+        assert_eq!(crate::verify(b"message", &signature, &public), Ok(()));
+        assert_eq!(verify(b"other", &signature, &public), Err(VAZ256Error::VerificationFailed));
+
+        let (_, other) = keygen_with(&mut TestEntropy::new(b"other")).unwrap();
+        assert_eq!(verify(b"message", &signature, &other), Err(VAZ256Error::PublicKeyMismatch));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_stack_bound() {
+        // A thread whose stack is exactly STACK_BOUND; overflowing it aborts the test binary
+        let worker = std::thread::Builder::new().stack_size(STACK_BOUND).spawn(|| {
+            let (secret, public) = keygen().unwrap();
+            let signature = sign(b"small stack", &secret).unwrap();
+            verify(b"small stack", &signature, &public)?;
+            self_test()
+        });
+        assert_eq!(worker.unwrap().join().unwrap(), Ok(()));
+    }
+}
@@ -0,0 +1,350 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Signed checksum manifests of directory trees.
+//!
+//! A manifest lists every regular file below a root directory in the
+//! `sha256sum` layout, one file per line and sorted by path:
+//!
+//! ```text
+//! <hex SHAKE256-256 digest>  <relative/path>
+//! ```
+//!
+//! Paths are relative to the root, use `/` as separator and never contain
+//! `.` or `..` components. The whole manifest is signed once with VAZ256,
+//! so a release needs a single signature however many files it ships.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::shake::Shake256;
+use crate::{hex, sign, verify, PublicKey, SecretKey, Signature, VAZ256Error, VAZ256Result};
+
+/// Size of a file digest
+pub const DIGEST_SIZE: usize = 32;
+
+/// How symbolic links below the root are handled
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SymlinkPolicy {
+    /// Fail on any symbolic link
+    #[default]
+    Reject,
+    /// Leave symbolic links out of the manifest
+    Skip,
+    /// Hash the file or walk the directory a link points to
+    Follow,
+}
+
+/// Options for walking a directory tree
+#[derive(Clone, Debug, Default)]
+pub struct WalkOptions {
+    pub symlinks: SymlinkPolicy,
+    /// Relative paths left out of the manifest, such as the manifest itself
+    pub exclude: Vec<String>,
+}
+
+/// Differences between a manifest and a directory tree
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    /// Files whose contents changed
+    pub modified: Vec<String>,
+    /// Files listed in the manifest but absent from the tree
+    pub missing: Vec<String>,
+    /// Files present in the tree but not listed in the manifest
+    pub extra: Vec<String>,
+}
+
+impl Report {
+    /// Returns whether the tree matches the manifest exactly
+    pub fn is_clean(&self) -> bool {
+        self.modified.is_empty() && self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+/// Checksum manifest of a directory tree
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    entries: BTreeMap<String, [u8; DIGEST_SIZE]>,
+}
+
+/// Returns the SHAKE256-256 digest of everything `reader` yields
+pub fn digest_reader<R: Read>(mut reader: R) -> io::Result<[u8; DIGEST_SIZE]> {
+    let mut shake = Shake256::new();
+    io::copy(&mut reader, &mut shake)?;
+    let mut digest = [0u8; DIGEST_SIZE];
+    shake.finalize(&mut digest);
+    Ok(digest)
+}
+
+/// Normalizes a manifest path: `\` and empty or `.` components are not
+/// significant, while absolute paths and `..` are refused
+fn normalize(path: &str) -> Option<String> {
+    if path.starts_with('/') || path.contains('\\') || path.contains('\0') {
+        return None;
+    }
+    let mut components = Vec::new();
+    for component in path.split('/') {
+        match component {
+            "" | "." => continue,
+            ".." => return None,
+            _ => components.push(component),
+        }
+    }
+    if components.is_empty() {
+        return None;
+    }
+    Some(components.join("/"))
+}
+
+fn invalid_data(path: &Path, reason: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), reason))
+}
+
+impl Manifest {
+    /// Creates an empty manifest
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds or replaces the digest of `path`
+    pub fn insert(&mut self, path: &str, digest: [u8; DIGEST_SIZE]) -> VAZ256Result<()> {
+        let path = normalize(path).ok_or(VAZ256Error::DeserializationError)?;
+        self.entries.insert(path, digest);
+        Ok(())
+    }
+
+    /// Returns the digest recorded for `path`
+    pub fn get(&self, path: &str) -> Option<&[u8; DIGEST_SIZE]> {
+        normalize(path).and_then(|path| self.entries.get(&path))
+    }
+
+    /// Returns the entries sorted by path
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &[u8; DIGEST_SIZE])> {
+        self.entries.iter().map(|(path, digest)| (path.as_str(), digest))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Hashes every regular file below `root`
+    pub fn from_dir(root: &Path, options: &WalkOptions) -> io::Result<Self> {
+        let exclude: Vec<String> = options.exclude.iter().filter_map(|path| normalize(path)).collect();
+        let mut manifest = Manifest::new();
+        let mut ancestors = vec![fs::canonicalize(root)?];
+        manifest.walk(root, "", options.symlinks, &exclude, &mut ancestors)?;
+        Ok(manifest)
+    }
+
+    fn walk(
+        &mut self,
+        dir: &Path,
+        prefix: &str,
+        symlinks: SymlinkPolicy,
+        exclude: &[String],
+        ancestors: &mut Vec<PathBuf>,
+    ) -> io::Result<()> {
+        let mut children = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            let name = name.to_str().ok_or_else(|| invalid_data(&entry.path(), "file name is not UTF-8"))?;
+            if name.contains(['\n', '\r', '\\']) {
+                return Err(invalid_data(&entry.path(), "file name cannot be listed in a manifest"));
+            }
+            children.push((format!("{}{}", prefix, name), entry.path()));
+        }
+
+        for (relative, path) in children {
+            if exclude.contains(&relative) {
+                continue;
+            }
+            let mut metadata = fs::symlink_metadata(&path)?;
+            if metadata.file_type().is_symlink() {
+                match symlinks {
+                    SymlinkPolicy::Reject => return Err(invalid_data(&path, "symbolic link")),
+                    SymlinkPolicy::Skip => continue,
+                    SymlinkPolicy::Follow => metadata = fs::metadata(&path)?,
+                }
+            }
+            if metadata.is_dir() {
+                let canonical = fs::canonicalize(&path)?;
+                if ancestors.contains(&canonical) {
+                    return Err(invalid_data(&path, "symbolic link cycle"));
+                }
+                ancestors.push(canonical);
+                self.walk(&path, &format!("{}/", relative), symlinks, exclude, ancestors)?;
+                ancestors.pop();
+            } else if metadata.is_file() {
+                let digest = digest_reader(File::open(&path)?)?;
+                self.entries.insert(relative, digest);
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a manifest; `*` binary-mode markers are accepted
+    pub fn parse(text: &str) -> VAZ256Result<Self> {
+        let mut manifest = Manifest::new();
+        for line in text.lines() {
+            if line.is_empty() {
+                continue;
+            }
+            let (digest, path) = line.split_once(' ').ok_or(VAZ256Error::DeserializationError)?;
+            let path = path.strip_prefix([' ', '*']).ok_or(VAZ256Error::DeserializationError)?;
+            if digest.len() != 2 * DIGEST_SIZE {
+                return Err(VAZ256Error::InvalidLength);
+            }
+            let digest: [u8; DIGEST_SIZE] = hex::decode(digest)
+                .map_err(|_| VAZ256Error::HexDecodingError)?
+                .try_into()
+                .map_err(|_| VAZ256Error::InvalidLength)?;
+            let path = normalize(path).ok_or(VAZ256Error::DeserializationError)?;
+            if manifest.entries.insert(path, digest).is_some() {
+                return Err(VAZ256Error::DeserializationError);
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// Signs the serialized manifest
+    pub fn sign(&self, key: &SecretKey) -> VAZ256Result<Signature> {
+        sign(self.to_string().as_bytes(), key)
+    }
+
+    /// Verifies `signature` over the exact manifest text, then parses it
+    pub fn verify_signed(text: &[u8], signature: &Signature, public_key: &PublicKey) -> VAZ256Result<Self> {
+        verify(text, signature, public_key)?;
+        Self::parse(std::str::from_utf8(text).map_err(|_| VAZ256Error::DeserializationError)?)
+    }
+
+    /// Compares the manifest against the tree below `root`
+    pub fn compare_dir(&self, root: &Path, options: &WalkOptions) -> io::Result<Report> {
+        let actual = Manifest::from_dir(root, options)?;
+        let mut report = Report::default();
+        for (path, digest) in &self.entries {
+            match actual.entries.get(path) {
+                Some(found) if found == digest => {}
+                Some(_) => report.modified.push(path.clone()),
+                None => report.missing.push(path.clone()),
+            }
+        }
+        report.extra = actual.entries.keys().filter(|path| !self.entries.contains_key(*path)).cloned().collect();
+        Ok(report)
+    }
+}
+
+impl fmt::Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (path, digest) in &self.entries {
+            writeln!(f, "{}  {}", hex::encode(digest), path)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keygen;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("vaz256-manifest-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_parse_and_normalize() {
+        let digest = hex::encode(digest_reader(&b"hello release"[..]).unwrap());
+        assert_eq!(digest, "395eb55b523b4288e06f1a15254180b34ed361eec2ffeefec7decbd82dfe774b");
+
+        let text = format!("{d}  ./docs//README.md\n{d} *bin/vaz256\n", d = digest);
+        let manifest = Manifest::parse(&text).unwrap();
+        assert_eq!(manifest.len(), 2);
+        assert_eq!(manifest.to_string(), format!("{d}  bin/vaz256\n{d}  docs/README.md\n", d = digest));
+        assert!(manifest.get("./docs/README.md").is_some());
+
+        for path in ["/etc/passwd", "a/../b", "..", "a\\b", "."] {
+            assert!(Manifest::parse(&format!("{}  {}\n", digest, path)).is_err(), "{}", path);
+        }
+        assert!(Manifest::parse(&format!("{d}  a\n{d}  ./a\n", d = digest)).is_err());
+        assert_eq!(Manifest::parse("abcd  a\n").unwrap_err(), VAZ256Error::InvalidLength);
+    }
+
+    #[test]
+    fn test_compare_dir() {
+        let dir = temp_dir("compare");
+        fs::create_dir_all(dir.join("lib/nested")).unwrap();
+        fs::write(dir.join("README"), b"readme").unwrap();
+        fs::write(dir.join("lib/a.rs"), b"a").unwrap();
+        fs::write(dir.join("lib/nested/b.rs"), b"b").unwrap();
+        fs::write(dir.join("SUMS"), b"").unwrap();
+
+        let options = WalkOptions { exclude: vec!["SUMS".to_string()], ..WalkOptions::default() };
+        let manifest = Manifest::from_dir(&dir, &options).unwrap();
+        let paths: Vec<&str> = manifest.entries().map(|(path, _)| path).collect();
+        assert_eq!(paths, ["README", "lib/a.rs", "lib/nested/b.rs"]);
+        assert!(manifest.compare_dir(&dir, &options).unwrap().is_clean());
+
+        fs::write(dir.join("lib/a.rs"), b"A").unwrap();
+        fs::remove_file(dir.join("lib/nested/b.rs")).unwrap();
+        fs::write(dir.join("lib/c.rs"), b"c").unwrap();
+        let report = manifest.compare_dir(&dir, &options).unwrap();
+        assert_eq!(report.modified, ["lib/a.rs"]);
+        assert_eq!(report.missing, ["lib/nested/b.rs"]);
+        assert_eq!(report.extra, ["lib/c.rs"]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_policy() {
+        let dir = temp_dir("symlinks");
+        fs::create_dir_all(dir.join("data")).unwrap();
+        fs::write(dir.join("data/file"), b"contents").unwrap();
+        std::os::unix::fs::symlink("data/file", dir.join("link")).unwrap();
+        std::os::unix::fs::symlink("..", dir.join("data/up")).unwrap();
+
+        assert!(Manifest::from_dir(&dir, &WalkOptions::default()).is_err());
+        let skip = WalkOptions { symlinks: SymlinkPolicy::Skip, ..WalkOptions::default() };
+        assert_eq!(Manifest::from_dir(&dir, &skip).unwrap().len(), 1);
+        let follow = WalkOptions { symlinks: SymlinkPolicy::Follow, ..WalkOptions::default() };
+        assert!(Manifest::from_dir(&dir, &follow).is_err());
+
+        fs::remove_file(dir.join("data/up")).unwrap();
+        let manifest = Manifest::from_dir(&dir, &follow).unwrap();
+        assert_eq!(manifest.get("link"), manifest.get("data/file"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_sign_verify() {
+        let (sk, pk) = keygen().unwrap();
+        let mut manifest = Manifest::new();
+        manifest.insert("vaz256-1.0.tar.gz", [1u8; DIGEST_SIZE]).unwrap();
+        let signature = manifest.sign(&sk).unwrap();
+        let text = manifest.to_string();
+        assert_eq!(Manifest::verify_signed(text.as_bytes(), &signature, &pk).unwrap(), manifest);
+        let tampered = text.replace("0101", "0102");
+        assert!(Manifest::verify_signed(tampered.as_bytes(), &signature, &pk).is_err());
+    }
+}
@@ -0,0 +1,304 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Minisign-style detached file signatures.
+//!
+//! A signature file has four lines:
+//!
+//! ```text
+//! untrusted comment: <text>
+//! base64(algorithm || key id || signature of SHAKE256(file))
+//! trusted comment: <text>
+//! base64(signature of (signature || trusted comment))
+//! ```
+//!
+//! The algorithm is `VZ` and the key ID is the first 8 bytes of SHAKE256 of
+//! the compact public key. Public key files use the same armor with a blob
+//! of algorithm, key ID and compact public key. As the Dilithium5 key is
+//! embedded in every signature, the 32-byte public key suffices to verify.
+
+use std::fmt;
+use std::io::{self, Read};
+
+use crate::fips202::shake256;
+use crate::shake::Shake256;
+use crate::{base64, hex, sign, verify, PublicKey, SecretKey, Signature, VAZ256Error, VAZ256Result};
+use crate::{PUBLIC_KEY_SIZE, SIGNATURE_SIZE};
+
+/// Signature algorithm identifier
+pub const ALGORITHM: [u8; 2] = *b"VZ";
+/// Size of a key ID
+pub const KEY_ID_SIZE: usize = 8;
+/// Size of the SHAKE256 pre-hash of a file
+pub const PREHASH_SIZE: usize = 64;
+
+const UNTRUSTED_PREFIX: &str = "untrusted comment: ";
+const TRUSTED_PREFIX: &str = "trusted comment: ";
+
+/// Returns the key ID of a public key
+pub fn key_id(public_key: &PublicKey) -> [u8; KEY_ID_SIZE] {
+    let mut id = [0u8; KEY_ID_SIZE];
+    shake256(&mut id, KEY_ID_SIZE, public_key.as_bytes(), PUBLIC_KEY_SIZE);
+    id
+}
+
+/// Returns the SHAKE256 pre-hash of `data`
+pub fn prehash(data: &[u8]) -> [u8; PREHASH_SIZE] {
+    let mut digest = [0u8; PREHASH_SIZE];
+    shake256(&mut digest, PREHASH_SIZE, data, data.len());
+    digest
+}
+
+/// Returns the SHAKE256 pre-hash of everything `reader` yields
+pub fn prehash_reader<R: Read>(mut reader: R) -> io::Result<[u8; PREHASH_SIZE]> {
+    let mut shake = Shake256::new();
+    io::copy(&mut reader, &mut shake)?;
+    let mut digest = [0u8; PREHASH_SIZE];
+    shake.finalize(&mut digest);
+    Ok(digest)
+}
+
+fn check_comment(comment: &str) -> VAZ256Result<()> {
+    if comment.contains(['\n', '\r']) {
+        return Err(VAZ256Error::SigningFailed);
+    }
+    Ok(())
+}
+
+fn decode_line(line: Option<&str>) -> VAZ256Result<Vec<u8>> {
+    base64::decode(line.ok_or(VAZ256Error::DeserializationError)?.trim())
+        .map_err(|_| VAZ256Error::DeserializationError)
+}
+
+fn comment_line<'a>(line: Option<&'a str>, prefix: &str) -> VAZ256Result<&'a str> {
+    line.and_then(|line| line.strip_prefix(prefix)).ok_or(VAZ256Error::DeserializationError)
+}
+
+/// Public key file
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinisignPublicKey {
+    public_key: PublicKey,
+    untrusted_comment: String,
+}
+
+impl MinisignPublicKey {
+    /// Wraps a public key with the default comment naming its key ID
+    pub fn new(public_key: PublicKey) -> Self {
+        let untrusted_comment = format!("minisign public key {}", hex::encode(key_id(&public_key)).to_uppercase());
+        MinisignPublicKey { public_key, untrusted_comment }
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    pub fn key_id(&self) -> [u8; KEY_ID_SIZE] {
+        key_id(&self.public_key)
+    }
+
+    pub fn untrusted_comment(&self) -> &str {
+        &self.untrusted_comment
+    }
+
+    /// Parses a public key file
+    pub fn from_string(text: &str) -> VAZ256Result<Self> {
+        let mut lines = text.lines().map(|line| line.trim_end_matches('\r'));
+        let untrusted_comment = comment_line(lines.next(), UNTRUSTED_PREFIX)?.to_string();
+        let blob = decode_line(lines.next())?;
+        if lines.any(|line| !line.trim().is_empty()) {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        if blob.len() != 2 + KEY_ID_SIZE + PUBLIC_KEY_SIZE || blob[..2] != ALGORITHM {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let public_key = PublicKey::from_bytes(&blob[2 + KEY_ID_SIZE..])?;
+        if blob[2..2 + KEY_ID_SIZE] != key_id(&public_key) {
+            return Err(VAZ256Error::PublicKeyMismatch);
+        }
+        Ok(MinisignPublicKey { public_key, untrusted_comment })
+    }
+}
+
+impl fmt::Display for MinisignPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let blob = [&ALGORITHM[..], &self.key_id(), self.public_key.as_bytes()].concat();
+        writeln!(f, "{}{}", UNTRUSTED_PREFIX, self.untrusted_comment)?;
+        writeln!(f, "{}", base64::encode(blob))
+    }
+}
+
+/// Detached signature file
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinisignSignature {
+    key_id: [u8; KEY_ID_SIZE],
+    signature: Vec<u8>,
+    untrusted_comment: String,
+    trusted_comment: String,
+    global_signature: Vec<u8>,
+}
+
+impl MinisignSignature {
+    /// Signs `data` with the given comments, which must be single lines
+    pub fn sign(data: &[u8], key: &SecretKey, trusted_comment: &str, untrusted_comment: &str) -> VAZ256Result<Self> {
+        Self::sign_prehashed(&prehash(data), key, trusted_comment, untrusted_comment)
+    }
+
+    /// Signs a pre-hash computed with [`prehash`] or [`prehash_reader`]
+    pub fn sign_prehashed(
+        prehash: &[u8; PREHASH_SIZE],
+        key: &SecretKey,
+        trusted_comment: &str,
+        untrusted_comment: &str,
+    ) -> VAZ256Result<Self> {
+        check_comment(trusted_comment)?;
+        check_comment(untrusted_comment)?;
+        let signature = sign(prehash, key)?.to_bytes().to_vec();
+        let global_signature = sign(&[&signature[..], trusted_comment.as_bytes()].concat(), key)?.to_bytes().to_vec();
+        Ok(MinisignSignature {
+            key_id: key_id(&key.public_key()),
+            signature,
+            untrusted_comment: untrusted_comment.to_string(),
+            trusted_comment: trusted_comment.to_string(),
+            global_signature,
+        })
+    }
+
+    pub fn key_id(&self) -> [u8; KEY_ID_SIZE] {
+        self.key_id
+    }
+
+    /// Returns the trusted comment, which is only authentic after verification
+    pub fn trusted_comment(&self) -> &str {
+        &self.trusted_comment
+    }
+
+    pub fn untrusted_comment(&self) -> &str {
+        &self.untrusted_comment
+    }
+
+    /// Verifies the signature of `data` and of the trusted comment
+    pub fn verify(&self, data: &[u8], public_key: &PublicKey) -> VAZ256Result<()> {
+        self.verify_prehashed(&prehash(data), public_key)
+    }
+
+    /// Verifies the signature of a pre-hash and of the trusted comment
+    ///
+    /// A key ID that does not belong to `public_key` fails with
+    /// `PublicKeyMismatch`.
+    pub fn verify_prehashed(&self, prehash: &[u8; PREHASH_SIZE], public_key: &PublicKey) -> VAZ256Result<()> {
+        if self.key_id != key_id(public_key) {
+            return Err(VAZ256Error::PublicKeyMismatch);
+        }
+        verify(prehash, &Signature::from_bytes(&self.signature)?, public_key)?;
+        let global = [&self.signature[..], self.trusted_comment.as_bytes()].concat();
+        verify(&global, &Signature::from_bytes(&self.global_signature)?, public_key)
+    }
+
+    /// Parses a signature file
+    pub fn from_string(text: &str) -> VAZ256Result<Self> {
+        let mut lines = text.lines().map(|line| line.trim_end_matches('\r'));
+        let untrusted_comment = comment_line(lines.next(), UNTRUSTED_PREFIX)?.to_string();
+        let blob = decode_line(lines.next())?;
+        let trusted_comment = comment_line(lines.next(), TRUSTED_PREFIX)?.to_string();
+        let global_signature = decode_line(lines.next())?;
+        if lines.any(|line| !line.trim().is_empty()) {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        if blob.len() != 2 + KEY_ID_SIZE + SIGNATURE_SIZE || blob[..2] != ALGORITHM {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        if global_signature.len() != SIGNATURE_SIZE {
+            return Err(VAZ256Error::InvalidLength);
+        }
+        Ok(MinisignSignature {
+            key_id: blob[2..2 + KEY_ID_SIZE].try_into().unwrap(),
+            signature: blob[2 + KEY_ID_SIZE..].to_vec(),
+            untrusted_comment,
+            trusted_comment,
+            global_signature,
+        })
+    }
+}
+
+impl fmt::Display for MinisignSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let blob = [&ALGORITHM[..], &self.key_id, &self.signature].concat();
+        writeln!(f, "{}{}", UNTRUSTED_PREFIX, self.untrusted_comment)?;
+        writeln!(f, "{}", base64::encode(blob))?;
+        writeln!(f, "{}{}", TRUSTED_PREFIX, self.trusted_comment)?;
+        writeln!(f, "{}", base64::encode(&self.global_signature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keygen;
+
+    #[test]
+    fn test_key_id_and_prehash() {
+        let pk = PublicKey::from_bytes(&[7u8; 32]).unwrap();
+        assert_eq!(hex::encode(key_id(&pk)), "d7b4cf68d3f1b711");
+        let expected = "395eb55b523b4288e06f1a15254180b34ed361eec2ffeefec7decbd82dfe774b\
+                        e946b2604043edc3b0b92651b5b2e7438aa740b20bafcc78d1f5121e73ec4eaf";
+        assert_eq!(hex::encode(prehash(b"hello release")), expected);
+        assert_eq!(prehash_reader(&b"hello release"[..]).unwrap(), prehash(b"hello release"));
+        let large = vec![0xa5u8; 20_000];
+        assert_eq!(prehash_reader(&large[..]).unwrap(), prehash(&large));
+    }
+
+    #[test]
+    fn test_public_key_file() {
+        let pk = PublicKey::from_bytes(&[7u8; 32]).unwrap();
+        let file = MinisignPublicKey::new(pk);
+        let text = file.to_string();
+        assert!(text.starts_with("untrusted comment: minisign public key D7B4CF68D3F1B711\nVlrXtM9o0/G3EQcH"));
+        assert_eq!(MinisignPublicKey::from_string(&text).unwrap(), file);
+
+        let mut blob = base64::decode(text.lines().nth(1).unwrap()).unwrap();
+        blob[2] ^= 1;
+        let tampered = format!("untrusted comment: x\n{}\n", base64::encode(blob));
+        assert_eq!(MinisignPublicKey::from_string(&tampered).unwrap_err(), VAZ256Error::PublicKeyMismatch);
+    }
+
+    #[test]
+    fn test_sign_verify() {
+        let (sk, pk) = keygen().unwrap();
+        let trusted = "timestamp:1750000000\tfile:vaz256-1.0.tar.gz\thashed";
+        let signature = MinisignSignature::sign(b"release", &sk, trusted, "signature from vaz256 secret key").unwrap();
+        let parsed = MinisignSignature::from_string(&signature.to_string()).unwrap();
+        assert_eq!(parsed, signature);
+        assert_eq!(parsed.trusted_comment(), trusted);
+        assert!(parsed.verify(b"release", &pk).is_ok());
+        assert!(parsed.verify(b"releasE", &pk).is_err());
+
+        let (_, other) = keygen().unwrap();
+        assert_eq!(parsed.verify(b"release", &other).unwrap_err(), VAZ256Error::PublicKeyMismatch);
+
+        let forged = signature.to_string().replace("timestamp:1750000000", "timestamp:1999999999");
+        let forged = MinisignSignature::from_string(&forged).unwrap();
+        assert!(forged.verify(b"release", &pk).is_err());
+        assert!(MinisignSignature::sign(b"release", &sk, "two\nlines", "").is_err());
+    }
+
+    #[test]
+    fn test_rejects_other_algorithms() {
+        let (sk, _) = keygen().unwrap();
+        let text = MinisignSignature::sign(b"release", &sk, "t", "u").unwrap().to_string();
+        let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+        let mut blob = base64::decode(&lines[1]).unwrap();
+        blob[..2].copy_from_slice(b"ED");
+        lines[1] = base64::encode(blob);
+        assert!(MinisignSignature::from_string(&lines.join("\n")).is_err());
+    }
+}
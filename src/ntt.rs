@@ -23,7 +23,7 @@
 const N: usize = crate::params_dilithium5::N as usize;
 
 /// Precomputed roots of unity.
-const ZETAS: [i32; N] = [
+pub(crate) const ZETAS: [i32; N] = [
     0, 25847, -2608894, -518909, 237124, -777960, -876248, 466468, 1826347,
     2353451, -359251, -2091905, 3119733, -2884855, 3111497, 2680103, 2725464,
     1024112, -1079900, 3585928, -549488, -1119584, 2619752, -2108549, -2118186,
@@ -0,0 +1,985 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! OpenPGP (RFC 9580) v6 certificates and signatures for VAZ256 keys.
+//!
+//! VAZ256 uses the experimental public-key algorithm ID 100. Its public key
+//! material is the 32-byte compact public key, its secret key material the
+//! 32-byte seed and its signature material the raw VAZ256 signature over
+//! the salted SHA2-512 digest. Secret keys are stored unencrypted.
+//!
+//! A certificate consists of a primary key (certify and sign) with its
+//! direct key signature, user IDs with positive certifications, and signing
+//! subkeys whose binding signatures embed a primary key binding signature.
+//! Exactly one self-signature is accepted per component, and revocations
+//! are not supported.
+
+use std::fmt;
+
+use crate::entropy::{EntropySource, OsEntropy};
+use crate::sha2::{sha256, sha512};
+use crate::zeroize::Zeroize;
+use crate::{base64, hex, sign, verify, PublicKey, SecretKey, Signature, VAZ256Error, VAZ256Result};
+use crate::{PUBLIC_KEY_SIZE, SECRET_KEY_SIZE, SIGNATURE_SIZE};
+
+/// Experimental public-key algorithm ID of VAZ256
+pub const ALGORITHM: u8 = 100;
+
+const VERSION: u8 = 6;
+const HASH_SHA512: u8 = 10;
+const SALT_SIZE: usize = 32;
+
+const TAG_SIGNATURE: u8 = 2;
+const TAG_SECRET_KEY: u8 = 5;
+const TAG_PUBLIC_KEY: u8 = 6;
+const TAG_SECRET_SUBKEY: u8 = 7;
+const TAG_USER_ID: u8 = 13;
+const TAG_PUBLIC_SUBKEY: u8 = 14;
+
+const SIG_BINARY: u8 = 0x00;
+const SIG_TEXT: u8 = 0x01;
+const SIG_POSITIVE_CERTIFICATION: u8 = 0x13;
+const SIG_SUBKEY_BINDING: u8 = 0x18;
+const SIG_PRIMARY_KEY_BINDING: u8 = 0x19;
+const SIG_DIRECT_KEY: u8 = 0x1F;
+
+const SUBPACKET_CREATION_TIME: u8 = 2;
+const SUBPACKET_PRIMARY_USER_ID: u8 = 25;
+const SUBPACKET_KEY_FLAGS: u8 = 27;
+const SUBPACKET_EMBEDDED_SIGNATURE: u8 = 32;
+const SUBPACKET_ISSUER_FINGERPRINT: u8 = 33;
+
+const KEY_FLAG_CERTIFY: u8 = 0x01;
+const KEY_FLAG_SIGN: u8 = 0x02;
+
+const PUBLIC_KEY_BLOCK: &str = "PUBLIC KEY BLOCK";
+const PRIVATE_KEY_BLOCK: &str = "PRIVATE KEY BLOCK";
+const SIGNATURE_BLOCK: &str = "SIGNATURE";
+const SIGNED_MESSAGE_HEADER: &str = "-----BEGIN PGP SIGNED MESSAGE-----";
+
+fn timestamp(time: i64) -> VAZ256Result<u32> {
+    u32::try_from(time).map_err(|_| VAZ256Error::SigningFailed)
+}
+
+/// Writes a new format packet length or subpacket length
+fn write_length(out: &mut Vec<u8>, len: usize) {
+    if len < 192 {
+        out.push(len as u8);
+    } else if len < 8384 {
+        let len = len - 192;
+        out.extend_from_slice(&[(len >> 8) as u8 + 192, len as u8]);
+    } else {
+        out.push(0xFF);
+        out.extend_from_slice(&(len as u32).to_be_bytes());
+    }
+}
+
+fn read_length(input: &mut &[u8]) -> VAZ256Result<usize> {
+    let (len, used) = match *input {
+        [first @ 0..=191, ..] => (*first as usize, 1),
+        [first @ 192..=223, second, ..] => (((*first as usize - 192) << 8) + *second as usize + 192, 2),
+        [0xFF, a, b, c, d, ..] => (u32::from_be_bytes([*a, *b, *c, *d]) as usize, 5),
+        // Partial body lengths are not supported
+        _ => return Err(VAZ256Error::DeserializationError),
+    };
+    *input = &input[used..];
+    Ok(len)
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> VAZ256Result<&'a [u8]> {
+    if input.len() < len {
+        return Err(VAZ256Error::DeserializationError);
+    }
+    let (head, rest) = input.split_at(len);
+    *input = rest;
+    Ok(head)
+}
+
+fn take_u32(input: &mut &[u8]) -> VAZ256Result<u32> {
+    Ok(u32::from_be_bytes(take(input, 4)?.try_into().unwrap()))
+}
+
+fn write_packet(out: &mut Vec<u8>, tag: u8, body: &[u8]) {
+    out.push(0xC0 | tag);
+    write_length(out, body.len());
+    out.extend_from_slice(body);
+}
+
+/// Splits a byte string into new format packets
+fn read_packets(mut input: &[u8]) -> VAZ256Result<Vec<(u8, &[u8])>> {
+    let mut packets = Vec::new();
+    while !input.is_empty() {
+        let header = take(&mut input, 1)?[0];
+        // Legacy format packets are not supported
+        if header & 0xC0 != 0xC0 {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let len = read_length(&mut input)?;
+        packets.push((header & 0x3F, take(&mut input, len)?));
+    }
+    Ok(packets)
+}
+
+/// v6 key fingerprint, the SHA2-256 digest of the public key packet
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fingerprint([u8; 32]);
+
+impl Fingerprint {
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Returns the key ID, the first 8 octets of the fingerprint
+    pub fn key_id(&self) -> [u8; 8] {
+        self.0[..8].try_into().unwrap()
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.0).to_uppercase())
+    }
+}
+
+/// v6 public key packet body
+#[derive(Clone, Debug, PartialEq)]
+struct KeyPacket {
+    created: u32,
+    public_key: PublicKey,
+}
+
+impl KeyPacket {
+    fn body(&self) -> Vec<u8> {
+        let mut body = vec![VERSION];
+        body.extend_from_slice(&self.created.to_be_bytes());
+        body.push(ALGORITHM);
+        body.extend_from_slice(&(PUBLIC_KEY_SIZE as u32).to_be_bytes());
+        body.extend_from_slice(self.public_key.as_bytes());
+        body
+    }
+
+    /// Parses the public part of a key packet, leaving any secret part in `input`
+    fn parse(input: &mut &[u8]) -> VAZ256Result<Self> {
+        if take(input, 1)?[0] != VERSION {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let created = take_u32(input)?;
+        if take(input, 1)?[0] != ALGORITHM || take_u32(input)? as usize != PUBLIC_KEY_SIZE {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let public_key = PublicKey::from_bytes(take(input, PUBLIC_KEY_SIZE)?)?;
+        Ok(KeyPacket { created, public_key })
+    }
+
+    /// Returns the key as it is hashed into signatures and fingerprints
+    fn hash_data(&self) -> Vec<u8> {
+        let body = self.body();
+        let mut data = vec![0x9B];
+        data.extend_from_slice(&(body.len() as u32).to_be_bytes());
+        data.extend_from_slice(&body);
+        data
+    }
+
+    fn fingerprint(&self) -> Fingerprint {
+        Fingerprint(sha256(&self.hash_data()))
+    }
+}
+
+fn secret_key_body(packet: &KeyPacket, key: &SecretKey) -> Vec<u8> {
+    let mut body = packet.body();
+    // String-to-key usage 0: unencrypted, without checksum in v6
+    body.push(0);
+    body.extend_from_slice(key.as_bytes());
+    body
+}
+
+fn parse_secret_key(mut body: &[u8]) -> VAZ256Result<(KeyPacket, SecretKey)> {
+    let packet = KeyPacket::parse(&mut body)?;
+    // Encrypted secret keys are not supported
+    if take(&mut body, 1)?[0] != 0 {
+        return Err(VAZ256Error::DeserializationError);
+    }
+    let key = SecretKey::from_bytes(take(&mut body, SECRET_KEY_SIZE)?)?;
+    if !body.is_empty() {
+        return Err(VAZ256Error::DeserializationError);
+    }
+    if key.public_key() != packet.public_key {
+        return Err(VAZ256Error::PublicKeyMismatch);
+    }
+    Ok((packet, key))
+}
+
+fn write_subpacket(out: &mut Vec<u8>, kind: u8, critical: bool, body: &[u8]) {
+    write_length(out, body.len() + 1);
+    out.push(if critical { kind | 0x80 } else { kind });
+    out.extend_from_slice(body);
+}
+
+/// Iterates over the (type, critical, body) of a subpacket area
+fn read_subpackets(mut area: &[u8]) -> VAZ256Result<Vec<(u8, bool, &[u8])>> {
+    let mut subpackets = Vec::new();
+    while !area.is_empty() {
+        let len = read_length(&mut area)?;
+        let subpacket = take(&mut area, len)?;
+        let (&kind, body) = subpacket.split_first().ok_or(VAZ256Error::DeserializationError)?;
+        subpackets.push((kind & 0x7F, kind & 0x80 != 0, body));
+    }
+    Ok(subpackets)
+}
+
+/// v6 signature packet body
+#[derive(Clone, Debug, PartialEq)]
+struct SignaturePacket {
+    sig_type: u8,
+    hashed: Vec<u8>,
+    unhashed: Vec<u8>,
+    prefix: [u8; 2],
+    salt: [u8; SALT_SIZE],
+    signature: Vec<u8>,
+}
+
+impl SignaturePacket {
+    /// Signs `data` with a creation time subpacket followed by the hashed
+    /// subpackets in `extra`
+    fn create(sig_type: u8, data: &[u8], key: &SecretKey, created: u32, extra: &[u8]) -> VAZ256Result<Self> {
+        let mut hashed = Vec::new();
+        write_subpacket(&mut hashed, SUBPACKET_CREATION_TIME, true, &created.to_be_bytes());
+        hashed.extend_from_slice(extra);
+
+        let mut salt = [0u8; SALT_SIZE];
+        OsEntropy.fill_bytes(&mut salt)?;
+        let mut packet = SignaturePacket {
+            sig_type,
+            hashed,
+            unhashed: Vec::new(),
+            prefix: [0; 2],
+            salt,
+            signature: Vec::new(),
+        };
+        let digest = packet.digest(data);
+        packet.prefix = [digest[0], digest[1]];
+        packet.signature = sign(&digest, key)?.to_bytes().to_vec();
+        Ok(packet)
+    }
+
+    /// Salted SHA2-512 digest of `data` followed by the v6 trailer
+    fn digest(&self, data: &[u8]) -> [u8; 64] {
+        let mut hashed = vec![VERSION, self.sig_type, ALGORITHM, HASH_SHA512];
+        hashed.extend_from_slice(&(self.hashed.len() as u32).to_be_bytes());
+        hashed.extend_from_slice(&self.hashed);
+        let mut input = Vec::with_capacity(SALT_SIZE + data.len() + 2 * hashed.len() + 6);
+        input.extend_from_slice(&self.salt);
+        input.extend_from_slice(data);
+        input.extend_from_slice(&hashed);
+        input.extend_from_slice(&[VERSION, 0xFF]);
+        input.extend_from_slice(&(hashed.len() as u32).to_be_bytes());
+        sha512(&input)
+    }
+
+    fn verify(&self, data: &[u8], public_key: &PublicKey) -> VAZ256Result<()> {
+        let digest = self.digest(data);
+        if digest[..2] != self.prefix {
+            return Err(VAZ256Error::VerificationFailed);
+        }
+        verify(&digest, &Signature::from_bytes(&self.signature)?, public_key)
+    }
+
+    fn body(&self) -> Vec<u8> {
+        let mut body = vec![VERSION, self.sig_type, ALGORITHM, HASH_SHA512];
+        body.extend_from_slice(&(self.hashed.len() as u32).to_be_bytes());
+        body.extend_from_slice(&self.hashed);
+        body.extend_from_slice(&(self.unhashed.len() as u32).to_be_bytes());
+        body.extend_from_slice(&self.unhashed);
+        body.extend_from_slice(&self.prefix);
+        body.push(SALT_SIZE as u8);
+        body.extend_from_slice(&self.salt);
+        body.extend_from_slice(&self.signature);
+        body
+    }
+
+    fn parse(mut body: &[u8]) -> VAZ256Result<Self> {
+        let input = &mut body;
+        let header = take(input, 4)?;
+        if header[0] != VERSION || header[2] != ALGORITHM || header[3] != HASH_SHA512 {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let len = take_u32(input)? as usize;
+        let hashed = take(input, len)?.to_vec();
+        let len = take_u32(input)? as usize;
+        let unhashed = take(input, len)?.to_vec();
+        let prefix = take(input, 2)?.try_into().unwrap();
+        if take(input, 1)?[0] as usize != SALT_SIZE {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let salt = take(input, SALT_SIZE)?.try_into().unwrap();
+        if input.len() != SIGNATURE_SIZE {
+            return Err(VAZ256Error::InvalidLength);
+        }
+        let packet = SignaturePacket {
+            sig_type: header[1],
+            hashed,
+            unhashed,
+            prefix,
+            salt,
+            signature: input.to_vec(),
+        };
+
+        // Critical subpackets must be understood
+        for (kind, critical, _) in read_subpackets(&packet.hashed)? {
+            let known = matches!(
+                kind,
+                SUBPACKET_CREATION_TIME
+                    | SUBPACKET_PRIMARY_USER_ID
+                    | SUBPACKET_KEY_FLAGS
+                    | SUBPACKET_EMBEDDED_SIGNATURE
+                    | SUBPACKET_ISSUER_FINGERPRINT
+            );
+            if critical && !known {
+                return Err(VAZ256Error::DeserializationError);
+            }
+        }
+        read_subpackets(&packet.unhashed)?;
+        if packet.creation_time().is_none() {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        Ok(packet)
+    }
+
+    /// Returns the body of the first hashed subpacket of the given type
+    fn subpacket(&self, kind: u8) -> Option<&[u8]> {
+        read_subpackets(&self.hashed)
+            .ok()?
+            .into_iter()
+            .find(|(k, _, _)| *k == kind)
+            .map(|(_, _, body)| body)
+    }
+
+    fn creation_time(&self) -> Option<u32> {
+        Some(u32::from_be_bytes(self.subpacket(SUBPACKET_CREATION_TIME)?.try_into().ok()?))
+    }
+
+    fn issuer(&self) -> Option<Fingerprint> {
+        match self.subpacket(SUBPACKET_ISSUER_FINGERPRINT)? {
+            [VERSION, fingerprint @ ..] => Some(Fingerprint(fingerprint.try_into().ok()?)),
+            _ => None,
+        }
+    }
+
+    fn key_flags(&self) -> u8 {
+        self.subpacket(SUBPACKET_KEY_FLAGS).and_then(|flags| flags.first().copied()).unwrap_or(0)
+    }
+}
+
+/// Hashed subpackets naming the issuer, plus any `extra` subpackets
+fn issued_by(issuer: &KeyPacket, extra: &[(u8, &[u8])]) -> Vec<u8> {
+    let mut area = Vec::new();
+    let mut fingerprint = vec![VERSION];
+    fingerprint.extend_from_slice(issuer.fingerprint().as_bytes());
+    write_subpacket(&mut area, SUBPACKET_ISSUER_FINGERPRINT, false, &fingerprint);
+    for (kind, body) in extra {
+        write_subpacket(&mut area, *kind, false, body);
+    }
+    area
+}
+
+fn user_id_data(primary: &KeyPacket, user_id: &str) -> Vec<u8> {
+    let mut data = primary.hash_data();
+    data.push(0xB4);
+    data.extend_from_slice(&(user_id.len() as u32).to_be_bytes());
+    data.extend_from_slice(user_id.as_bytes());
+    data
+}
+
+fn subkey_data(primary: &KeyPacket, subkey: &KeyPacket) -> Vec<u8> {
+    [primary.hash_data(), subkey.hash_data()].concat()
+}
+
+/// Transferable public key of VAZ256 keys
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cert {
+    primary: KeyPacket,
+    direct_key: SignaturePacket,
+    user_ids: Vec<(String, SignaturePacket)>,
+    subkeys: Vec<(KeyPacket, SignaturePacket)>,
+}
+
+impl Cert {
+    /// Returns the fingerprint of the primary key
+    pub fn fingerprint(&self) -> Fingerprint {
+        self.primary.fingerprint()
+    }
+
+    /// Returns the primary public key
+    pub fn public_key(&self) -> &PublicKey {
+        &self.primary.public_key
+    }
+
+    /// Returns the creation time of the primary key
+    pub fn created(&self) -> i64 {
+        self.primary.created as i64
+    }
+
+    pub fn user_ids(&self) -> Vec<&str> {
+        self.user_ids.iter().map(|(id, _)| id.as_str()).collect()
+    }
+
+    /// Returns the fingerprints of the subkeys
+    pub fn subkey_fingerprints(&self) -> Vec<Fingerprint> {
+        self.subkeys.iter().map(|(key, _)| key.fingerprint()).collect()
+    }
+
+    fn write(&self, out: &mut Vec<u8>, secrets: Option<(&SecretKey, &[SecretKey])>) {
+        match secrets {
+            Some((primary, _)) => write_packet(out, TAG_SECRET_KEY, &secret_key_body(&self.primary, primary)),
+            None => write_packet(out, TAG_PUBLIC_KEY, &self.primary.body()),
+        }
+        write_packet(out, TAG_SIGNATURE, &self.direct_key.body());
+        for (user_id, signature) in &self.user_ids {
+            write_packet(out, TAG_USER_ID, user_id.as_bytes());
+            write_packet(out, TAG_SIGNATURE, &signature.body());
+        }
+        for (i, (subkey, binding)) in self.subkeys.iter().enumerate() {
+            match secrets {
+                Some((_, keys)) => write_packet(out, TAG_SECRET_SUBKEY, &secret_key_body(subkey, &keys[i])),
+                None => write_packet(out, TAG_PUBLIC_SUBKEY, &subkey.body()),
+            }
+            write_packet(out, TAG_SIGNATURE, &binding.body());
+        }
+    }
+
+    /// Returns the binary encoding
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write(&mut out, None);
+        out
+    }
+
+    /// Returns the ASCII armored encoding
+    pub fn to_armored(&self) -> String {
+        armor(PUBLIC_KEY_BLOCK, &self.to_bytes())
+    }
+
+    /// Parses a binary certificate and verifies its self-signatures
+    pub fn from_bytes(data: &[u8]) -> VAZ256Result<Self> {
+        Ok(parse_cert(data, false)?.0)
+    }
+
+    /// Parses an ASCII armored certificate and verifies its self-signatures
+    pub fn from_armored(text: &str) -> VAZ256Result<Self> {
+        Self::from_bytes(&dearmor(text, PUBLIC_KEY_BLOCK)?)
+    }
+
+    /// Verifies the direct key signature, user ID certifications and
+    /// subkey bindings, including the embedded primary key bindings
+    fn verify_self_signatures(&self) -> VAZ256Result<()> {
+        let primary = &self.primary;
+        let issued_by_primary = |s: &SignaturePacket| s.issuer() == Some(primary.fingerprint());
+        if self.direct_key.sig_type != SIG_DIRECT_KEY || !issued_by_primary(&self.direct_key) {
+            return Err(VAZ256Error::VerificationFailed);
+        }
+        self.direct_key.verify(&primary.hash_data(), &primary.public_key)?;
+
+        for (user_id, certification) in &self.user_ids {
+            if certification.sig_type != SIG_POSITIVE_CERTIFICATION || !issued_by_primary(certification) {
+                return Err(VAZ256Error::VerificationFailed);
+            }
+            certification.verify(&user_id_data(primary, user_id), &primary.public_key)?;
+        }
+
+        for (subkey, binding) in &self.subkeys {
+            if binding.sig_type != SIG_SUBKEY_BINDING || !issued_by_primary(binding) {
+                return Err(VAZ256Error::VerificationFailed);
+            }
+            let data = subkey_data(primary, subkey);
+            binding.verify(&data, &primary.public_key)?;
+            let embedded = binding
+                .subpacket(SUBPACKET_EMBEDDED_SIGNATURE)
+                .ok_or(VAZ256Error::VerificationFailed)?;
+            let back = SignaturePacket::parse(embedded)?;
+            if back.sig_type != SIG_PRIMARY_KEY_BINDING || back.issuer() != Some(subkey.fingerprint()) {
+                return Err(VAZ256Error::VerificationFailed);
+            }
+            back.verify(&data, &subkey.public_key)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the signing-capable key with the given fingerprint
+    fn signing_key(&self, fingerprint: &Fingerprint) -> Option<&KeyPacket> {
+        if self.primary.fingerprint() == *fingerprint && self.direct_key.key_flags() & KEY_FLAG_SIGN != 0 {
+            return Some(&self.primary);
+        }
+        self.subkeys
+            .iter()
+            .find(|(key, binding)| key.fingerprint() == *fingerprint && binding.key_flags() & KEY_FLAG_SIGN != 0)
+            .map(|(key, _)| key)
+    }
+
+    /// Verifies the first signature by this certificate among `signatures`
+    fn verify_any(&self, signatures: &[SignaturePacket], sig_type: u8, data: &[u8]) -> VAZ256Result<Fingerprint> {
+        for signature in signatures {
+            let Some(key) = signature.issuer().and_then(|issuer| self.signing_key(&issuer)) else {
+                continue;
+            };
+            if signature.sig_type != sig_type || signature.creation_time() < Some(key.created) {
+                return Err(VAZ256Error::VerificationFailed);
+            }
+            signature.verify(data, &key.public_key)?;
+            return Ok(key.fingerprint());
+        }
+        Err(VAZ256Error::PublicKeyMismatch)
+    }
+
+    /// Verifies an armored detached binary signature of `data`, returning
+    /// the fingerprint of the signing key
+    ///
+    /// Fails with `PublicKeyMismatch` when no signature was made by a
+    /// signing key of this certificate.
+    pub fn verify_detached(&self, data: &[u8], signature: &str) -> VAZ256Result<Fingerprint> {
+        let signatures = parse_signatures(&dearmor(signature, SIGNATURE_BLOCK)?)?;
+        self.verify_any(&signatures, SIG_BINARY, data)
+    }
+
+    /// Verifies a cleartext signed message, returning the signed text with
+    /// `\n` line endings
+    pub fn verify_cleartext(&self, message: &str) -> VAZ256Result<String> {
+        let (text, signature) = split_cleartext(message)?;
+        let signatures = parse_signatures(&dearmor(signature, SIGNATURE_BLOCK)?)?;
+        self.verify_any(&signatures, SIG_TEXT, canonical_text(&text).as_bytes())?;
+        Ok(text)
+    }
+}
+
+fn parse_signatures(data: &[u8]) -> VAZ256Result<Vec<SignaturePacket>> {
+    read_packets(data)?
+        .into_iter()
+        .map(|(tag, body)| match tag {
+            TAG_SIGNATURE => SignaturePacket::parse(body),
+            _ => Err(VAZ256Error::DeserializationError),
+        })
+        .collect()
+}
+
+/// Parses a public or secret transferable key and verifies its self-signatures
+fn parse_cert(data: &[u8], secret: bool) -> VAZ256Result<(Cert, Vec<SecretKey>)> {
+    let (primary_tag, subkey_tag) = if secret {
+        (TAG_SECRET_KEY, TAG_SECRET_SUBKEY)
+    } else {
+        (TAG_PUBLIC_KEY, TAG_PUBLIC_SUBKEY)
+    };
+    let mut secrets = Vec::new();
+    let mut key = |body: &[u8]| -> VAZ256Result<KeyPacket> {
+        if secret {
+            let (packet, key) = parse_secret_key(body)?;
+            secrets.push(key);
+            Ok(packet)
+        } else {
+            let mut body = body;
+            let packet = KeyPacket::parse(&mut body)?;
+            if !body.is_empty() {
+                return Err(VAZ256Error::DeserializationError);
+            }
+            Ok(packet)
+        }
+    };
+
+    let packets = read_packets(data)?;
+    let mut packets = packets.into_iter();
+    let next_signature = |packets: &mut std::vec::IntoIter<(u8, &[u8])>| match packets.next() {
+        Some((TAG_SIGNATURE, body)) => SignaturePacket::parse(body),
+        _ => Err(VAZ256Error::DeserializationError),
+    };
+
+    let primary = match packets.next() {
+        Some((tag, body)) if tag == primary_tag => key(body)?,
+        _ => return Err(VAZ256Error::DeserializationError),
+    };
+    let direct_key = next_signature(&mut packets)?;
+    let mut user_ids = Vec::new();
+    let mut subkeys = Vec::new();
+    while let Some((tag, body)) = packets.next() {
+        match tag {
+            TAG_USER_ID if subkeys.is_empty() => {
+                let user_id = String::from_utf8(body.to_vec()).map_err(|_| VAZ256Error::DeserializationError)?;
+                user_ids.push((user_id, next_signature(&mut packets)?));
+            }
+            tag if tag == subkey_tag => {
+                let subkey = key(body)?;
+                subkeys.push((subkey, next_signature(&mut packets)?));
+            }
+            _ => return Err(VAZ256Error::DeserializationError),
+        }
+    }
+
+    let cert = Cert { primary, direct_key, user_ids, subkeys };
+    cert.verify_self_signatures()?;
+    Ok((cert, secrets))
+}
+
+/// Transferable secret key: a certificate together with its secret keys
+pub struct SecretCert {
+    cert: Cert,
+    primary: SecretKey,
+    subkeys: Vec<SecretKey>,
+}
+
+impl SecretCert {
+    /// Creates a certificate for `primary` with the given user IDs and
+    /// signing subkeys, all created at `created` (seconds since the Unix
+    /// epoch). The first user ID is marked primary.
+    pub fn new(primary: SecretKey, user_ids: &[&str], subkeys: Vec<SecretKey>, created: i64) -> VAZ256Result<Self> {
+        let created = timestamp(created)?;
+        let primary_packet = KeyPacket { created, public_key: primary.public_key() };
+
+        let direct_key = SignaturePacket::create(
+            SIG_DIRECT_KEY,
+            &primary_packet.hash_data(),
+            &primary,
+            created,
+            &issued_by(&primary_packet, &[(SUBPACKET_KEY_FLAGS, &[KEY_FLAG_CERTIFY | KEY_FLAG_SIGN])]),
+        )?;
+
+        let user_ids = user_ids
+            .iter()
+            .enumerate()
+            .map(|(i, user_id)| {
+                let primary_flag: &[(u8, &[u8])] = if i == 0 { &[(SUBPACKET_PRIMARY_USER_ID, &[1])] } else { &[] };
+                let signature = SignaturePacket::create(
+                    SIG_POSITIVE_CERTIFICATION,
+                    &user_id_data(&primary_packet, user_id),
+                    &primary,
+                    created,
+                    &issued_by(&primary_packet, primary_flag),
+                )?;
+                Ok((user_id.to_string(), signature))
+            })
+            .collect::<VAZ256Result<_>>()?;
+
+        let subkey_packets = subkeys
+            .iter()
+            .map(|subkey| {
+                let packet = KeyPacket { created, public_key: subkey.public_key() };
+                let data = subkey_data(&primary_packet, &packet);
+                let back = SignaturePacket::create(SIG_PRIMARY_KEY_BINDING, &data, subkey, created, &issued_by(&packet, &[]))?;
+                let binding = SignaturePacket::create(
+                    SIG_SUBKEY_BINDING,
+                    &data,
+                    &primary,
+                    created,
+                    &issued_by(
+                        &primary_packet,
+                        &[(SUBPACKET_KEY_FLAGS, &[KEY_FLAG_SIGN]), (SUBPACKET_EMBEDDED_SIGNATURE, &back.body())],
+                    ),
+                )?;
+                Ok((packet, binding))
+            })
+            .collect::<VAZ256Result<_>>()?;
+
+        let cert = Cert {
+            primary: primary_packet,
+            direct_key,
+            user_ids,
+            subkeys: subkey_packets,
+        };
+        Ok(SecretCert { cert, primary, subkeys })
+    }
+
+    /// Returns the public certificate
+    pub fn cert(&self) -> &Cert {
+        &self.cert
+    }
+
+    /// Returns the binary encoding with unencrypted secret keys
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.cert.write(&mut out, Some((&self.primary, &self.subkeys)));
+        out
+    }
+
+    /// Returns the ASCII armored encoding with unencrypted secret keys
+    pub fn to_armored(&self) -> String {
+        let mut data = self.to_bytes();
+        let armored = armor(PRIVATE_KEY_BLOCK, &data);
+        data.zeroize();
+        armored
+    }
+
+    /// Parses a binary transferable secret key and verifies its self-signatures
+    ///
+    /// Secret keys that do not match their public keys fail with
+    /// `PublicKeyMismatch`.
+    pub fn from_bytes(data: &[u8]) -> VAZ256Result<Self> {
+        let (cert, mut secrets) = parse_cert(data, true)?;
+        let primary = secrets.remove(0);
+        Ok(SecretCert { cert, primary, subkeys: secrets })
+    }
+
+    /// Parses an ASCII armored transferable secret key
+    pub fn from_armored(text: &str) -> VAZ256Result<Self> {
+        let mut data = dearmor(text, PRIVATE_KEY_BLOCK)?;
+        let result = Self::from_bytes(&data);
+        data.zeroize();
+        result
+    }
+
+    /// Returns the key used for data signatures: the last subkey, or the
+    /// primary key when there are none
+    fn signer(&self) -> (&KeyPacket, &SecretKey) {
+        match (self.cert.subkeys.last(), self.subkeys.last()) {
+            (Some((packet, _)), Some(key)) => (packet, key),
+            _ => (&self.cert.primary, &self.primary),
+        }
+    }
+
+    /// Creates an armored detached binary signature of `data` made at `time`
+    pub fn sign_detached(&self, data: &[u8], time: i64) -> VAZ256Result<String> {
+        let (packet, key) = self.signer();
+        let signature = SignaturePacket::create(SIG_BINARY, data, key, timestamp(time)?, &issued_by(packet, &[]))?;
+        let mut out = Vec::new();
+        write_packet(&mut out, TAG_SIGNATURE, &signature.body());
+        Ok(armor(SIGNATURE_BLOCK, &out))
+    }
+
+    /// Creates a cleartext signed message of `text` made at `time`
+    ///
+    /// Trailing whitespace on each line is not signed and is removed.
+    pub fn sign_cleartext(&self, text: &str, time: i64) -> VAZ256Result<String> {
+        let (packet, key) = self.signer();
+        let text = text.strip_suffix('\n').unwrap_or(text);
+        let canonical = canonical_text(text);
+        let signature =
+            SignaturePacket::create(SIG_TEXT, canonical.as_bytes(), key, timestamp(time)?, &issued_by(packet, &[]))?;
+        let mut out = Vec::new();
+        write_packet(&mut out, TAG_SIGNATURE, &signature.body());
+
+        let mut message = format!("{}\n\n", SIGNED_MESSAGE_HEADER);
+        for line in canonical.split("\r\n") {
+            // Dash-escape lines that could be mistaken for armor
+            if line.starts_with('-') {
+                message.push_str("- ");
+            }
+            message.push_str(line);
+            message.push('\n');
+        }
+        message.push_str(&armor(SIGNATURE_BLOCK, &out));
+        Ok(message)
+    }
+}
+
+/// Text signature form: trailing spaces and tabs removed, CRLF line endings
+fn canonical_text(text: &str) -> String {
+    text.split('\n')
+        .map(|line| line.trim_end_matches(['\r', ' ', '\t']))
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+/// Splits a cleartext signed message into its unescaped text and the
+/// armored signature
+fn split_cleartext(message: &str) -> VAZ256Result<(String, &str)> {
+    let start = message.find(SIGNED_MESSAGE_HEADER).ok_or(VAZ256Error::DeserializationError)?;
+    let mut rest = &message[start + SIGNED_MESSAGE_HEADER.len()..];
+    rest = rest.strip_prefix("\r\n").or_else(|| rest.strip_prefix('\n')).ok_or(VAZ256Error::DeserializationError)?;
+
+    // Armor headers such as "Hash:" end at the first empty line
+    loop {
+        let (line, next) = rest.split_once('\n').ok_or(VAZ256Error::DeserializationError)?;
+        rest = next;
+        if line.trim_end_matches('\r').is_empty() {
+            break;
+        }
+    }
+
+    let mut lines = Vec::new();
+    loop {
+        let (line, next) = rest.split_once('\n').ok_or(VAZ256Error::DeserializationError)?;
+        let line = line.trim_end_matches('\r');
+        if line == "-----BEGIN PGP SIGNATURE-----" {
+            return Ok((lines.join("\n"), rest));
+        }
+        lines.push(match line.strip_prefix('-') {
+            Some(escaped) => escaped.strip_prefix(' ').ok_or(VAZ256Error::DeserializationError)?,
+            None => line,
+        });
+        rest = next;
+    }
+}
+
+/// CRC-24 of the armor checksum line
+fn crc24(data: &[u8]) -> u32 {
+    let mut crc = 0xB704CEu32;
+    for &byte in data {
+        crc ^= (byte as u32) << 16;
+        for _ in 0..8 {
+            crc <<= 1;
+            if crc & 0x100_0000 != 0 {
+                crc ^= 0x186_4CFB;
+            }
+        }
+    }
+    crc & 0xFF_FFFF
+}
+
+/// ASCII armor without checksum, which RFC 9580 no longer recommends
+fn armor(label: &str, data: &[u8]) -> String {
+    let body = base64::encode(data);
+    let mut out = format!("-----BEGIN PGP {}-----\n\n", label);
+    for line in body.as_bytes().chunks(64) {
+        // Base64 output is pure ASCII
+        out.push_str(std::str::from_utf8(line).unwrap());
+        out.push('\n');
+    }
+    out.push_str(&format!("-----END PGP {}-----\n", label));
+    out
+}
+
+/// Decodes the first armor block with the given label, checking the
+/// optional CRC-24 checksum
+fn dearmor(text: &str, label: &str) -> VAZ256Result<Vec<u8>> {
+    let begin = format!("-----BEGIN PGP {}-----", label);
+    let end = format!("-----END PGP {}-----", label);
+    let mut lines = text.lines().map(str::trim_end);
+    lines.find(|line| *line == begin).ok_or(VAZ256Error::DeserializationError)?;
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+        if !line.contains(": ") {
+            return Err(VAZ256Error::DeserializationError);
+        }
+    }
+
+    let mut body = String::new();
+    let mut checksum = None;
+    for line in lines {
+        if line == end {
+            let data = base64::decode(&body).map_err(|_| VAZ256Error::DeserializationError)?;
+            if let Some(checksum) = checksum {
+                let expected = crc24(&data).to_be_bytes();
+                if checksum != expected[1..] {
+                    return Err(VAZ256Error::DeserializationError);
+                }
+            }
+            return Ok(data);
+        }
+        match line.strip_prefix('=') {
+            Some(crc) if checksum.is_none() => {
+                checksum = Some(base64::decode(crc).map_err(|_| VAZ256Error::DeserializationError)?)
+            }
+            Some(_) => return Err(VAZ256Error::DeserializationError),
+            None if checksum.is_none() => body.push_str(line),
+            None => return Err(VAZ256Error::DeserializationError),
+        }
+    }
+    Err(VAZ256Error::DeserializationError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keygen;
+
+    const CREATED: i64 = 1_700_000_000;
+
+    fn new_cert(subkeys: usize) -> SecretCert {
+        let (primary, _) = keygen().unwrap();
+        let subkeys = (0..subkeys).map(|_| keygen().unwrap().0).collect();
+        SecretCert::new(primary, &["Release Signing <release@example.com>", "Alt <alt@example.com>"], subkeys, CREATED)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let packet = KeyPacket {
+            created: CREATED as u32,
+            public_key: PublicKey::from_bytes(&[7u8; 32]).unwrap(),
+        };
+        assert_eq!(
+            hex::encode(packet.hash_data()),
+            format!("9b0000002a066553f1006400000020{}", "07".repeat(32))
+        );
+        assert_eq!(
+            packet.fingerprint().to_string(),
+            "E45627B703FD9901162D497CD0ACE141374462CE36FF5AE93D11706BDE1CC321"
+        );
+        assert_eq!(packet.fingerprint().key_id(), packet.fingerprint().as_bytes()[..8]);
+    }
+
+    #[test]
+    fn test_cert_round_trip() {
+        let secret = new_cert(1);
+        let cert = secret.cert();
+        assert_eq!(cert.user_ids(), ["Release Signing <release@example.com>", "Alt <alt@example.com>"]);
+        assert_eq!(cert.subkey_fingerprints().len(), 1);
+
+        let armored = cert.to_armored();
+        assert!(armored.starts_with("-----BEGIN PGP PUBLIC KEY BLOCK-----\n\nxioG"));
+        assert_eq!(&Cert::from_armored(&armored).unwrap(), cert);
+
+        let parsed = SecretCert::from_armored(&secret.to_armored()).unwrap();
+        assert_eq!(parsed.cert(), cert);
+        assert_eq!(parsed.to_bytes(), secret.to_bytes());
+
+        // Changing a user ID breaks its certification
+        let tampered = cert.to_bytes();
+        let position = tampered.windows(7).position(|w| w == b"Release").unwrap();
+        let mut tampered = tampered.clone();
+        tampered[position] = b'r';
+        assert_eq!(Cert::from_bytes(&tampered).unwrap_err(), VAZ256Error::VerificationFailed);
+    }
+
+    #[test]
+    fn test_detached_signature() {
+        let secret = new_cert(1);
+        let cert = secret.cert();
+        let signature = secret.sign_detached(b"package contents", CREATED + 60).unwrap();
+        assert!(signature.starts_with("-----BEGIN PGP SIGNATURE-----\n\n"));
+        assert_eq!(cert.verify_detached(b"package contents", &signature).unwrap(), cert.subkey_fingerprints()[0]);
+        assert!(cert.verify_detached(b"package contentz", &signature).is_err());
+
+        let other = new_cert(0);
+        assert_eq!(other.cert().verify_detached(b"package contents", &signature).unwrap_err(), VAZ256Error::PublicKeyMismatch);
+        let own = other.sign_detached(b"package contents", CREATED).unwrap();
+        assert_eq!(other.cert().verify_detached(b"package contents", &own).unwrap(), other.cert().fingerprint());
+    }
+
+    #[test]
+    fn test_cleartext_signature() {
+        let secret = new_cert(0);
+        let text = "Packages: vaz256\n-----BEGIN not armor\nSHA256: abc  \n";
+        let message = secret.sign_cleartext(text, CREATED).unwrap();
+        assert!(message.starts_with("-----BEGIN PGP SIGNED MESSAGE-----\n\nPackages: vaz256\n- -----BEGIN not armor\nSHA256: abc\n-----BEGIN PGP SIGNATURE-----"));
+        assert_eq!(
+            secret.cert().verify_cleartext(&message).unwrap(),
+            "Packages: vaz256\n-----BEGIN not armor\nSHA256: abc"
+        );
+        let crlf = message.replace('\n', "\r\n");
+        assert!(secret.cert().verify_cleartext(&crlf).is_ok());
+        let tampered = message.replace("vaz256\n", "vaz257\n");
+        assert!(secret.cert().verify_cleartext(&tampered).is_err());
+    }
+
+    #[test]
+    fn test_armor_checksum() {
+        let data = b"openpgp armor".to_vec();
+        let crc = crc24(&data).to_be_bytes();
+        let armored = armor(SIGNATURE_BLOCK, &data);
+        let with_crc = armored.replace("-----END", &format!("={}\n-----END", base64::encode(&crc[1..])));
+        assert_eq!(dearmor(&with_crc, SIGNATURE_BLOCK).unwrap(), data);
+        let bad_crc = armored.replace("-----END", "=AAAA\n-----END");
+        assert!(dearmor(&bad_crc, SIGNATURE_BLOCK).is_err());
+        let with_header = armored.replace("-----\n\n", "-----\nComment: test\n\n");
+        assert_eq!(dearmor(&with_header, SIGNATURE_BLOCK).unwrap(), data);
+        assert_eq!(crc24(b""), 0xB704CE);
+    }
+}
@@ -0,0 +1,119 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! PEM armoring (RFC 7468) of DER encoded structures.
+
+use alloc::{format, string::{String, ToString}, vec::Vec};
+
+use crate::{base64, VAZ256Error, VAZ256Result};
+
+/// Label of PKCS#8 private keys
+pub const PRIVATE_KEY_LABEL: &str = "PRIVATE KEY";
+/// Label of SubjectPublicKeyInfo public keys
+pub const PUBLIC_KEY_LABEL: &str = "PUBLIC KEY";
+/// Label of X.509 certificates
+pub const CERTIFICATE_LABEL: &str = "CERTIFICATE";
+/// Label of PKCS#10 certification requests
+pub const CERTIFICATE_REQUEST_LABEL: &str = "CERTIFICATE REQUEST";
+/// Label of CMS ContentInfo structures
+pub const CMS_LABEL: &str = "CMS";
+
+const LINE_WIDTH: usize = 64;
+
+/// Wraps DER bytes into a PEM block with the given label
+pub fn encode(label: &str, der: &[u8]) -> String {
+    encode_with_width(label, der, LINE_WIDTH)
+}
+
+/// Wraps bytes into a PEM style block with a custom line width, as used by
+/// the OpenSSH armors
+pub(crate) fn encode_with_width(label: &str, der: &[u8], width: usize) -> String {
+    let body = base64::encode(der);
+    let mut pem = String::with_capacity(body.len() + body.len() / width + 2 * label.len() + 40);
+    pem.push_str("-----BEGIN ");
+    pem.push_str(label);
+    pem.push_str("-----\n");
+    for line in body.as_bytes().chunks(width) {
+        // Base64 output is pure ASCII
+        pem.push_str(core::str::from_utf8(line).unwrap());
+        pem.push('\n');
+    }
+    pem.push_str("-----END ");
+    pem.push_str(label);
+    pem.push_str("-----\n");
+    pem
+}
+
+/// Extracts the label and DER bytes of the first PEM block in `pem`
+pub fn decode(pem: &str) -> VAZ256Result<(String, Vec<u8>)> {
+    let mut lines = pem.lines().map(str::trim);
+    let label = lines
+        .by_ref()
+        .find_map(|line| line.strip_prefix("-----BEGIN ")?.strip_suffix("-----"))
+        .ok_or(VAZ256Error::DeserializationError)?
+        .to_string();
+    let end = format!("-----END {}-----", label);
+
+    let mut body = String::new();
+    for line in lines {
+        if line == end {
+            let der = base64::decode(&body).map_err(|_| VAZ256Error::DeserializationError)?;
+            return Ok((label, der));
+        }
+        // Explanatory headers (RFC 1421 style) are not accepted
+        if line.contains(':') {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        body.push_str(line);
+    }
+    Err(VAZ256Error::DeserializationError)
+}
+
+/// Extracts the DER bytes of the first PEM block, requiring the given label
+pub fn decode_label(pem: &str, expected: &str) -> VAZ256Result<Vec<u8>> {
+    let (label, der) = decode(pem)?;
+    if label != expected {
+        return Err(VAZ256Error::DeserializationError);
+    }
+    Ok(der)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let der: Vec<u8> = (0..=255).collect();
+        let pem = encode(PUBLIC_KEY_LABEL, &der);
+        assert!(pem.starts_with("-----BEGIN PUBLIC KEY-----\n"));
+        assert!(pem.lines().all(|line| line.len() <= LINE_WIDTH));
+        assert_eq!(decode(&pem).unwrap(), (PUBLIC_KEY_LABEL.to_string(), der));
+    }
+
+    #[test]
+    fn test_wrong_label() {
+        let pem = encode(PRIVATE_KEY_LABEL, b"key");
+        assert_eq!(decode_label(&pem, PRIVATE_KEY_LABEL).unwrap(), b"key");
+        assert_eq!(
+            decode_label(&pem, PUBLIC_KEY_LABEL).unwrap_err(),
+            VAZ256Error::DeserializationError
+        );
+    }
+
+    #[test]
+    fn test_missing_end() {
+        let pem = "-----BEGIN PUBLIC KEY-----\nAAAA\n";
+        assert!(decode(pem).is_err());
+    }
+}
@@ -18,9 +18,21 @@
 // from the original CRYSTALS-Dilithium implementation for use in VAZ256™
 // signature scheme.
 
-use crate::{fips202, ntt, params_dilithium5, reduce, rounding_dilithium5};
+use crate::{fips202, fips202x4, ntt, params_dilithium5, reduce, rounding_dilithium5};
 
 const N: usize = params_dilithium5::N as usize;
+
+/// Runs an AVX2 kernel from [`crate::avx2`] and returns its result when the CPU supports AVX2.
+/// Otherwise execution falls through to the scalar code that follows.
+macro_rules! try_avx2 {
+    ($kernel:ident($($arg:expr),*)) => {
+        #[cfg(target_arch = "x86_64")]
+        if crate::avx2::available() {
+            // SAFETY: the kernels only require AVX2, which was just detected
+            return unsafe { crate::avx2::$kernel($($arg),*) };
+        }
+    };
+}
 const UNIFORM_NBLOCKS: usize = (767 + fips202::SHAKE128_RATE) / fips202::SHAKE128_RATE;
 const D_SHL: i32 = 1 << (params_dilithium5::D - 1);
 
@@ -41,6 +53,7 @@ impl Default for Poly {
 
 /// Inplace reduction of all coefficients of polynomial to representative in [-6283009,6283007].
 pub fn reduce(a: &mut Poly) {
+    try_avx2!(reduce(&mut a.coeffs));
     // Bad C style
     // for i in 0..N {
     //     a.coeffs[i] = reduce::reduce32(a.coeffs[i]);
@@ -53,6 +66,7 @@ pub fn reduce(a: &mut Poly) {
 
 /// For all coefficients of in/out polynomial add Q if coefficient is negative.
 pub fn caddq(a: &mut Poly) {
+    try_avx2!(caddq(&mut a.coeffs));
     // Bad C style
     // for i in 0..N {
     //     a.coeffs[i] = reduce::caddq(a.coeffs[i]);
@@ -97,12 +111,14 @@ pub fn shiftl(a: &mut Poly) {
 
 /// Inplace forward NTT. Coefficients can grow by 8*Q in absolute value.
 pub fn ntt(a: &mut Poly) {
+    try_avx2!(ntt(&mut a.coeffs));
     ntt::ntt(&mut a.coeffs);
 }
 
 /// Inplace inverse NTT and multiplication by 2^{32}.
 /// Input coefficients need to be less than Q in absolute value and output coefficients are again bounded by Q.
 pub fn invntt_tomont(a: &mut Poly) {
+    try_avx2!(invntt_tomont(&mut a.coeffs));
     ntt::invntt_tomont(&mut a.coeffs);
 }
 
@@ -115,6 +131,7 @@ pub fn invntt_tomont(a: &mut Poly) {
 /// 
 /// Returns resulting polynomial
 pub fn pointwise_montgomery(c: &mut Poly, a: &Poly, b: &Poly) {
+    try_avx2!(pointwise_montgomery(&mut c.coeffs, &a.coeffs, &b.coeffs));
     for i in 0..N {
         c.coeffs[i] = reduce::montgomery_reduce(a.coeffs[i] as i64 * b.coeffs[i] as i64);
     }
@@ -147,6 +164,7 @@ pub fn chknorm(a: &Poly, b: i32) -> i32 {
     if b > (params_dilithium5::Q - 1)/ 8 {
         return 1;
     }
+    try_avx2!(chknorm(&a.coeffs, b));
     // for i in a.coeffs.iter() {
     //     let mut t = *i >> 31;
     //     t = *i - (t & 2 * *i);
@@ -212,6 +230,43 @@ pub fn uniform(a: &mut Poly, seed: &[u8], nonce: u16) {
     }
 }
 
+/// Four-way version of [`uniform`]: samples each polynomial present in `a` from SHAKE128(seed|nonces\[i\]).
+/// Output is identical to calling [`uniform`] on every polynomial.
+pub fn uniform_x4(mut a: [Option<&mut Poly>; 4], seed: &[u8], nonces: [u16; 4]) {
+    let mut state = fips202x4::KeccakStateX4::default();
+    fips202x4::shake128x4_stream_init(&mut state, seed, &nonces);
+
+    let mut buf = [[0u8; UNIFORM_NBLOCKS * fips202::SHAKE128_RATE + 2]; 4];
+    let [b0, b1, b2, b3] = &mut buf;
+    fips202x4::shake128x4_squeezeblocks([b0, b1, b2, b3], UNIFORM_NBLOCKS, &mut state);
+
+    let mut buflen = [UNIFORM_NBLOCKS * fips202::SHAKE128_RATE; 4];
+    let mut ctr = [N; 4];
+    for (lane, poly) in a.iter_mut().enumerate() {
+        if let Some(poly) = poly {
+            ctr[lane] = rej_uniform(&mut poly.coeffs, N, &buf[lane], buflen[lane]);
+        }
+    }
+
+    while ctr.iter().any(|&c| c < N) {
+        let mut off = [0usize; 4];
+        for lane in 0..4 {
+            off[lane] = buflen[lane] % 3;
+            buf[lane].copy_within(buflen[lane] - off[lane]..buflen[lane], 0);
+            buflen[lane] = fips202::SHAKE128_RATE + off[lane];
+        }
+        let [b0, b1, b2, b3] = &mut buf;
+        let out = [&mut b0[off[0]..], &mut b1[off[1]..], &mut b2[off[2]..], &mut b3[off[3]..]];
+        fips202x4::shake128x4_squeezeblocks(out, 1, &mut state);
+        for (lane, poly) in a.iter_mut().enumerate() {
+            if let Some(poly) = poly.as_deref_mut().filter(|_| ctr[lane] < N) {
+                let c = ctr[lane];
+                ctr[lane] += rej_uniform(&mut poly.coeffs[c..], N - c, &buf[lane], buflen[lane]);
+            }
+        }
+    }
+}
+
 /// Bit-pack polynomial t1 with coefficients fitting in 10 bits.
 /// Input coefficients are assumed to be standard representatives.
 pub fn t1_pack(r: &mut [u8], a: &Poly) {
@@ -340,6 +395,7 @@ const UNIFORM_GAMMA1_NBLOCKS: usize = (params_dilithium5::POLYZ_PACKEDBYTES + fi
 ///
 /// Returns a touple of polynomials with coefficients c0, c1
 pub fn decompose(a1: &mut Poly, a0: &mut Poly) {
+    try_avx2!(decompose(&mut a1.coeffs, &mut a0.coeffs));
     for i in 0..N {
         (a1.coeffs[i], a0.coeffs[i]) = rounding_dilithium5::decompose(a1.coeffs[i]);
     }
@@ -417,6 +473,36 @@ pub fn uniform_eta(a: &mut Poly, seed: &[u8], nonce: u16) {
     }
 }
 
+/// Four-way version of [`uniform_eta`]: samples each polynomial present in `a` from SHAKE256(seed|nonces\[i\]).
+/// Output is identical to calling [`uniform_eta`] on every polynomial.
+pub fn uniform_eta_x4(mut a: [Option<&mut Poly>; 4], seed: &[u8], nonces: [u16; 4]) {
+    let mut state = fips202x4::KeccakStateX4::default();
+    fips202x4::shake256x4_stream_init(&mut state, seed, &nonces);
+
+    let mut buf = [[0u8; UNIFORM_ETA_NBLOCKS * fips202::SHAKE256_RATE]; 4];
+    let [b0, b1, b2, b3] = &mut buf;
+    fips202x4::shake256x4_squeezeblocks([b0, b1, b2, b3], UNIFORM_ETA_NBLOCKS, &mut state);
+
+    let buflen = UNIFORM_ETA_NBLOCKS * fips202::SHAKE256_RATE;
+    let mut ctr = [N; 4];
+    for (lane, poly) in a.iter_mut().enumerate() {
+        if let Some(poly) = poly {
+            ctr[lane] = rej_eta(&mut poly.coeffs, N, &buf[lane], buflen);
+        }
+    }
+
+    while ctr.iter().any(|&c| c < N) {
+        let [b0, b1, b2, b3] = &mut buf;
+        fips202x4::shake256x4_squeezeblocks([b0, b1, b2, b3], 1, &mut state);
+        for (lane, poly) in a.iter_mut().enumerate() {
+            if let Some(poly) = poly.as_deref_mut().filter(|_| ctr[lane] < N) {
+                let c = ctr[lane];
+                ctr[lane] += rej_eta(&mut poly.coeffs[c..], N - c, &buf[lane], fips202::SHAKE256_RATE);
+            }
+        }
+    }
+}
+
 /// Sample polynomial with uniformly random coefficients in [-(GAMMA1 - 1), GAMMA1 - 1] by performing rejection sampling on output stream of SHAKE256(seed|nonce).
 pub fn uniform_gamma1(a: &mut Poly, seed: &[u8], nonce: u16) {
     let mut state = fips202::KeccakState::default();
@@ -427,6 +513,22 @@ pub fn uniform_gamma1(a: &mut Poly, seed: &[u8], nonce: u16) {
     z_unpack(a, &mut buf);
 }
 
+/// Four-way version of [`uniform_gamma1`]: samples each polynomial present in `a` from SHAKE256(seed|nonces\[i\]).
+/// Output is identical to calling [`uniform_gamma1`] on every polynomial.
+pub fn uniform_gamma1_x4(a: [Option<&mut Poly>; 4], seed: &[u8], nonces: [u16; 4]) {
+    let mut state = fips202x4::KeccakStateX4::default();
+    fips202x4::shake256x4_stream_init(&mut state, seed, &nonces);
+
+    let mut buf = [[0u8; UNIFORM_GAMMA1_NBLOCKS * fips202::SHAKE256_RATE]; 4];
+    let [b0, b1, b2, b3] = &mut buf;
+    fips202x4::shake256x4_squeezeblocks([b0, b1, b2, b3], UNIFORM_GAMMA1_NBLOCKS, &mut state);
+    for (poly, lane) in a.into_iter().zip(&buf) {
+        if let Some(poly) = poly {
+            z_unpack(poly, lane);
+        }
+    }
+}
+
 /// Implementation of H. Samples polynomial with TAU nonzero coefficients in {-1,1} using the output stream of SHAKE256(seed).
 pub fn challenge(c: &mut Poly, seed: &[u8]) {
     let mut state = fips202::KeccakState::default();
@@ -548,4 +650,52 @@ pub fn w1_pack(r: &mut [u8], a: &Poly) {
     for i in 0..N / 2 {
         r[i] = (a.coeffs[2 * i + 0] | (a.coeffs[2 * i + 1] << 4)) as u8;
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NONCES: [u16; 4] = [0, 7, 0x0103, 0xfff0];
+
+    fn seed() -> [u8; params_dilithium5::CRHBYTES] {
+        let mut seed = [0u8; params_dilithium5::CRHBYTES];
+        fips202::shake256(&mut seed, params_dilithium5::CRHBYTES, b"poly x4 seed", 12);
+        seed
+    }
+
+    fn check_x4(sampler: fn([Option<&mut Poly>; 4], &[u8], [u16; 4]), scalar: fn(&mut Poly, &[u8], u16)) {
+        let seed = seed();
+        for lanes in 1..=4 {
+            let mut polys = [Poly::default(); 4];
+            let mut batch: [Option<&mut Poly>; 4] = Default::default();
+            for (slot, poly) in batch.iter_mut().zip(polys.iter_mut()).take(lanes) {
+                *slot = Some(poly);
+            }
+            sampler(batch, &seed, NONCES);
+
+            for (lane, poly) in polys.iter().enumerate() {
+                let mut expected = Poly::default();
+                if lane < lanes {
+                    scalar(&mut expected, &seed, NONCES[lane]);
+                }
+                assert_eq!(poly.coeffs, expected.coeffs);
+            }
+        }
+    }
+
+    #[test]
+    fn test_uniform_x4() {
+        check_x4(uniform_x4, uniform);
+    }
+
+    #[test]
+    fn test_uniform_eta_x4() {
+        check_x4(uniform_eta_x4, uniform_eta);
+    }
+
+    #[test]
+    fn test_uniform_gamma1_x4() {
+        check_x4(uniform_gamma1_x4, uniform_gamma1);
+    }
+}
@@ -19,7 +19,7 @@
 // signature scheme.
 
 
-use std::mem::swap;
+use core::mem::swap;
 
 use crate::{params_dilithium5, poly_dilithium5, poly_dilithium5::Poly};
 
@@ -54,10 +54,27 @@ impl Default for Polyvecl {
 
 /// Implementation of ExpandA. Generates matrix A with uniformly random coefficients a_{i,j} by performing rejection sampling on the output stream of SHAKE128(rho|j|i).
 pub fn matrix_expand(mat: &mut [Polyvecl], rho: &[u8]) {
-    for i in 0..K {
-        for j in 0..L {
-            poly_dilithium5::uniform(&mut mat[i].vec[j], rho, ((i << 8) + j) as u16);
+    let polys = mat[..K].iter_mut().enumerate().flat_map(|(i, row)| {
+        row.vec.iter_mut().enumerate().map(move |(j, poly)| (poly, ((i << 8) + j) as u16))
+    });
+    sample_x4(poly_dilithium5::uniform_x4, polys, rho);
+}
+
+/// Feeds `(polynomial, nonce)` pairs to a four-way sampler, four at a time.
+fn sample_x4<'a>(
+    sampler: fn([Option<&mut Poly>; 4], &[u8], [u16; 4]),
+    polys: impl Iterator<Item = (&'a mut Poly, u16)>,
+    seed: &[u8],
+) {
+    let mut polys = polys.peekable();
+    while polys.peek().is_some() {
+        let mut batch: [Option<&mut Poly>; 4] = Default::default();
+        let mut nonces = [0u16; 4];
+        for (lane, (poly, nonce)) in polys.by_ref().take(4).enumerate() {
+            batch[lane] = Some(poly);
+            nonces[lane] = nonce;
         }
+        sampler(batch, seed, nonces);
     }
 }
 
@@ -78,17 +95,14 @@ pub fn matrix_pointwise_montgomery(t: &mut Polyveck, mat: &[Polyvecl], v: &Polyv
     }
 }
 
-pub fn l_uniform_eta(v: &mut Polyvecl, seed: &[u8], mut nonce: u16) {
-    for i in 0..L {
-        poly_dilithium5::uniform_eta(&mut v.vec[i], seed, nonce);
-        nonce += 1;
-    }
+pub fn l_uniform_eta(v: &mut Polyvecl, seed: &[u8], nonce: u16) {
+    let polys = v.vec.iter_mut().zip(nonce..);
+    sample_x4(poly_dilithium5::uniform_eta_x4, polys, seed);
 }
 
 pub fn l_uniform_gamma1(v: &mut Polyvecl, seed: &[u8], nonce: u16) {
-    for i in 0..L {
-        poly_dilithium5::uniform_gamma1(&mut v.vec[i], seed, L as u16 * nonce + i as u16);
-    }
+    let polys = v.vec.iter_mut().zip(L as u16 * nonce..);
+    sample_x4(poly_dilithium5::uniform_gamma1_x4, polys, seed);
 }
 pub fn l_reduce(v: &mut Polyvecl) {
     for i in 0..L {
@@ -134,11 +148,9 @@ pub fn l_chknorm(v: &Polyvecl, bound: i32) -> u8 {
 
 //---------------------------------
 
-pub fn k_uniform_eta(v: &mut Polyveck, seed: &[u8], mut nonce: u16) {
-    for i in 0..K {
-        poly_dilithium5::uniform_eta(&mut v.vec[i], seed, nonce);
-        nonce += 1
-    }
+pub fn k_uniform_eta(v: &mut Polyveck, seed: &[u8], nonce: u16) {
+    let polys = v.vec.iter_mut().zip(nonce..);
+    sample_x4(poly_dilithium5::uniform_eta_x4, polys, seed);
 }
 
 /// Reduce coefficients of polynomials in vector of length K
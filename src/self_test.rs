@@ -0,0 +1,281 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Known-answer self-tests, in the style of FIPS 140 power-on self-tests.
+//!
+//! [`self_test`] checks SHAKE256, Dilithium5 key generation, signing and
+//! verification, and the SHAKE256 compression of the public key against
+//! fixed answers. The Dilithium5 answers belong to entry 0 of
+//! `kat/PQCsignKAT_Dilithium5.rsp`. [`crate::low_stack::self_test`] checks
+//! the same answers with the small-stack Dilithium5 code, within
+//! [`crate::low_stack::STACK_BOUND`].
+//!
+//! With the `power-on-self-test` feature each implementation's tests run
+//! before its first key generation, signature or verification. A failure of
+//! either is latched: from then on these operations, and both self-tests,
+//! fail with [`VAZ256Error::SelfTestFailed`].
+
+#[cfg(feature = "power-on-self-test")]
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use crate::dilithium5::{Dilithium5Keypair, PUBLICKEYBYTES, SECRETKEYBYTES, SIGNBYTES};
+use crate::fips202::shake256;
+use crate::sha3::sha3_256;
+use crate::sign_dilithium5_small;
+use crate::{FullPublicKey, VAZ256Error, VAZ256Result, PUBLIC_KEY_SIZE};
+
+/// SHAKE256 of 200 bytes of `0xa3`, which spans two blocks
+const SHAKE256_INPUT: [u8; 200] = [0xa3; 200];
+const SHAKE256_OUTPUT: [u8; 32] = [
+    0xcd, 0x8a, 0x92, 0x0e, 0xd1, 0x41, 0xaa, 0x04, 0x07, 0xa2, 0x2d, 0x59, 0x28, 0x86, 0x52, 0xe9,
+    0xd9, 0xf1, 0xa7, 0xee, 0x0c, 0x1e, 0x7c, 0x1c, 0xa6, 0x99, 0x42, 0x4d, 0xa8, 0x4a, 0x90, 0x4d,
+];
+
+/// Dilithium5 key seed, which is also the VAZ256 secret key
+const KEY_SEED: [u8; 32] = [
+    0x7c, 0x99, 0x35, 0xa0, 0xb0, 0x76, 0x94, 0xaa, 0x0c, 0x6d, 0x10, 0xe4, 0xdb, 0x6b, 0x1a, 0xdd,
+    0x2f, 0xd8, 0x1a, 0x25, 0xcc, 0xb1, 0x48, 0x03, 0x2d, 0xcd, 0x73, 0x99, 0x36, 0x73, 0x7f, 0x2d,
+];
+const MESSAGE: [u8; 33] = [
+    0xd8, 0x1c, 0x4d, 0x8d, 0x73, 0x4f, 0xcb, 0xfb, 0xea, 0xde, 0x3d, 0x3f, 0x8a, 0x03, 0x9f, 0xaa,
+    0x2a, 0x2c, 0x99, 0x57, 0xe8, 0x35, 0xad, 0x55, 0xb2, 0x2e, 0x75, 0xbf, 0x57, 0xbb, 0x55, 0x6a, 0xc8,
+];
+/// SHA3-256 of the Dilithium5 public key
+const PUBLIC_KEY_DIGEST: [u8; 32] = [
+    0x4c, 0xb1, 0x33, 0x52, 0xc6, 0x00, 0xd2, 0x97, 0x20, 0xc5, 0x56, 0x92, 0x85, 0x28, 0xe1, 0x42,
+    0x9f, 0x9d, 0xe7, 0xb5, 0xc1, 0xe5, 0xa7, 0xe3, 0x91, 0x8c, 0x5b, 0xc0, 0xb4, 0x50, 0x2a, 0x33,
+];
+/// SHA3-256 of the deterministic Dilithium5 signature of [`MESSAGE`]
+const SIGNATURE_DIGEST: [u8; 32] = [
+    0xc4, 0x96, 0xbe, 0x59, 0x5c, 0x8f, 0x22, 0x67, 0xf9, 0xa4, 0x41, 0x5f, 0xd6, 0x7c, 0xcf, 0x98,
+    0x33, 0xf1, 0xc0, 0xd6, 0x74, 0x9e, 0x0c, 0xdf, 0x6e, 0x3e, 0x30, 0xda, 0xd1, 0x03, 0xd2, 0xb1,
+];
+/// Compact VAZ256 public key
+const COMPACT_PUBLIC_KEY: [u8; PUBLIC_KEY_SIZE] = [
+    0x85, 0xf6, 0x84, 0xae, 0xb4, 0x01, 0xb9, 0xaa, 0xaf, 0x81, 0x2a, 0x00, 0xe1, 0x24, 0xff, 0x56,
+    0xfe, 0xe5, 0x1b, 0xa7, 0xc1, 0x12, 0x82, 0x61, 0x7f, 0x05, 0x72, 0xcc, 0x79, 0x1d, 0xc8, 0x1c,
+];
+
+/// Latched bits: which implementations passed, and whether any test failed
+#[cfg(feature = "power-on-self-test")]
+const REGULAR_PASSED: u8 = 1;
+#[cfg(feature = "power-on-self-test")]
+const SMALL_PASSED: u8 = 2;
+#[cfg(feature = "power-on-self-test")]
+const FAILED: u8 = 4;
+
+/// Self-test results. Bits are only ever set, so a failure can't be overwritten by a later pass.
+#[cfg(feature = "power-on-self-test")]
+struct Latch(AtomicU8);
+
+#[cfg(feature = "power-on-self-test")]
+impl Latch {
+    const fn new() -> Self {
+        Self(AtomicU8::new(0))
+    }
+
+    fn failed(&self) -> bool {
+        self.0.load(Ordering::Acquire) & FAILED != 0
+    }
+
+    /// Runs `tests` and sets `passed` or [`FAILED`]. Fails without running them once anything has failed.
+    fn run(&self, passed: u8, tests: fn() -> VAZ256Result<()>) -> VAZ256Result<()> {
+        if self.failed() {
+            return Err(VAZ256Error::SelfTestFailed);
+        }
+        let result = tests();
+        let state = self.0.fetch_or(if result.is_ok() { passed } else { FAILED }, Ordering::AcqRel);
+        if state & FAILED != 0 {
+            return Err(VAZ256Error::SelfTestFailed);
+        }
+        result
+    }
+
+    /// Like [`Latch::run`], but passes without running `tests` once they have passed
+    fn check(&self, passed: u8, tests: fn() -> VAZ256Result<()>) -> VAZ256Result<()> {
+        match self.0.load(Ordering::Acquire) {
+            state if state & FAILED != 0 => Err(VAZ256Error::SelfTestFailed),
+            state if state & passed != 0 => Ok(()),
+            _ => self.run(passed, tests),
+        }
+    }
+}
+
+#[cfg(feature = "power-on-self-test")]
+static STATE: Latch = Latch::new();
+
+fn ensure(condition: bool) -> VAZ256Result<()> {
+    if condition {
+        Ok(())
+    } else {
+        Err(VAZ256Error::SelfTestFailed)
+    }
+}
+
+fn shake256_test() -> VAZ256Result<()> {
+    let mut output = [0u8; 32];
+    shake256(&mut output, 32, &SHAKE256_INPUT, SHAKE256_INPUT.len());
+    ensure(output == SHAKE256_OUTPUT)
+}
+
+/// Checks key generation, signing and verification, and the compression of the public key
+fn dilithium5_test(seed: &[u8; 32], message: &[u8]) -> VAZ256Result<()> {
+    let keypair = Dilithium5Keypair::generate(seed);
+    ensure(sha3_256(&keypair.public.bytes) == PUBLIC_KEY_DIGEST)?;
+    ensure(FullPublicKey::new(keypair.public.to_bytes()).public_key().as_bytes() == &COMPACT_PUBLIC_KEY)?;
+
+    let mut signature = keypair.sign(message);
+    ensure(sha3_256(&signature) == SIGNATURE_DIGEST)?;
+    ensure(keypair.public.verify(message, &signature))?;
+    // Verification must also reject
+    signature[0] ^= 1;
+    ensure(!keypair.public.verify(message, &signature))
+}
+
+/// Like [`dilithium5_test`], with the small-stack code and without a copy of any key or signature
+fn dilithium5_small_test(seed: &[u8; 32], message: &[u8]) -> VAZ256Result<()> {
+    let mut pk = [0u8; PUBLICKEYBYTES];
+    let mut sk = [0u8; SECRETKEYBYTES];
+    sign_dilithium5_small::keypair(&mut pk, &mut sk, seed);
+    ensure(sha3_256(&pk) == PUBLIC_KEY_DIGEST)?;
+    let mut compact = [0u8; PUBLIC_KEY_SIZE];
+    shake256(&mut compact, PUBLIC_KEY_SIZE, &pk, PUBLICKEYBYTES);
+    ensure(compact == COMPACT_PUBLIC_KEY)?;
+
+    let mut signature = [0u8; SIGNBYTES];
+    sign_dilithium5_small::signature(&mut signature, message, &sk);
+    ensure(sha3_256(&signature) == SIGNATURE_DIGEST)?;
+    ensure(sign_dilithium5_small::verify(&signature, message, &pk))?;
+    signature[0] ^= 1;
+    ensure(!sign_dilithium5_small::verify(&signature, message, &pk))
+}
+
+fn known_answer_tests() -> VAZ256Result<()> {
+    shake256_test()?;
+    dilithium5_test(&KEY_SEED, &MESSAGE)
+}
+
+fn small_known_answer_tests() -> VAZ256Result<()> {
+    shake256_test()?;
+    dilithium5_small_test(&KEY_SEED, &MESSAGE)
+}
+
+/// Runs the known-answer self-tests, failing with [`VAZ256Error::SelfTestFailed`].
+/// With the `power-on-self-test` feature the result is also latched.
+#[cfg(not(feature = "power-on-self-test"))]
+pub fn self_test() -> VAZ256Result<()> {
+    known_answer_tests()
+}
+
+/// Runs the known-answer self-tests, failing with [`VAZ256Error::SelfTestFailed`].
+/// The result is latched: after a failure this keeps failing without running the tests.
+#[cfg(feature = "power-on-self-test")]
+pub fn self_test() -> VAZ256Result<()> {
+    STATE.run(REGULAR_PASSED, known_answer_tests)
+}
+
+/// The self-tests behind [`crate::low_stack::self_test`]
+#[cfg(not(feature = "power-on-self-test"))]
+pub(crate) fn self_test_small() -> VAZ256Result<()> {
+    small_known_answer_tests()
+}
+
+/// The self-tests behind [`crate::low_stack::self_test`], latched like [`self_test`]
+#[cfg(feature = "power-on-self-test")]
+pub(crate) fn self_test_small() -> VAZ256Result<()> {
+    STATE.run(SMALL_PASSED, small_known_answer_tests)
+}
+
+/// Gate in front of key generation, signing and verification. Without the
+/// `power-on-self-test` feature it always passes.
+#[cfg(not(feature = "power-on-self-test"))]
+#[inline(always)]
+pub(crate) fn check() -> VAZ256Result<()> {
+    Ok(())
+}
+
+/// Gate in front of key generation, signing and verification, which runs
+/// [`self_test`] on first use and fails once any self-test has failed
+#[cfg(feature = "power-on-self-test")]
+pub(crate) fn check() -> VAZ256Result<()> {
+    STATE.check(REGULAR_PASSED, known_answer_tests)
+}
+
+/// [`check`] for the functions of [`crate::low_stack`]
+#[cfg(not(feature = "power-on-self-test"))]
+#[inline(always)]
+pub(crate) fn check_small() -> VAZ256Result<()> {
+    Ok(())
+}
+
+/// [`check`] for the functions of [`crate::low_stack`], which runs the
+/// small-stack self-tests on first use
+#[cfg(feature = "power-on-self-test")]
+pub(crate) fn check_small() -> VAZ256Result<()> {
+    STATE.check(SMALL_PASSED, small_known_answer_tests)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_test() {
+        assert_eq!(self_test(), Ok(()));
+        assert_eq!(check(), Ok(()));
+        assert_eq!(self_test_small(), Ok(()));
+        assert_eq!(check_small(), Ok(()));
+        #[cfg(feature = "power-on-self-test")]
+        assert_eq!(STATE.0.load(Ordering::Acquire), REGULAR_PASSED | SMALL_PASSED);
+    }
+
+    #[test]
+    fn test_wrong_answers_fail() {
+        let mut seed = KEY_SEED;
+        seed[0] ^= 1;
+        assert_eq!(dilithium5_test(&seed, &MESSAGE), Err(VAZ256Error::SelfTestFailed));
+        assert_eq!(dilithium5_test(&KEY_SEED, &MESSAGE[1..]), Err(VAZ256Error::SelfTestFailed));
+        assert_eq!(dilithium5_small_test(&seed, &MESSAGE), Err(VAZ256Error::SelfTestFailed));
+        assert_eq!(dilithium5_small_test(&KEY_SEED, &MESSAGE[1..]), Err(VAZ256Error::SelfTestFailed));
+    }
+
+    #[test]
+    #[cfg(feature = "power-on-self-test")]
+    fn test_failure_is_latched() {
+        // A latch of its own, as failing the global one would fail every other test
+        let latch = Latch::new();
+        assert_eq!(latch.check(SMALL_PASSED, small_known_answer_tests), Ok(()));
+        assert_eq!(latch.run(REGULAR_PASSED, || Err(VAZ256Error::SelfTestFailed)), Err(VAZ256Error::SelfTestFailed));
+        for _ in 0..2 {
+            assert_eq!(latch.run(REGULAR_PASSED, known_answer_tests), Err(VAZ256Error::SelfTestFailed));
+            assert_eq!(latch.check(REGULAR_PASSED, known_answer_tests), Err(VAZ256Error::SelfTestFailed));
+            assert_eq!(latch.check(SMALL_PASSED, small_known_answer_tests), Err(VAZ256Error::SelfTestFailed));
+            assert_eq!(latch.run(SMALL_PASSED, small_known_answer_tests), Err(VAZ256Error::SelfTestFailed));
+        }
+        assert_eq!(latch.0.load(Ordering::Acquire), SMALL_PASSED | FAILED);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_answers_match_kat_file() {
+        let rsp = crate::kat::parse(include_str!("../kat/PQCsignKAT_Dilithium5.rsp")).unwrap();
+        let entry = &rsp.entries[0];
+        assert_eq!(entry.bytes("msg").unwrap(), MESSAGE);
+        assert_eq!(sha3_256(&entry.bytes("pk").unwrap()), PUBLIC_KEY_DIGEST);
+        let sm = entry.bytes("sm").unwrap();
+        assert_eq!(sha3_256(&sm[..crate::dilithium5::SIGNBYTES]), SIGNATURE_DIGEST);
+        let mut compact = [0u8; PUBLIC_KEY_SIZE];
+        shake256(&mut compact, PUBLIC_KEY_SIZE, &entry.bytes("pk").unwrap(), crate::dilithium5::PUBLICKEYBYTES);
+        assert_eq!(compact, COMPACT_PUBLIC_KEY);
+    }
+}
@@ -0,0 +1,131 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Fixed-output SHA3-224/256/384/512 (FIPS 202) and the original Keccak-256
+//! used by Ethereum, which differs from SHA3-256 only in its padding byte
+//! (`0x01` instead of `0x06`).
+
+use crate::fips202::{keccak_absorb, keccak_finalize, keccak_squeeze, KeccakState};
+
+const SHA3_PADDING: u8 = 0x06;
+const KECCAK_PADDING: u8 = 0x01;
+
+macro_rules! fixed_hasher {
+    ($name:ident, $function:ident, $size:ident, $bytes:expr, $padding:expr, $doc:expr) => {
+        #[doc = concat!("Output size of ", $doc)]
+        pub const $size: usize = $bytes;
+
+        #[doc = concat!("Incremental ", $doc, " hasher")]
+        #[derive(Clone, Default)]
+        pub struct $name {
+            state: KeccakState,
+        }
+
+        impl $name {
+            const RATE: usize = 200 - 2 * $bytes;
+
+            /// Creates a hasher with an empty input
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// Absorbs more input
+            pub fn update(&mut self, data: &[u8]) {
+                keccak_absorb(&mut self.state, Self::RATE, data, data.len());
+            }
+
+            /// Returns the digest of everything absorbed
+            pub fn finalize(mut self) -> [u8; $bytes] {
+                keccak_finalize(&mut self.state.s, self.state.pos, Self::RATE, $padding);
+                let mut digest = [0u8; $bytes];
+                keccak_squeeze(&mut digest, $bytes, &mut self.state.s, Self::RATE, Self::RATE);
+                digest
+            }
+        }
+
+        #[doc = concat!("Returns the ", $doc, " digest of `data`")]
+        pub fn $function(data: &[u8]) -> [u8; $bytes] {
+            let mut hasher = $name::new();
+            hasher.update(data);
+            hasher.finalize()
+        }
+    };
+}
+
+fixed_hasher!(Sha3_224, sha3_224, SHA3_224_SIZE, 28, SHA3_PADDING, "SHA3-224");
+fixed_hasher!(Sha3_256, sha3_256, SHA3_256_SIZE, 32, SHA3_PADDING, "SHA3-256");
+fixed_hasher!(Sha3_384, sha3_384, SHA3_384_SIZE, 48, SHA3_PADDING, "SHA3-384");
+fixed_hasher!(Sha3_512, sha3_512, SHA3_512_SIZE, 64, SHA3_PADDING, "SHA3-512");
+fixed_hasher!(Keccak256, keccak256, KECCAK256_SIZE, 32, KECCAK_PADDING, "Keccak-256");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_sha3_abc() {
+        assert_eq!(hex::encode(sha3_224(b"abc")), "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf");
+        assert_eq!(
+            hex::encode(sha3_256(b"abc")),
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+        );
+        assert_eq!(
+            hex::encode(sha3_384(b"abc")),
+            "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b2\
+             98d88cea927ac7f539f1edf228376d25"
+        );
+        assert_eq!(
+            hex::encode(sha3_512(b"abc")),
+            "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e\
+             10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"
+        );
+        assert_eq!(
+            hex::encode(sha3_256(b"")),
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"
+        );
+    }
+
+    #[test]
+    fn test_keccak256() {
+        assert_eq!(
+            hex::encode(keccak256(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex::encode(keccak256(b"abc")),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+    }
+
+    #[test]
+    fn test_incremental() {
+        let data: Vec<u8> = (0..=255u8).chain(0..=255u8).collect();
+        let mut sha3 = Sha3_256::new();
+        let mut keccak = Keccak256::new();
+        for piece in data.chunks(135) {
+            sha3.update(piece);
+            keccak.update(piece);
+        }
+        assert_eq!(
+            hex::encode(sha3.clone().finalize()),
+            "d4728ea5e9f3819f2b4760151a8f802dbe9f941fd6fb59b3715892436555772a"
+        );
+        assert_eq!(sha3.finalize(), sha3_256(&data));
+        assert_eq!(
+            hex::encode(keccak.finalize()),
+            "f55ba327291604f0e5be6651752398b7be2331aad65f5763ce067df95cc13be1"
+        );
+    }
+}
@@ -0,0 +1,155 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! SHAKE128 and SHAKE256 extendable-output functions (FIPS 202).
+//!
+//! With the `std` feature the hashers absorb through `std::io::Write`, so
+//! `io::copy` can feed them from any reader. They can be cloned to fork a
+//! common prefix, and their output is read from an [`XofReader`] in pieces
+//! of any length.
+
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
+
+use crate::fips202::{keccak_absorb, keccak_finalize, keccak_squeeze, KeccakState, SHAKE128_RATE, SHAKE256_RATE};
+
+const SHAKE_PADDING: u8 = 0x1F;
+
+/// Output stream of a finalized extendable-output function
+#[derive(Clone)]
+pub struct XofReader {
+    state: KeccakState,
+    rate: usize,
+}
+
+impl XofReader {
+    /// Finalizes `state` with padding byte `padding` and starts squeezing
+    pub(crate) fn new(mut state: KeccakState, rate: usize, padding: u8) -> Self {
+        keccak_finalize(&mut state.s, state.pos, rate, padding);
+        state.pos = rate;
+        XofReader { state, rate }
+    }
+
+    /// Fills `out` with the next output bytes
+    pub fn squeeze(&mut self, out: &mut [u8]) {
+        self.state.pos = keccak_squeeze(out, out.len(), &mut self.state.s, self.state.pos, self.rate);
+    }
+}
+
+#[cfg(feature = "std")]
+impl Read for XofReader {
+    /// Always fills `buf` completely, as the output stream never ends
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.squeeze(buf);
+        Ok(buf.len())
+    }
+}
+
+macro_rules! shake_hasher {
+    ($name:ident, $rate:expr, $doc:expr) => {
+        #[doc = concat!("Incremental ", $doc, " hasher")]
+        #[derive(Clone, Default)]
+        pub struct $name {
+            state: KeccakState,
+        }
+
+        impl $name {
+            /// Creates a hasher with an empty input
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// Absorbs more input
+            pub fn update(&mut self, data: &[u8]) {
+                keccak_absorb(&mut self.state, $rate, data, data.len());
+            }
+
+            /// Finishes absorbing and returns the output stream
+            pub fn finalize_xof(self) -> XofReader {
+                XofReader::new(self.state, $rate, SHAKE_PADDING)
+            }
+
+            /// Fills `out` with output
+            pub fn finalize(self, out: &mut [u8]) {
+                self.finalize_xof().squeeze(out);
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl Write for $name {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.update(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+    };
+}
+
+shake_hasher!(Shake128, SHAKE128_RATE, "SHAKE128");
+shake_hasher!(Shake256, SHAKE256_RATE, "SHAKE256");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+
+    #[test]
+    fn test_shake_vectors() {
+        let mut out = [0u8; 32];
+        let mut shake = Shake128::new();
+        shake.update(b"abc");
+        shake.finalize(&mut out);
+        assert_eq!(hex::encode(out), "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8");
+        Shake256::new().finalize(&mut out);
+        assert_eq!(hex::encode(out), "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_write_and_read() {
+        let input = b"The quick brown fox".repeat(20);
+        let mut shake = Shake128::new();
+        io::copy(&mut &input[..], &mut shake).unwrap();
+
+        let mut reader = shake.finalize_xof();
+        let mut out = [0u8; 500];
+        for piece in out.chunks_mut(77) {
+            reader.read_exact(piece).unwrap();
+        }
+        assert_eq!(hex::encode(&out[480..]), "032edaf3835be9d588b6f395987a3a00471c80e5");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_clone_forks_state() {
+        let mut prefix = Shake256::new();
+        prefix.write_all(b"shared prefix|").unwrap();
+        let mut left = prefix.clone();
+        let mut right = prefix;
+        left.update(b"left");
+        right.update(b"right");
+
+        let mut expected = Shake256::new();
+        expected.update(b"shared prefix|left");
+        let (mut a, mut b) = ([0u8; 64], [0u8; 64]);
+        left.finalize(&mut a);
+        expected.finalize(&mut b);
+        assert_eq!(a, b);
+        right.finalize(&mut b);
+        assert_ne!(a, b);
+    }
+}
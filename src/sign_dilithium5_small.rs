@@ -0,0 +1,345 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Dilithium5 key generation, signing and verification with a small stack.
+//!
+//! Produces exactly the bytes of [`crate::sign_dilithium5`], but never holds
+//! a vector of polynomials. Matrix A is sampled one polynomial at a time
+//! while a row of A·v is accumulated, and y, w and the secret vectors are
+//! recomputed from their seeds or packed forms whenever they are needed
+//! again, following the "small" strategies of pqm4.
+
+use crate::params_dilithium5::{
+    BETA, CRHBYTES, GAMMA1, GAMMA2, K, L, OMEGA, POLYETA_PACKEDBYTES, POLYT0_PACKEDBYTES, POLYT1_PACKEDBYTES,
+    POLYW1_PACKEDBYTES, POLYZ_PACKEDBYTES, PUBLICKEYBYTES, SEEDBYTES, SIGNBYTES,
+};
+use crate::{fips202, poly_dilithium5 as poly, poly_dilithium5::Poly};
+
+const N: usize = crate::params_dilithium5::N as usize;
+
+/// Offsets of the packed s1, s2 and t0 inside a secret key
+const SK_S1: usize = 3 * SEEDBYTES;
+const SK_S2: usize = SK_S1 + L * POLYETA_PACKEDBYTES;
+const SK_T0: usize = SK_S2 + K * POLYETA_PACKEDBYTES;
+
+/// Offsets of z and the hint inside a signature
+const SIG_Z: usize = SEEDBYTES;
+const SIG_H: usize = SIG_Z + L * POLYZ_PACKEDBYTES;
+
+/// Computes row `i` of A·v in NTT domain, sampling A one polynomial at a time.
+/// `v_hat(j, poly)` must fill `poly` with the NTT of the j-th entry of v.
+fn matrix_row(w: &mut Poly, rho: &[u8], i: usize, v_hat: impl Fn(usize, &mut Poly)) {
+    let mut a = Poly::default();
+    let mut v = Poly::default();
+    let mut t = Poly::default();
+    for j in 0..L {
+        poly::uniform(&mut a, rho, ((i << 8) + j) as u16);
+        v_hat(j, &mut v);
+        if j == 0 {
+            poly::pointwise_montgomery(w, &a, &v);
+        } else {
+            poly::pointwise_montgomery(&mut t, &a, &v);
+            poly::add_ip(w, &t);
+        }
+    }
+}
+
+/// Multiplies `cp` by the NTT of `s` and returns to normal domain, leaving the product in `r`
+fn challenge_product(r: &mut Poly, cp: &Poly, s: &mut Poly) {
+    poly::ntt(s);
+    poly::pointwise_montgomery(r, cp, s);
+    poly::invntt_tomont(r);
+}
+
+/// Generate public and private key from `seed`, same as [`crate::sign_dilithium5::keypair`].
+pub fn keypair(pk: &mut [u8], sk: &mut [u8], seed: &[u8]) {
+    const SEEDBUF_LEN: usize = 2 * SEEDBYTES + CRHBYTES;
+    let mut seedbuf = [0u8; SEEDBUF_LEN];
+    fips202::shake256(&mut seedbuf, SEEDBUF_LEN, seed, SEEDBYTES);
+    let (rho, rest) = seedbuf.split_at(SEEDBYTES);
+    let (rhoprime, key) = rest.split_at(CRHBYTES);
+
+    pk[..SEEDBYTES].copy_from_slice(rho);
+    sk[..SEEDBYTES].copy_from_slice(rho);
+    sk[SEEDBYTES..2 * SEEDBYTES].copy_from_slice(key);
+
+    let mut s = Poly::default();
+    for j in 0..L {
+        poly::uniform_eta(&mut s, rhoprime, j as u16);
+        poly::eta_pack(&mut sk[SK_S1 + j * POLYETA_PACKEDBYTES..], &s);
+    }
+    for i in 0..K {
+        poly::uniform_eta(&mut s, rhoprime, (L + i) as u16);
+        poly::eta_pack(&mut sk[SK_S2 + i * POLYETA_PACKEDBYTES..], &s);
+    }
+
+    let mut t1 = Poly::default();
+    let mut t0 = Poly::default();
+    for i in 0..K {
+        matrix_row(&mut t1, rho, i, |j, v| {
+            poly::eta_unpack(v, &sk[SK_S1 + j * POLYETA_PACKEDBYTES..]);
+            poly::ntt(v);
+        });
+        poly::reduce(&mut t1);
+        poly::invntt_tomont(&mut t1);
+        poly::eta_unpack(&mut s, &sk[SK_S2 + i * POLYETA_PACKEDBYTES..]);
+        poly::add_ip(&mut t1, &s);
+        poly::caddq(&mut t1);
+
+        poly::power2round(&mut t1, &mut t0);
+        poly::t1_pack(&mut pk[SEEDBYTES + i * POLYT1_PACKEDBYTES..], &t1);
+        poly::t0_pack(&mut sk[SK_T0 + i * POLYT0_PACKEDBYTES..], &t0);
+    }
+
+    fips202::shake256(&mut sk[2 * SEEDBYTES..3 * SEEDBYTES], SEEDBYTES, pk, PUBLICKEYBYTES);
+}
+
+/// Compute a deterministic signature, same as [`crate::sign_dilithium5::signature`] without `rnd`.
+pub fn signature(sig: &mut [u8], msg: &[u8], sk: &[u8]) {
+    let rho = &sk[..SEEDBYTES];
+    let mut keymu = [0u8; SEEDBYTES + CRHBYTES];
+    keymu[..SEEDBYTES].copy_from_slice(&sk[SEEDBYTES..2 * SEEDBYTES]);
+
+    let mut state = fips202::KeccakState::default();
+    fips202::shake256_absorb(&mut state, &sk[2 * SEEDBYTES..3 * SEEDBYTES], SEEDBYTES);
+    fips202::shake256_absorb(&mut state, msg, msg.len());
+    fips202::shake256_finalize(&mut state);
+    fips202::shake256_squeeze(&mut keymu[SEEDBYTES..], CRHBYTES, &mut state);
+    let mu = &keymu[SEEDBYTES..];
+
+    let mut rhoprime = [0u8; CRHBYTES];
+    fips202::shake256(&mut rhoprime, CRHBYTES, &keymu, SEEDBYTES + CRHBYTES);
+
+    // Row i of w = A·y, split into high bits w1 and low bits w0
+    let w_row = |w1: &mut Poly, w0: &mut Poly, i: usize, nonce: u16| {
+        matrix_row(w0, rho, i, |j, v| {
+            poly::uniform_gamma1(v, &rhoprime, L as u16 * nonce + j as u16);
+            poly::ntt(v);
+        });
+        poly::reduce(w0);
+        poly::invntt_tomont(w0);
+        poly::caddq(w0);
+        poly::decompose(w0, w1);
+    };
+
+    let mut cp = Poly::default();
+    let mut w1 = Poly::default();
+    let mut w0 = Poly::default();
+    let mut s = Poly::default();
+    let mut r = Poly::default();
+    let mut buf = [0u8; POLYW1_PACKEDBYTES];
+    let mut nonce: u16 = 0;
+    'attempt: loop {
+        let y_nonce = nonce;
+        nonce += 1;
+
+        // Challenge from mu and the packed high bits of w, absorbed row by row
+        state.init();
+        fips202::shake256_absorb(&mut state, mu, CRHBYTES);
+        for i in 0..K {
+            w_row(&mut w1, &mut w0, i, y_nonce);
+            poly::w1_pack(&mut buf, &w1);
+            fips202::shake256_absorb(&mut state, &buf, POLYW1_PACKEDBYTES);
+        }
+        fips202::shake256_finalize(&mut state);
+        fips202::shake256_squeeze(sig, SEEDBYTES, &mut state);
+        poly::challenge(&mut cp, sig);
+        poly::ntt(&mut cp);
+
+        // z = y + c*s1, packed straight into the signature
+        for j in 0..L {
+            poly::eta_unpack(&mut s, &sk[SK_S1 + j * POLYETA_PACKEDBYTES..]);
+            challenge_product(&mut r, &cp, &mut s);
+            poly::uniform_gamma1(&mut s, &rhoprime, L as u16 * y_nonce + j as u16);
+            poly::add_ip(&mut r, &s);
+            poly::reduce(&mut r);
+            if poly::chknorm(&r, (GAMMA1 - BETA) as i32) > 0 {
+                continue 'attempt;
+            }
+            poly::z_pack(&mut sig[SIG_Z + j * POLYZ_PACKEDBYTES..], &r);
+        }
+
+        // Recompute w row by row to check the low bits and emit the hint
+        sig[SIG_H..SIG_H + OMEGA + K].fill(0);
+        let mut k = 0;
+        for i in 0..K {
+            w_row(&mut w1, &mut w0, i, y_nonce);
+
+            poly::eta_unpack(&mut s, &sk[SK_S2 + i * POLYETA_PACKEDBYTES..]);
+            challenge_product(&mut r, &cp, &mut s);
+            poly::sub_ip(&mut w0, &r);
+            poly::reduce(&mut w0);
+            if poly::chknorm(&w0, (GAMMA2 - BETA) as i32) > 0 {
+                continue 'attempt;
+            }
+
+            poly::t0_unpack(&mut s, &sk[SK_T0 + i * POLYT0_PACKEDBYTES..]);
+            challenge_product(&mut r, &cp, &mut s);
+            poly::reduce(&mut r);
+            if poly::chknorm(&r, GAMMA2 as i32) > 0 {
+                continue 'attempt;
+            }
+
+            poly::add_ip(&mut w0, &r);
+            poly::make_hint(&mut r, &w0, &w1);
+            for (j, &bit) in r.coeffs.iter().enumerate() {
+                if bit != 0 {
+                    if k == OMEGA {
+                        continue 'attempt;
+                    }
+                    sig[SIG_H + k] = j as u8;
+                    k += 1;
+                }
+            }
+            sig[SIG_H + OMEGA + i] = k as u8;
+        }
+        return;
+    }
+}
+
+/// Decodes row `i` of the packed hint into `h`, with the same validity checks as `unpack_sig`.
+fn unpack_hint(h: &mut Poly, hint: &[u8], i: usize) -> bool {
+    let start = if i == 0 { 0 } else { hint[OMEGA + i - 1] as usize };
+    let end = hint[OMEGA + i] as usize;
+    if end < start || end > OMEGA {
+        return false;
+    }
+
+    h.coeffs = [0; N];
+    for j in start..end {
+        if j > start && hint[j] <= hint[j - 1] {
+            return false;
+        }
+        h.coeffs[hint[j] as usize] = 1;
+    }
+    i + 1 < K || hint[end..OMEGA].iter().all(|&b| b == 0)
+}
+
+/// Verify a signature, same as [`crate::sign_dilithium5::verify`].
+pub fn verify(sig: &[u8], m: &[u8], pk: &[u8]) -> bool {
+    if sig.len() != SIGNBYTES {
+        return false;
+    }
+    let rho = &pk[..SEEDBYTES];
+    let hint = &sig[SIG_H..];
+
+    let mut z = Poly::default();
+    for j in 0..L {
+        poly::z_unpack(&mut z, &sig[SIG_Z + j * POLYZ_PACKEDBYTES..]);
+        if poly::chknorm(&z, (GAMMA1 - BETA) as i32) > 0 {
+            return false;
+        }
+    }
+
+    // Compute CRH(CRH(rho, t1), msg)
+    let mut mu = [0u8; CRHBYTES];
+    let mut state = fips202::KeccakState::default();
+    fips202::shake256(&mut mu, SEEDBYTES, pk, PUBLICKEYBYTES);
+    fips202::shake256_absorb(&mut state, &mu, SEEDBYTES);
+    fips202::shake256_absorb(&mut state, m, m.len());
+    fips202::shake256_finalize(&mut state);
+    fips202::shake256_squeeze(&mut mu, CRHBYTES, &mut state);
+
+    let mut cp = Poly::default();
+    poly::challenge(&mut cp, &sig[..SEEDBYTES]);
+    poly::ntt(&mut cp);
+
+    // Reconstruct w1 = UseHint(h, Az - c*t1*2^d) row by row and hash it
+    let mut w = Poly::default();
+    let mut t = Poly::default();
+    let mut h = Poly::default();
+    let mut buf = [0u8; POLYW1_PACKEDBYTES];
+    state.init();
+    fips202::shake256_absorb(&mut state, &mu, CRHBYTES);
+    for i in 0..K {
+        matrix_row(&mut w, rho, i, |j, v| {
+            poly::z_unpack(v, &sig[SIG_Z + j * POLYZ_PACKEDBYTES..]);
+            poly::ntt(v);
+        });
+        poly::t1_unpack(&mut t, &pk[SEEDBYTES + i * POLYT1_PACKEDBYTES..]);
+        poly::shiftl(&mut t);
+        poly::ntt(&mut t);
+        poly::pointwise_montgomery(&mut h, &cp, &t);
+        poly::sub_ip(&mut w, &h);
+        poly::reduce(&mut w);
+        poly::invntt_tomont(&mut w);
+        poly::caddq(&mut w);
+
+        if !unpack_hint(&mut h, hint, i) {
+            return false;
+        }
+        poly::use_hint(&mut w, &h);
+        poly::w1_pack(&mut buf, &w);
+        fips202::shake256_absorb(&mut state, &buf, POLYW1_PACKEDBYTES);
+    }
+
+    let mut c2 = [0u8; SEEDBYTES];
+    fips202::shake256_finalize(&mut state);
+    fips202::shake256_squeeze(&mut c2, SEEDBYTES, &mut state);
+    // Doesn't require constant time equality check
+    c2 == sig[..SEEDBYTES]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params_dilithium5::SECRETKEYBYTES;
+    use crate::sign_dilithium5;
+
+    fn keys(seed: &[u8]) -> ([u8; PUBLICKEYBYTES], [u8; SECRETKEYBYTES]) {
+        let mut pk = [0u8; PUBLICKEYBYTES];
+        let mut sk = [0u8; SECRETKEYBYTES];
+        keypair(&mut pk, &mut sk, seed);
+        (pk, sk)
+    }
+
+    #[test]
+    fn test_matches_regular_implementation() {
+        for seed in [[0u8; SEEDBYTES], [0x5a; SEEDBYTES], [0xff; SEEDBYTES]] {
+            let (pk, sk) = keys(&seed);
+            let mut expected_pk = [0u8; PUBLICKEYBYTES];
+            let mut expected_sk = [0u8; SECRETKEYBYTES];
+            sign_dilithium5::keypair(&mut expected_pk, &mut expected_sk, &seed);
+            assert_eq!(pk, expected_pk);
+            assert_eq!(sk, expected_sk);
+
+            for msg in [&b""[..], b"low stack", &[0xa5; 1000]] {
+                let mut sig = [0u8; SIGNBYTES];
+                let mut expected = [0u8; SIGNBYTES];
+                signature(&mut sig, msg, &sk);
+                sign_dilithium5::signature(&mut expected, msg, &sk, None);
+                assert_eq!(sig, expected);
+                assert!(verify(&sig, msg, &pk));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejects_what_regular_verify_rejects() {
+        let (pk, sk) = keys(&[7u8; SEEDBYTES]);
+        let mut sig = [0u8; SIGNBYTES];
+        signature(&mut sig, b"message", &sk);
+        assert!(!verify(&sig, b"massage", &pk));
+        assert!(!verify(&sig[1..], b"message", &pk));
+
+        // Flip bytes in the challenge, z and each part of the hint
+        let positions = [0, SIG_Z + 5, SIG_H - 1, SIG_H, SIG_H + OMEGA - 1, SIG_H + OMEGA, SIGNBYTES - 1];
+        for pos in positions {
+            for delta in [1u8, 0x80] {
+                let mut bad = sig;
+                bad[pos] ^= delta;
+                assert_eq!(verify(&bad, b"message", &pk), sign_dilithium5::verify(&bad, b"message", &pk));
+            }
+        }
+    }
+}
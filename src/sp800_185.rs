@@ -0,0 +1,538 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! SHA-3 derived functions of NIST SP 800-185: cSHAKE, KMAC, TupleHash and
+//! ParallelHash, in their 128 and 256-bit security variants.
+//!
+//! Each function has an incremental type and one-shot helpers. The `_xof`
+//! forms encode an output length of zero, so that their output is a stream
+//! whose prefix does not depend on how much is requested.
+
+use crate::fips202::{keccak_absorb, KeccakState, SHAKE128_RATE, SHAKE256_RATE};
+pub use crate::shake::XofReader;
+
+const CSHAKE_DOMAIN: u8 = 0x04;
+const SHAKE_DOMAIN: u8 = 0x1F;
+
+/// Shortest tag [`Kmac::verify`] accepts: SP 800-185 (section 8.4.2) forbids
+/// MAC outputs shorter than 32 bits
+pub const KMAC_MIN_TAG_SIZE: usize = 4;
+
+/// Security strength of a function
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Strength {
+    S128,
+    S256,
+}
+
+impl Strength {
+    fn rate(self) -> usize {
+        match self {
+            Strength::S128 => SHAKE128_RATE,
+            Strength::S256 => SHAKE256_RATE,
+        }
+    }
+
+    /// Size of the chaining values of ParallelHash
+    fn chain_size(self) -> usize {
+        match self {
+            Strength::S128 => 32,
+            Strength::S256 => 64,
+        }
+    }
+}
+
+/// Returns `left_encode(x)` in a buffer with its length
+fn left_encode(x: u64) -> ([u8; 9], usize) {
+    let n = (8 - x.leading_zeros() as usize / 8).max(1);
+    let mut out = [0u8; 9];
+    out[0] = n as u8;
+    out[1..=n].copy_from_slice(&x.to_be_bytes()[8 - n..]);
+    (out, n + 1)
+}
+
+/// Returns `right_encode(x)` in a buffer with its length
+fn right_encode(x: u64) -> ([u8; 9], usize) {
+    let n = (8 - x.leading_zeros() as usize / 8).max(1);
+    let mut out = [0u8; 9];
+    out[..n].copy_from_slice(&x.to_be_bytes()[8 - n..]);
+    out[n] = n as u8;
+    (out, n + 1)
+}
+
+/// cSHAKE128 or cSHAKE256 (SP 800-185, section 3)
+#[derive(Clone)]
+pub struct CShake {
+    state: KeccakState,
+    strength: Strength,
+    domain: u8,
+}
+
+impl CShake {
+    fn new(strength: Strength, name: &[u8], customization: &[u8]) -> Self {
+        let mut cshake = CShake { state: KeccakState::default(), strength, domain: SHAKE_DOMAIN };
+        // With both strings empty cSHAKE is plain SHAKE
+        if !name.is_empty() || !customization.is_empty() {
+            cshake.domain = CSHAKE_DOMAIN;
+            cshake.bytepad(&[name, customization]);
+        }
+        cshake
+    }
+
+    /// Creates cSHAKE128 with function name `name` and customization string `customization`
+    pub fn v128(name: &[u8], customization: &[u8]) -> Self {
+        Self::new(Strength::S128, name, customization)
+    }
+
+    /// Creates cSHAKE256 with function name `name` and customization string `customization`
+    pub fn v256(name: &[u8], customization: &[u8]) -> Self {
+        Self::new(Strength::S256, name, customization)
+    }
+
+    /// Absorbs `bytepad(encode_string(s_1) || ... || encode_string(s_n), rate)`
+    fn bytepad(&mut self, strings: &[&[u8]]) {
+        let rate = self.strength.rate();
+        let (w, w_len) = left_encode(rate as u64);
+        self.update(&w[..w_len]);
+        let mut written = w_len;
+        for s in strings {
+            written += self.encode_string(s);
+        }
+        let padding = (rate - written % rate) % rate;
+        self.update(&[0u8; SHAKE128_RATE][..padding]);
+    }
+
+    /// Absorbs `encode_string(s)` and returns its length
+    fn encode_string(&mut self, s: &[u8]) -> usize {
+        let (prefix, prefix_len) = left_encode(8 * s.len() as u64);
+        self.update(&prefix[..prefix_len]);
+        self.update(s);
+        prefix_len + s.len()
+    }
+
+    fn right_encode(&mut self, x: u64) {
+        let (encoded, len) = right_encode(x);
+        self.update(&encoded[..len]);
+    }
+
+    /// Absorbs more input
+    pub fn update(&mut self, data: &[u8]) {
+        keccak_absorb(&mut self.state, self.strength.rate(), data, data.len());
+    }
+
+    /// Finishes absorbing and returns a reader of the output stream
+    pub fn finalize_xof(self) -> XofReader {
+        XofReader::new(self.state, self.strength.rate(), self.domain)
+    }
+
+    /// Fills `out` with output
+    pub fn finalize(self, out: &mut [u8]) {
+        self.finalize_xof().squeeze(out);
+    }
+}
+
+/// KMAC128 or KMAC256 (SP 800-185, section 4)
+#[derive(Clone)]
+pub struct Kmac {
+    inner: CShake,
+}
+
+impl Kmac {
+    fn new(strength: Strength, key: &[u8], customization: &[u8]) -> Self {
+        let mut inner = CShake::new(strength, b"KMAC", customization);
+        inner.bytepad(&[key]);
+        Kmac { inner }
+    }
+
+    /// Creates KMAC128 keyed with `key`
+    pub fn v128(key: &[u8], customization: &[u8]) -> Self {
+        Self::new(Strength::S128, key, customization)
+    }
+
+    /// Creates KMAC256 keyed with `key`
+    pub fn v256(key: &[u8], customization: &[u8]) -> Self {
+        Self::new(Strength::S256, key, customization)
+    }
+
+    /// Absorbs more input
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// Fills `out` with a tag bound to its length
+    pub fn finalize(mut self, out: &mut [u8]) {
+        self.inner.right_encode(8 * out.len() as u64);
+        self.inner.finalize(out);
+    }
+
+    /// Returns the KMACXOF output stream
+    pub fn finalize_xof(mut self) -> XofReader {
+        self.inner.right_encode(0);
+        self.inner.finalize_xof()
+    }
+
+    /// Checks `tag` against the expected tag of the same length in constant time.
+    /// Tags shorter than [`KMAC_MIN_TAG_SIZE`], including empty ones, never verify.
+    pub fn verify(mut self, tag: &[u8]) -> bool {
+        if tag.len() < KMAC_MIN_TAG_SIZE {
+            return false;
+        }
+        self.inner.right_encode(8 * tag.len() as u64);
+        let mut reader = self.inner.finalize_xof();
+        let mut expected = [0u8; 64];
+        tag.chunks(expected.len()).fold(0u8, |acc, chunk| {
+            let expected = &mut expected[..chunk.len()];
+            reader.squeeze(expected);
+            expected.iter().zip(chunk).fold(acc, |acc, (a, b)| acc | (a ^ b))
+        }) == 0
+    }
+}
+
+/// TupleHash128 or TupleHash256 (SP 800-185, section 5)
+#[derive(Clone)]
+pub struct TupleHash {
+    inner: CShake,
+}
+
+impl TupleHash {
+    /// Creates TupleHash128
+    pub fn v128(customization: &[u8]) -> Self {
+        TupleHash { inner: CShake::new(Strength::S128, b"TupleHash", customization) }
+    }
+
+    /// Creates TupleHash256
+    pub fn v256(customization: &[u8]) -> Self {
+        TupleHash { inner: CShake::new(Strength::S256, b"TupleHash", customization) }
+    }
+
+    /// Appends one element of the tuple
+    pub fn update(&mut self, element: &[u8]) {
+        self.inner.encode_string(element);
+    }
+
+    /// Fills `out` with a hash bound to its length
+    pub fn finalize(mut self, out: &mut [u8]) {
+        self.inner.right_encode(8 * out.len() as u64);
+        self.inner.finalize(out);
+    }
+
+    /// Returns the TupleHashXOF output stream
+    pub fn finalize_xof(mut self) -> XofReader {
+        self.inner.right_encode(0);
+        self.inner.finalize_xof()
+    }
+}
+
+/// ParallelHash128 or ParallelHash256 (SP 800-185, section 6)
+///
+/// Blocks are hashed one after another as their bytes arrive; the
+/// construction allows parallel evaluation but this implementation does not
+/// rely on it.
+#[derive(Clone)]
+pub struct ParallelHash {
+    inner: CShake,
+    block_size: usize,
+    leaf: CShake,
+    filled: usize,
+    blocks: u64,
+}
+
+impl ParallelHash {
+    fn new(strength: Strength, block_size: usize, customization: &[u8]) -> Self {
+        assert!(block_size > 0, "ParallelHash block size must not be zero");
+        let mut inner = CShake::new(strength, b"ParallelHash", customization);
+        let (encoded, len) = left_encode(block_size as u64);
+        inner.update(&encoded[..len]);
+        let leaf = CShake::new(strength, b"", b"");
+        ParallelHash { inner, block_size, leaf, filled: 0, blocks: 0 }
+    }
+
+    /// Creates ParallelHash128 over blocks of `block_size` bytes
+    pub fn v128(block_size: usize, customization: &[u8]) -> Self {
+        Self::new(Strength::S128, block_size, customization)
+    }
+
+    /// Creates ParallelHash256 over blocks of `block_size` bytes
+    pub fn v256(block_size: usize, customization: &[u8]) -> Self {
+        Self::new(Strength::S256, block_size, customization)
+    }
+
+    fn hash_block(&mut self) {
+        let leaf = core::mem::replace(&mut self.leaf, CShake::new(self.inner.strength, b"", b""));
+        let mut chain = [0u8; 64];
+        let chain = &mut chain[..self.inner.strength.chain_size()];
+        leaf.finalize(chain);
+        self.inner.update(chain);
+        self.filled = 0;
+        self.blocks += 1;
+    }
+
+    /// Absorbs more input
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let take = (self.block_size - self.filled).min(data.len());
+            self.leaf.update(&data[..take]);
+            self.filled += take;
+            data = &data[take..];
+            if self.filled == self.block_size {
+                self.hash_block();
+            }
+        }
+    }
+
+    fn finish(&mut self, output_bits: u64) {
+        if self.filled > 0 {
+            self.hash_block();
+        }
+        self.inner.right_encode(self.blocks);
+        self.inner.right_encode(output_bits);
+    }
+
+    /// Fills `out` with a hash bound to its length
+    pub fn finalize(mut self, out: &mut [u8]) {
+        self.finish(8 * out.len() as u64);
+        self.inner.finalize(out);
+    }
+
+    /// Returns the ParallelHashXOF output stream
+    pub fn finalize_xof(mut self) -> XofReader {
+        self.finish(0);
+        self.inner.finalize_xof()
+    }
+}
+
+/// cSHAKE128 of `input` into `out`
+pub fn cshake128(input: &[u8], name: &[u8], customization: &[u8], out: &mut [u8]) {
+    let mut cshake = CShake::v128(name, customization);
+    cshake.update(input);
+    cshake.finalize(out);
+}
+
+/// cSHAKE256 of `input` into `out`
+pub fn cshake256(input: &[u8], name: &[u8], customization: &[u8], out: &mut [u8]) {
+    let mut cshake = CShake::v256(name, customization);
+    cshake.update(input);
+    cshake.finalize(out);
+}
+
+/// KMAC128 of `input` under `key` into `out`
+pub fn kmac128(key: &[u8], input: &[u8], customization: &[u8], out: &mut [u8]) {
+    let mut kmac = Kmac::v128(key, customization);
+    kmac.update(input);
+    kmac.finalize(out);
+}
+
+/// KMAC256 of `input` under `key` into `out`
+pub fn kmac256(key: &[u8], input: &[u8], customization: &[u8], out: &mut [u8]) {
+    let mut kmac = Kmac::v256(key, customization);
+    kmac.update(input);
+    kmac.finalize(out);
+}
+
+/// KMACXOF128 of `input` under `key` into `out`
+pub fn kmac128_xof(key: &[u8], input: &[u8], customization: &[u8], out: &mut [u8]) {
+    let mut kmac = Kmac::v128(key, customization);
+    kmac.update(input);
+    kmac.finalize_xof().squeeze(out);
+}
+
+/// KMACXOF256 of `input` under `key` into `out`
+pub fn kmac256_xof(key: &[u8], input: &[u8], customization: &[u8], out: &mut [u8]) {
+    let mut kmac = Kmac::v256(key, customization);
+    kmac.update(input);
+    kmac.finalize_xof().squeeze(out);
+}
+
+/// TupleHash128 of `elements` into `out`
+pub fn tuple_hash128(elements: &[&[u8]], customization: &[u8], out: &mut [u8]) {
+    let mut hash = TupleHash::v128(customization);
+    elements.iter().for_each(|element| hash.update(element));
+    hash.finalize(out);
+}
+
+/// TupleHash256 of `elements` into `out`
+pub fn tuple_hash256(elements: &[&[u8]], customization: &[u8], out: &mut [u8]) {
+    let mut hash = TupleHash::v256(customization);
+    elements.iter().for_each(|element| hash.update(element));
+    hash.finalize(out);
+}
+
+/// ParallelHash128 of `input` over blocks of `block_size` bytes into `out`
+pub fn parallel_hash128(input: &[u8], block_size: usize, customization: &[u8], out: &mut [u8]) {
+    let mut hash = ParallelHash::v128(block_size, customization);
+    hash.update(input);
+    hash.finalize(out);
+}
+
+/// ParallelHash256 of `input` over blocks of `block_size` bytes into `out`
+pub fn parallel_hash256(input: &[u8], block_size: usize, customization: &[u8], out: &mut [u8]) {
+    let mut hash = ParallelHash::v256(block_size, customization);
+    hash.update(input);
+    hash.finalize(out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+    use alloc::vec::Vec;
+
+    fn sequence(len: usize) -> Vec<u8> {
+        (0..len).map(|i| i as u8).collect()
+    }
+
+    fn nist_key() -> Vec<u8> {
+        (0x40..0x60).collect()
+    }
+
+    #[test]
+    fn test_encodings() {
+        assert_eq!(left_encode(0), ([1, 0, 0, 0, 0, 0, 0, 0, 0], 2));
+        assert_eq!(left_encode(168).0[..2], [1, 168]);
+        assert_eq!(left_encode(256).0[..3], [2, 1, 0]);
+        assert_eq!(right_encode(0).0[..2], [0, 1]);
+        assert_eq!(right_encode(512).0[..3], [2, 0, 2]);
+        assert_eq!(right_encode(u64::MAX).1, 9);
+    }
+
+    #[test]
+    fn test_cshake_samples() {
+        let mut out = [0u8; 32];
+        cshake128(&sequence(4), b"", b"Email Signature", &mut out);
+        assert_eq!(hex::encode(out), "c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5");
+        cshake128(&sequence(200), b"", b"Email Signature", &mut out);
+        assert_eq!(hex::encode(out), "c5221d50e4f822d96a2e8881a961420f294b7b24fe3d2094baed2c6524cc166b");
+
+        let mut out = [0u8; 64];
+        cshake256(&sequence(4), b"", b"Email Signature", &mut out);
+        assert_eq!(
+            hex::encode(out),
+            "d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd1\
+             64020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c"
+        );
+
+        // Without a name and customization string cSHAKE is SHAKE
+        let mut shake = [0u8; 64];
+        crate::fips202::shake256(&mut shake, 64, b"abc", 3);
+        cshake256(b"abc", b"", b"", &mut out);
+        assert_eq!(out, shake);
+    }
+
+    #[test]
+    fn test_kmac_samples() {
+        let key = nist_key();
+        let mut out = [0u8; 32];
+        kmac128(&key, &sequence(4), b"", &mut out);
+        assert_eq!(hex::encode(out), "e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e");
+        kmac128(&key, &sequence(200), b"My Tagged Application", &mut out);
+        assert_eq!(hex::encode(out), "1f5b4e6cca02209e0dcb5ca635b89a15e271ecc760071dfd805faa38f9729230");
+        kmac128_xof(&key, &sequence(4), b"", &mut out);
+        assert_eq!(hex::encode(out), "cd83740bbd92ccc8cf032b1481a0f4460e7ca9dd12b08a0c4031178bacd6ec35");
+
+        let mut out = [0u8; 64];
+        kmac256(&key, &sequence(4), b"My Tagged Application", &mut out);
+        assert_eq!(
+            hex::encode(out),
+            "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7\
+             f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd"
+        );
+        kmac256_xof(&key, &sequence(200), b"My Tagged Application", &mut out);
+        assert_eq!(
+            hex::encode(out),
+            "d5be731c954ed7732846bb59dbe3a8e30f83e77a4bff4459f2f1c2b4ecebb8ce\
+             67ba01c62e8ab8578d2d499bd1bb276768781190020a306a97de281dcc30305d"
+        );
+
+        // Long XOF output read in pieces
+        let mut kmac = Kmac::v256(&key, b"x");
+        kmac.update(&sequence(200));
+        let mut reader = kmac.finalize_xof();
+        let mut long = [0u8; 400];
+        for chunk in long.chunks_mut(150) {
+            reader.squeeze(chunk);
+        }
+        assert_eq!(hex::encode(&long[384..]), "90c0111649ca99fcf7cd6d54ba076034");
+
+        let mut tag = [0u8; 32];
+        kmac128(&key, b"confirm", b"VAZ256", &mut tag);
+        let mut kmac = Kmac::v128(&key, b"VAZ256");
+        kmac.update(b"confirm");
+        assert!(kmac.clone().verify(&tag));
+        tag[31] ^= 1;
+        assert!(!kmac.clone().verify(&tag));
+
+        // Truncated tags are only accepted down to 32 bits
+        let mut short = [0u8; KMAC_MIN_TAG_SIZE];
+        kmac128(&key, b"confirm", b"VAZ256", &mut short);
+        assert!(kmac.clone().verify(&short));
+        let mut shorter = [0u8; KMAC_MIN_TAG_SIZE - 1];
+        kmac128(&key, b"confirm", b"VAZ256", &mut shorter);
+        assert!(!kmac.clone().verify(&shorter));
+        assert!(!kmac.verify(&[]));
+    }
+
+    #[test]
+    fn test_tuple_hash_samples() {
+        let third: Vec<u8> = (0x20..0x29).collect();
+        let elements: [&[u8]; 3] = [&[0, 1, 2], &[0x10, 0x11, 0x12, 0x13, 0x14, 0x15], &third];
+        let mut out = [0u8; 32];
+        tuple_hash128(&elements[..2], b"", &mut out);
+        assert_eq!(hex::encode(out), "c5d8786c1afb9b82111ab34b65b2c0048fa64e6d48e263264ce1707d3ffc8ed1");
+        tuple_hash128(&elements[..2], b"My Tuple App", &mut out);
+        assert_eq!(hex::encode(out), "75cdb20ff4db1154e841d758e24160c54bae86eb8c13e7f5f40eb35588e96dfb");
+
+        tuple_hash128(&elements, b"My Tuple App", &mut out);
+        assert_eq!(hex::encode(out), "e60f202c89a2631eda8d4c588ca5fd07f39e5151998deccf973adb3804bb6e84");
+        let mut hash = TupleHash::v128(b"");
+        elements[..2].iter().for_each(|element| hash.update(element));
+        hash.finalize_xof().squeeze(&mut out);
+        assert_eq!(hex::encode(out), "2f103cd7c32320353495c68de1a8129245c6325f6f2a3d608d92179c96e68488");
+
+        let mut out = [0u8; 64];
+        tuple_hash256(&elements, b"My Tuple App", &mut out);
+        assert_eq!(
+            hex::encode(out),
+            "45000be63f9b6bfd89f54717670f69a9bc763591a4f05c50d68891a744bcc6e7\
+             d6d5b5e82c018da999ed35b0bb49c9678e526abd8e85c13ed254021db9e790ce"
+        );
+    }
+
+    #[test]
+    fn test_parallel_hash_samples() {
+        let input: Vec<u8> = [0x00u8, 0x10, 0x20].iter().flat_map(|&base| base..base + 8).collect();
+        let mut out = [0u8; 32];
+        parallel_hash128(&input, 8, b"", &mut out);
+        assert_eq!(hex::encode(out), "ba8dc1d1d979331d3f813603c67f72609ab5e44b94a0b8f9af46514454a2b4f5");
+        parallel_hash128(&input, 8, b"Parallel Data", &mut out);
+        assert_eq!(hex::encode(out), "fc484dcb3f84dceedc353438151bee58157d6efed0445a81f165e495795b7206");
+
+        // Feeding the input in pieces that straddle blocks gives the same hash
+        let mut hash = ParallelHash::v128(8, b"Parallel Data");
+        for piece in input.chunks(5) {
+            hash.update(piece);
+        }
+        let mut pieces = [0u8; 32];
+        hash.clone().finalize(&mut pieces);
+        assert_eq!(pieces, out);
+        hash.finalize_xof().squeeze(&mut pieces);
+        assert_eq!(hex::encode(pieces), "ea2a793140820f7a128b8eb70a9439f93257c6e6e79b4a540d291d6dae7098d7");
+
+        let mut out = [0u8; 64];
+        parallel_hash256(&input, 8, b"Parallel Data", &mut out);
+        assert_eq!(
+            hex::encode(out),
+            "cdf15289b54f6212b4bc270528b49526006dd9b54e2b6add1ef6900dda3963bb\
+             33a72491f236969ca8afaea29c682d47a393c065b38e29fae651a2091c833110"
+        );
+    }
+}
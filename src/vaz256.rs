@@ -17,10 +17,17 @@ use crate::dilithium5::{
     Dilithium5Signature,
     Dilithium5Keypair,
 };
+use crate::entropy::EntropySource;
+#[cfg(feature = "std")]
+use crate::entropy::OsEntropy;
 use crate::fips202::{shake256};
+use crate::params_dilithium5::CRHBYTES;
+use crate::self_test;
 use crate::zeroize::Zeroize;
-use rand::{RngCore, rngs::OsRng};
+#[cfg(feature = "alloc")]
 use crate::hex;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 
 /// Constants defining the sizes of various components
 pub const SECRET_KEY_SIZE: usize = 32;
@@ -29,8 +36,11 @@ pub const DILITHIUM5_SIGNATURE_SIZE: usize = 4595;
 pub const DILITHIUM5_PUBLIC_KEY_SIZE: usize = 2592;
 pub const SIGNATURE_SIZE: usize = DILITHIUM5_SIGNATURE_SIZE + DILITHIUM5_PUBLIC_KEY_SIZE;
 
+/// Message signed by the pairwise consistency test of key generation
+pub(crate) const PAIRWISE_TEST_MESSAGE: &[u8] = b"VAZ256 pairwise consistency test";
+
 /// Possible errors that can occur during VAZ256 operations
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum VAZ256Error {
     KeyGenerationFailed,
     SigningFailed,
@@ -39,6 +49,12 @@ pub enum VAZ256Error {
     DeserializationError,
     InvalidLength,
     HexDecodingError,
+    ChainValidationFailed,
+    ClaimsValidationFailed,
+    DecryptionFailed,
+    EntropyUnavailable,
+    SelfTestFailed,
+    OutOfMemory,
 }
 
 pub type VAZ256Result<T> = Result<T, VAZ256Error>;
@@ -59,6 +75,10 @@ pub struct PublicKey {
     key: [u8; PUBLIC_KEY_SIZE],
 }
 
+/// Full Dilithium5 public key from which the compact public key is derived
+#[derive(Clone, Debug, PartialEq)]
+pub struct FullPublicKey([u8; DILITHIUM5_PUBLIC_KEY_SIZE]);
+
 /// Complete signature containing both Dilithium signature and public key
 pub struct Signature {
     dilithium_signature: Dilithium5Signature,
@@ -67,21 +87,40 @@ pub struct Signature {
 
 impl SecretKey {
     /// Creates a new SecretKey from raw bytes
-    fn new(secret: [u8; SECRET_KEY_SIZE]) -> Self {
+    pub(crate) fn new(secret: [u8; SECRET_KEY_SIZE]) -> Self {
         Self(secret)
     }
 
     /// Returns a reference to the underlying bytes
-    fn as_bytes(&self) -> &[u8; SECRET_KEY_SIZE] {
+    pub(crate) fn as_bytes(&self) -> &[u8; SECRET_KEY_SIZE] {
         &self.0
     }
 
+    /// Creates a SecretKey from raw bytes, such as 32 bytes of caller-supplied entropy
+    pub fn from_bytes(bytes: &[u8]) -> VAZ256Result<Self> {
+        let secret = bytes.try_into().map_err(|_| VAZ256Error::InvalidLength)?;
+        Ok(Self::new(secret))
+    }
+
+    /// Derives the compact public key belonging to this secret key
+    pub fn public_key(&self) -> PublicKey {
+        self.full_public_key().public_key()
+    }
+
+    /// Derives the full Dilithium5 public key belonging to this secret key
+    pub fn full_public_key(&self) -> FullPublicKey {
+        let keypair = Dilithium5Keypair::generate(self.as_bytes());
+        FullPublicKey(keypair.public.to_bytes())
+    }
+
     /// Converts the secret key to a hexadecimal string
+    #[cfg(feature = "alloc")]
     pub fn to_hex(&self) -> String {
         hex::encode(self.0)
     }
 
     /// Creates a SecretKey from a hexadecimal string
+    #[cfg(feature = "alloc")]
     pub fn from_hex(hex_str: &str) -> VAZ256Result<Self> {
         let decoded = hex::decode(hex_str)
             .map_err(|_| VAZ256Error::HexDecodingError)?;
@@ -97,12 +136,25 @@ impl SecretKey {
 }
 
 impl PublicKey {
+    /// Returns a reference to the underlying bytes
+    pub fn as_bytes(&self) -> &[u8; PUBLIC_KEY_SIZE] {
+        &self.key
+    }
+
+    /// Creates a PublicKey from raw bytes
+    pub fn from_bytes(bytes: &[u8]) -> VAZ256Result<Self> {
+        let key = bytes.try_into().map_err(|_| VAZ256Error::InvalidLength)?;
+        Ok(Self { key })
+    }
+
     /// Converts the public key to a hexadecimal string
+    #[cfg(feature = "alloc")]
     pub fn to_hex(&self) -> String {
         hex::encode(self.key)
     }
 
     /// Creates a PublicKey from a hexadecimal string
+    #[cfg(feature = "alloc")]
     pub fn from_hex(hex_str: &str) -> VAZ256Result<Self> {
         let decoded = hex::decode(hex_str)
             .map_err(|_| VAZ256Error::HexDecodingError)?;
@@ -117,26 +169,66 @@ impl PublicKey {
     }
 }
 
+impl FullPublicKey {
+    /// Wraps Dilithium5 public key bytes
+    pub(crate) fn new(bytes: [u8; DILITHIUM5_PUBLIC_KEY_SIZE]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns a reference to the underlying bytes
+    pub fn as_bytes(&self) -> &[u8; DILITHIUM5_PUBLIC_KEY_SIZE] {
+        &self.0
+    }
+
+    /// Creates a FullPublicKey from raw bytes
+    pub fn from_bytes(bytes: &[u8]) -> VAZ256Result<Self> {
+        let key = bytes.try_into().map_err(|_| VAZ256Error::InvalidLength)?;
+        Ok(Self(key))
+    }
+
+    /// Hashes the Dilithium5 public key into the compact public key
+    pub fn public_key(&self) -> PublicKey {
+        let mut key = [0u8; PUBLIC_KEY_SIZE];
+        shake256(&mut key, PUBLIC_KEY_SIZE, &self.0, DILITHIUM5_PUBLIC_KEY_SIZE);
+        PublicKey { key }
+    }
+}
+
 /// Generates a new keypair using system randomness
+#[cfg(feature = "std")]
 pub fn keygen() -> VAZ256Result<(SecretKey, PublicKey)> {
+    keygen_with(&mut OsEntropy)
+}
+
+/// Generates a new keypair from the given entropy source.
+/// A key that fails to verify its own signature is reported as [`VAZ256Error::KeyGenerationFailed`].
+pub fn keygen_with(entropy: &mut impl EntropySource) -> VAZ256Result<(SecretKey, PublicKey)> {
+    self_test::check()?;
     let mut secret = [0u8; SECRET_KEY_SIZE];
-    OsRng.fill_bytes(&mut secret);
+    entropy.fill_bytes(&mut secret)?;
     
-    let keypair = Dilithium5Keypair::generate(Some(&secret));
+    let secret = SecretKey::new(secret);
+    let keypair = Dilithium5Keypair::generate(secret.as_bytes());
     // Hash the Dilithium public key to create the compact public key
-    let public_bytes = keypair.public.to_bytes();
-    let mut key = [0u8; PUBLIC_KEY_SIZE];
-    shake256(&mut key, PUBLIC_KEY_SIZE, &public_bytes, public_bytes.len());
+    let public = FullPublicKey(keypair.public.to_bytes()).public_key();
+    pairwise_consistency_test(keypair, &public)?;
     
-    Ok((
-        SecretKey::new(secret),
-        PublicKey { key }
-    ))
+    Ok((secret, public))
+}
+
+/// Signs and verifies a fixed message with a new key before it is returned
+fn pairwise_consistency_test(keypair: Dilithium5Keypair, public: &PublicKey) -> VAZ256Result<()> {
+    let signature = Signature {
+        dilithium_signature: keypair.sign(PAIRWISE_TEST_MESSAGE),
+        dilithium_public_key: keypair.public,
+    };
+    verify(PAIRWISE_TEST_MESSAGE, &signature, public).map_err(|_| VAZ256Error::KeyGenerationFailed)
 }
 
 /// Signs a message using the secret key
 pub fn sign(message: &[u8], vaz256_sk: &SecretKey) -> VAZ256Result<Signature> {
-    let keypair = Dilithium5Keypair::generate(Some(vaz256_sk.as_bytes()));
+    self_test::check()?;
+    let keypair = Dilithium5Keypair::generate(vaz256_sk.as_bytes());
     
     let dilithium_signature = keypair.sign(message);
     
@@ -146,8 +238,31 @@ pub fn sign(message: &[u8], vaz256_sk: &SecretKey) -> VAZ256Result<Signature> {
     })
 }
 
+/// Signs a message with fresh randomness from `entropy` used as the signing seed
+/// `rhoprime` in place of the one derived from the key and message. The result
+/// differs on every call but verifies like a deterministic signature.
+pub fn sign_randomized(
+    message: &[u8],
+    vaz256_sk: &SecretKey,
+    entropy: &mut impl EntropySource,
+) -> VAZ256Result<Signature> {
+    self_test::check()?;
+    let mut rnd = [0u8; CRHBYTES];
+    entropy.fill_bytes(&mut rnd)?;
+    let keypair = Dilithium5Keypair::generate(vaz256_sk.as_bytes());
+
+    let dilithium_signature = keypair.secret.sign_randomized(message, &rnd);
+    rnd.zeroize();
+
+    Ok(Signature {
+        dilithium_signature,
+        dilithium_public_key: keypair.public,
+    })
+}
+
 /// Verifies a signature against a message and public key
 pub fn verify(message: &[u8], signature: &Signature, public_key: &PublicKey) -> VAZ256Result<()> {
+    self_test::check()?;
     // Verify that the signature's public key matches the expected public key hash
     let pk_bytes = signature.dilithium_public_key.to_bytes();
     let mut pk_hash = [0u8; PUBLIC_KEY_SIZE];
@@ -165,18 +280,42 @@ pub fn verify(message: &[u8], signature: &Signature, public_key: &PublicKey) ->
 }
 
 impl Signature {
+    /// Creates an all-zero signature, to be filled in through [`Signature::parts_mut`]
+    pub(crate) fn zeroed() -> Self {
+        Self {
+            dilithium_signature: [0u8; DILITHIUM5_SIGNATURE_SIZE],
+            dilithium_public_key: Dilithium5PublicKey { bytes: [0u8; DILITHIUM5_PUBLIC_KEY_SIZE] },
+        }
+    }
+
+    /// Returns the Dilithium5 signature and public key bytes without copying them
+    pub(crate) fn parts(&self) -> (&Dilithium5Signature, &[u8; DILITHIUM5_PUBLIC_KEY_SIZE]) {
+        (&self.dilithium_signature, &self.dilithium_public_key.bytes)
+    }
+
+    /// Mutable access to the Dilithium5 signature and public key bytes, for filling them in place
+    pub(crate) fn parts_mut(&mut self) -> (&mut Dilithium5Signature, &mut [u8; DILITHIUM5_PUBLIC_KEY_SIZE]) {
+        (&mut self.dilithium_signature, &mut self.dilithium_public_key.bytes)
+    }
+
     /// Converts the signature to raw bytes
-    pub fn to_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(SIGNATURE_SIZE);
-        bytes.extend_from_slice(&self.dilithium_signature);
-        bytes.extend_from_slice(&self.dilithium_public_key.to_bytes());
+    pub fn to_bytes(&self) -> [u8; SIGNATURE_SIZE] {
+        let mut bytes = [0u8; SIGNATURE_SIZE];
+        bytes[..DILITHIUM5_SIGNATURE_SIZE].copy_from_slice(&self.dilithium_signature);
+        bytes[DILITHIUM5_SIGNATURE_SIZE..].copy_from_slice(&self.dilithium_public_key.bytes);
         bytes
     }
     
     /// Converts the signature to a hexadecimal string
+    #[cfg(feature = "alloc")]
     pub fn to_hex(&self) -> String {
         hex::encode(self.to_bytes())
     }
+
+    /// Returns the full Dilithium5 public key embedded in the signature
+    pub fn full_public_key(&self) -> FullPublicKey {
+        FullPublicKey(self.dilithium_public_key.to_bytes())
+    }
     
     /// Creates a Signature from raw bytes
     pub fn from_bytes(bytes: &[u8]) -> VAZ256Result<Self> {
@@ -198,6 +337,7 @@ impl Signature {
     }
     
     /// Creates a Signature from a hexadecimal string
+    #[cfg(feature = "alloc")]
     pub fn from_hex(hex_str: &str) -> VAZ256Result<Self> {
         let decoded = hex::decode(hex_str)
             .map_err(|_| VAZ256Error::HexDecodingError)?;
@@ -209,8 +349,10 @@ impl Signature {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::entropy::TestEntropy;
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_keygen_sign_verify() {
         let (sk, pk) = keygen().unwrap();
         let message = b"test message";
@@ -220,6 +362,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_hex_conversion() {
         let (sk, pk) = keygen().unwrap();
         let message = b"test message";
@@ -242,6 +385,60 @@ mod tests {
     }
 
     #[test]
+    fn test_caller_supplied_entropy() {
+        let entropy = [7u8; SECRET_KEY_SIZE];
+        let sk = SecretKey::from_bytes(&entropy).unwrap();
+        #[cfg(feature = "alloc")]
+        assert_eq!(sk.public_key(), SecretKey::from_hex(&"07".repeat(SECRET_KEY_SIZE)).unwrap().public_key());
+        assert_eq!(SecretKey::from_bytes(&entropy[1..]).err(), Some(VAZ256Error::InvalidLength));
+
+        let bytes: [u8; SIGNATURE_SIZE] = sign(b"bootloader", &sk).unwrap().to_bytes();
+        let signature = Signature::from_bytes(&bytes).unwrap();
+        assert!(verify(b"bootloader", &signature, &sk.public_key()).is_ok());
+    }
+
+    #[test]
+    fn test_entropy_sources() {
+        let (sk, pk) = keygen_with(&mut TestEntropy::new(b"seed")).unwrap();
+        let (sk2, pk2) = keygen_with(&mut TestEntropy::new(b"seed")).unwrap();
+        assert_eq!(sk.as_bytes(), sk2.as_bytes());
+        assert_eq!(pk, pk2);
+
+        let mut entropy = TestEntropy::new(b"nonce");
+        let first = sign_randomized(b"message", &sk, &mut entropy).unwrap();
+        let second = sign_randomized(b"message", &sk, &mut entropy).unwrap();
+        assert_ne!(first.to_bytes(), second.to_bytes());
+        assert_ne!(first.to_bytes(), sign(b"message", &sk).unwrap().to_bytes());
+        assert!(verify(b"message", &first, &pk).is_ok());
+        assert!(verify(b"message", &second, &pk).is_ok());
+        assert_eq!(
+            sign_randomized(b"message", &sk, &mut TestEntropy::new(b"nonce")).unwrap().to_bytes(),
+            first.to_bytes()
+        );
+
+        struct Failing;
+        impl EntropySource for Failing {
+            fn fill_bytes(&mut self, _: &mut [u8]) -> VAZ256Result<()> {
+                Err(VAZ256Error::EntropyUnavailable)
+            }
+        }
+        assert_eq!(keygen_with(&mut Failing).err(), Some(VAZ256Error::EntropyUnavailable));
+        assert_eq!(sign_randomized(b"message", &sk, &mut Failing).err(), Some(VAZ256Error::EntropyUnavailable));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_pairwise_consistency() {
+        let (_, public) = keygen().unwrap();
+        let (other, _) = keygen().unwrap();
+        let keypair = Dilithium5Keypair::generate(other.as_bytes());
+        assert_eq!(pairwise_consistency_test(keypair, &public), Err(VAZ256Error::KeyGenerationFailed));
+        let keypair = Dilithium5Keypair::generate(other.as_bytes());
+        assert_eq!(pairwise_consistency_test(keypair, &other.public_key()), Ok(()));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
     fn test_wrong_message() {
         let (sk, pk) = keygen().unwrap();
         
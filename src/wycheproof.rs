@@ -0,0 +1,396 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Wycheproof-style verification test vectors.
+//!
+//! `kat/vaz256_verify_test.json` follows the layout of Project Wycheproof's
+//! `*_verify_test.json` files. Test groups hold a compact `publicKey`, and
+//! each test holds a `msg` and a raw `sig` in hex. The `result` is `valid`
+//! or `invalid`, and the `error` is the name of the [`VAZ256Error`] that
+//! [`Signature::from_bytes`] followed by [`verify`] must return. The `flags`
+//! name the malformation, and the top-level `notes` describe each flag.
+//!
+//! Every invalid vector breaks exactly one thing in an otherwise valid
+//! signature. Verifiers that report a single failure can map `error` to
+//! "reject".
+//!
+//! This module is only public with the `test-vectors` feature.
+
+use alloc::{format, string::{String, ToString}, vec, vec::Vec};
+
+use crate::entropy::TestEntropy;
+use crate::json::Value;
+use crate::params_dilithium5::{BETA, GAMMA1, K, L, OMEGA, POLYZ_PACKEDBYTES, SEEDBYTES, SIGNBYTES};
+use crate::poly_dilithium5::{z_pack, z_unpack, Poly};
+use crate::{
+    hex, keygen_with, sign, verify, PublicKey, SecretKey, Signature, VAZ256Error, VAZ256Result, SIGNATURE_SIZE,
+};
+
+/// Offset of the hint encoding in the Dilithium5 signature
+const HINTS: usize = SEEDBYTES + L * POLYZ_PACKEDBYTES;
+/// Size of the huge messages
+const HUGE_MESSAGE: usize = 1 << 16;
+
+const NOTES: [(&str, &str); 17] = [
+    ("Valid", "A valid signature"),
+    ("EmptyMessage", "The message is empty"),
+    ("HugeMessage", "The message is 64 KiB long"),
+    ("ModifiedMessage", "The message differs from the signed one"),
+    ("ModifiedZ", "A coefficient of z is changed but stays within the bound gamma1 - beta"),
+    ("OutOfRangeZ", "A coefficient of z is at least gamma1 - beta in absolute value"),
+    ("TooManyHints", "The hint counts claim more than omega hints"),
+    ("DecreasingHintCounts", "A cumulative hint count is smaller than the one before it"),
+    ("UnsortedHints", "The hint indices of a polynomial are not increasing"),
+    ("DuplicateHint", "A hint index of a polynomial is repeated"),
+    ("NonzeroHintPadding", "An unused hint index byte is not zero"),
+    ("TruncatedSignature", "The signature is shorter than 7187 bytes"),
+    ("ExtendedSignature", "The signature is longer than 7187 bytes"),
+    ("WrongEmbeddedKey", "The embedded Dilithium5 public key does not hash to the public key"),
+    ("WrongKeySignature", "The Dilithium5 signature was made with a different key"),
+    ("SwappedChallenge", "The challenge seed c is taken from the signature of another message"),
+    ("ZeroChallenge", "The challenge seed c is all zero"),
+];
+
+/// One verification test
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestVector {
+    pub tc_id: usize,
+    pub comment: String,
+    pub flags: Vec<String>,
+    pub public_key: PublicKey,
+    pub msg: Vec<u8>,
+    pub sig: Vec<u8>,
+    /// Error `verify` must fail with, or `None` for valid signatures
+    pub expected: Option<VAZ256Error>,
+}
+
+impl TestVector {
+    /// Decodes and verifies the signature
+    pub fn run(&self) -> VAZ256Result<()> {
+        let signature = Signature::from_bytes(&self.sig)?;
+        verify(&self.msg, &signature, &self.public_key)
+    }
+
+    /// Whether [`TestVector::run`] gives the expected result
+    pub fn passes(&self) -> bool {
+        self.run().err() == self.expected
+    }
+}
+
+/// Name of an error in the vectors, its `Debug` name. The match is exhaustive so that a new
+/// variant must be named here, and in [`error_from_name`], before the crate builds.
+fn error_name(error: VAZ256Error) -> &'static str {
+    match error {
+        VAZ256Error::KeyGenerationFailed => "KeyGenerationFailed",
+        VAZ256Error::SigningFailed => "SigningFailed",
+        VAZ256Error::VerificationFailed => "VerificationFailed",
+        VAZ256Error::PublicKeyMismatch => "PublicKeyMismatch",
+        VAZ256Error::DeserializationError => "DeserializationError",
+        VAZ256Error::InvalidLength => "InvalidLength",
+        VAZ256Error::HexDecodingError => "HexDecodingError",
+        VAZ256Error::ChainValidationFailed => "ChainValidationFailed",
+        VAZ256Error::ClaimsValidationFailed => "ClaimsValidationFailed",
+        VAZ256Error::DecryptionFailed => "DecryptionFailed",
+        VAZ256Error::EntropyUnavailable => "EntropyUnavailable",
+        VAZ256Error::SelfTestFailed => "SelfTestFailed",
+        VAZ256Error::OutOfMemory => "OutOfMemory",
+    }
+}
+
+fn error_from_name(name: &str) -> Option<VAZ256Error> {
+    let error = match name {
+        "KeyGenerationFailed" => VAZ256Error::KeyGenerationFailed,
+        "SigningFailed" => VAZ256Error::SigningFailed,
+        "VerificationFailed" => VAZ256Error::VerificationFailed,
+        "PublicKeyMismatch" => VAZ256Error::PublicKeyMismatch,
+        "DeserializationError" => VAZ256Error::DeserializationError,
+        "InvalidLength" => VAZ256Error::InvalidLength,
+        "HexDecodingError" => VAZ256Error::HexDecodingError,
+        "ChainValidationFailed" => VAZ256Error::ChainValidationFailed,
+        "ClaimsValidationFailed" => VAZ256Error::ClaimsValidationFailed,
+        "DecryptionFailed" => VAZ256Error::DecryptionFailed,
+        "EntropyUnavailable" => VAZ256Error::EntropyUnavailable,
+        "SelfTestFailed" => VAZ256Error::SelfTestFailed,
+        "OutOfMemory" => VAZ256Error::OutOfMemory,
+        _ => return None,
+    };
+    Some(error)
+}
+
+fn hex_field(value: &Value, name: &str) -> VAZ256Result<Vec<u8>> {
+    let text = value.get(name).and_then(Value::as_str).ok_or(VAZ256Error::DeserializationError)?;
+    hex::decode(text).map_err(|_| VAZ256Error::HexDecodingError)
+}
+
+/// Parses a test vector file
+pub fn parse(text: &str) -> VAZ256Result<Vec<TestVector>> {
+    let root = Value::parse(text)?;
+    let groups = root.get("testGroups").and_then(Value::as_array).ok_or(VAZ256Error::DeserializationError)?;
+    let mut vectors = Vec::new();
+    for group in groups {
+        let public_key = PublicKey::from_bytes(&hex_field(group, "publicKey")?)?;
+        let tests = group.get("tests").and_then(Value::as_array).ok_or(VAZ256Error::DeserializationError)?;
+        for test in tests {
+            let field = |name| test.get(name).ok_or(VAZ256Error::DeserializationError);
+            let tc_id = field("tcId")?.as_i64().ok_or(VAZ256Error::DeserializationError)? as usize;
+            let comment = field("comment")?.as_str().ok_or(VAZ256Error::DeserializationError)?.to_string();
+            let flags = field("flags")?
+                .as_array()
+                .ok_or(VAZ256Error::DeserializationError)?
+                .iter()
+                .map(|flag| flag.as_str().map(str::to_string).ok_or(VAZ256Error::DeserializationError))
+                .collect::<VAZ256Result<_>>()?;
+            let expected = match (field("result")?.as_str(), field("error")?) {
+                (Some("valid"), Value::Null) => None,
+                (Some("invalid"), Value::String(name)) => {
+                    Some(error_from_name(name).ok_or(VAZ256Error::DeserializationError)?)
+                }
+                _ => return Err(VAZ256Error::DeserializationError),
+            };
+            let (msg, sig) = (hex_field(test, "msg")?, hex_field(test, "sig")?);
+            vectors.push(TestVector { tc_id, comment, flags, public_key: public_key.clone(), msg, sig, expected });
+        }
+    }
+    Ok(vectors)
+}
+
+/// Builder of the vectors of one group
+struct Corpus {
+    public_key: PublicKey,
+    tests: Vec<Value>,
+}
+
+impl Corpus {
+    fn add(&mut self, comment: &str, flag: &str, msg: &[u8], sig: &[u8], expected: Option<VAZ256Error>) {
+        let (result, error) = match expected {
+            None => ("valid", Value::Null),
+            Some(error) => ("invalid", Value::from(error_name(error))),
+        };
+        self.tests.push(Value::Object(vec![
+            ("tcId".to_string(), Value::from(self.tests.len() + 1)),
+            ("comment".to_string(), Value::from(comment)),
+            ("flags".to_string(), Value::Array(vec![Value::from(flag)])),
+            ("msg".to_string(), Value::from(hex::encode(msg))),
+            ("sig".to_string(), Value::from(hex::encode(sig))),
+            ("result".to_string(), Value::from(result)),
+            ("error".to_string(), error),
+        ]));
+    }
+
+    /// Adds `sig` with its Dilithium5 signature changed by `modify`
+    fn add_modified(&mut self, comment: &str, flag: &str, msg: &[u8], sig: &[u8], modify: impl FnOnce(&mut [u8])) {
+        let mut modified = sig.to_vec();
+        modify(&mut modified[..SIGNBYTES]);
+        self.add(comment, flag, msg, &modified, Some(VAZ256Error::VerificationFailed));
+    }
+}
+
+/// Sets the first coefficient of z
+fn set_z(sig: &mut [u8], value: i32) {
+    let mut z = Poly::default();
+    z_unpack(&mut z, &sig[SEEDBYTES..]);
+    z.coeffs[0] = value;
+    z_pack(&mut sig[SEEDBYTES..], &z);
+}
+
+/// Cumulative hint counts of a Dilithium5 signature
+fn hint_counts(sig: &[u8]) -> [usize; K] {
+    core::array::from_fn(|i| sig[HINTS + OMEGA + i] as usize)
+}
+
+/// Signs numbered messages until one has a polynomial with two hints and unused hint slots
+fn sign_with_hints(secret: &SecretKey) -> (Vec<u8>, [u8; SIGNATURE_SIZE], usize) {
+    (0..)
+        .find_map(|i| {
+            let msg = format!("hint message {}", i).into_bytes();
+            let sig = sign(&msg, secret).unwrap().to_bytes();
+            let counts = hint_counts(&sig);
+            let total = counts[K - 1];
+            let starts = (0..K).map(|i| if i == 0 { 0 } else { counts[i - 1] });
+            let start = starts.zip(counts).position(|(a, b)| b - a >= 2);
+            match start {
+                Some(poly) if total < OMEGA => Some((msg, sig, if poly == 0 { 0 } else { counts[poly - 1] })),
+                _ => None,
+            }
+        })
+        .unwrap()
+}
+
+/// Generates the test vector file
+pub fn corpus() -> String {
+    let (secret, public_key) = keygen_with(&mut TestEntropy::new(b"wycheproof key")).unwrap();
+    let (other, other_public) = keygen_with(&mut TestEntropy::new(b"wycheproof other key")).unwrap();
+    let mut corpus = Corpus { public_key: public_key.clone(), tests: Vec::new() };
+    let msg = b"Wycheproof VAZ256".to_vec();
+    let sig = sign(&msg, &secret).unwrap().to_bytes();
+    let modify = |corpus: &mut Corpus, comment: &str, flag: &str, change: &dyn Fn(&mut [u8])| {
+        corpus.add_modified(comment, flag, &msg, &sig, change)
+    };
+
+    corpus.add("valid signature", "Valid", &msg, &sig, None);
+    let empty_sig = sign(b"", &secret).unwrap().to_bytes();
+    corpus.add("valid signature of an empty message", "EmptyMessage", b"", &empty_sig, None);
+    corpus.add("signature of a message checked against the empty message", "EmptyMessage", b"", &sig,
+        Some(VAZ256Error::VerificationFailed));
+    let huge: Vec<u8> = (0..HUGE_MESSAGE).map(|i| (i % 251) as u8).collect();
+    let huge_sig = sign(&huge, &secret).unwrap().to_bytes();
+    corpus.add("valid signature of a 64 KiB message", "HugeMessage", &huge, &huge_sig, None);
+    let mut huge_modified = huge.clone();
+    huge_modified[HUGE_MESSAGE - 1] ^= 1;
+    corpus.add("last byte of a 64 KiB message changed", "HugeMessage", &huge_modified, &huge_sig,
+        Some(VAZ256Error::VerificationFailed));
+    let mut modified_msg = msg.clone();
+    modified_msg[0] ^= 0x80;
+    corpus.add("first bit of the message flipped", "ModifiedMessage", &modified_msg, &sig,
+        Some(VAZ256Error::VerificationFailed));
+
+    let bound = (GAMMA1 - BETA) as i32;
+    modify(&mut corpus, "z coefficient set to gamma1 - beta - 1", "ModifiedZ", &|s| set_z(s, bound - 1));
+    modify(&mut corpus, "z coefficient set to gamma1 - beta", "OutOfRangeZ", &|s| set_z(s, bound));
+    modify(&mut corpus, "z coefficient set to -(gamma1 - beta)", "OutOfRangeZ", &|s| set_z(s, -bound));
+    modify(&mut corpus, "z coefficient set to gamma1", "OutOfRangeZ", &|s| set_z(s, GAMMA1 as i32));
+    modify(&mut corpus, "z coefficient set to -gamma1 + 1", "OutOfRangeZ", &|s| set_z(s, 1 - GAMMA1 as i32));
+
+    let (hint_msg, hint_sig, first) = sign_with_hints(&secret);
+    let counts = hint_counts(&hint_sig);
+    let hint = |corpus: &mut Corpus, comment: &str, flag: &str, change: &dyn Fn(&mut [u8])| {
+        corpus.add_modified(comment, flag, &hint_msg, &hint_sig, change)
+    };
+    hint(&mut corpus, "last hint count is omega + 1", "TooManyHints", &|s| s[HINTS + OMEGA + K - 1] = OMEGA as u8 + 1);
+    hint(&mut corpus, "last hint count is 255", "TooManyHints", &|s| s[HINTS + OMEGA + K - 1] = 0xff);
+    hint(&mut corpus, "first hint count above the last", "DecreasingHintCounts", &|s| {
+        s[HINTS + OMEGA] = counts[K - 1] as u8 + 1
+    });
+    hint(&mut corpus, "two hint indices of a polynomial swapped", "UnsortedHints", &|s| {
+        s.swap(HINTS + first, HINTS + first + 1)
+    });
+    hint(&mut corpus, "hint index repeated", "DuplicateHint", &|s| s[HINTS + first + 1] = s[HINTS + first]);
+    hint(&mut corpus, "first unused hint index is 1", "NonzeroHintPadding", &|s| s[HINTS + counts[K - 1]] = 1);
+    hint(&mut corpus, "last unused hint index is 255", "NonzeroHintPadding", &|s| s[HINTS + OMEGA - 1] = 0xff);
+
+    let resized: [(&str, &str, Vec<u8>); 5] = [
+        ("empty signature", "TruncatedSignature", Vec::new()),
+        ("last byte removed", "TruncatedSignature", sig[..SIGNATURE_SIZE - 1].to_vec()),
+        ("Dilithium5 signature without the public key", "TruncatedSignature", sig[..SIGNBYTES].to_vec()),
+        ("zero byte appended", "ExtendedSignature", [&sig[..], &[0]].concat()),
+        ("signature repeated twice", "ExtendedSignature", [sig, sig].concat()),
+    ];
+    for (comment, flag, resized) in resized {
+        corpus.add(comment, flag, &msg, &resized, Some(VAZ256Error::InvalidLength));
+    }
+
+    let other_sig = sign(&msg, &other).unwrap().to_bytes();
+    corpus.add("valid signature of another key", "WrongEmbeddedKey", &msg, &other_sig,
+        Some(VAZ256Error::PublicKeyMismatch));
+    let mut flipped_key = sig;
+    flipped_key[SIGNATURE_SIZE - 1] ^= 1;
+    corpus.add("last bit of the embedded public key flipped", "WrongEmbeddedKey", &msg, &flipped_key,
+        Some(VAZ256Error::PublicKeyMismatch));
+    let mut wrong_key = other_sig;
+    wrong_key[SIGNBYTES..].copy_from_slice(&sig[SIGNBYTES..]);
+    corpus.add("signature of another key with this embedded public key", "WrongKeySignature", &msg, &wrong_key,
+        Some(VAZ256Error::VerificationFailed));
+    assert_ne!(other_public, public_key);
+
+    let swapped = sign(b"another message", &secret).unwrap().to_bytes();
+    modify(&mut corpus, "c taken from the signature of another message", "SwappedChallenge", &|s| {
+        s[..SEEDBYTES].copy_from_slice(&swapped[..SEEDBYTES])
+    });
+    modify(&mut corpus, "c is all zero", "ZeroChallenge", &|s| s[..SEEDBYTES].fill(0));
+
+    corpus.into_json()
+}
+
+impl Corpus {
+    /// Writes the file with one test per line
+    fn into_json(self) -> String {
+        let notes = Value::Object(NOTES.iter().map(|(flag, note)| (flag.to_string(), Value::from(*note))).collect());
+        let mut out = String::from("{\n");
+        out += "  \"algorithm\": \"VAZ256\",\n";
+        out += &format!("  \"numberOfTests\": {},\n", self.tests.len());
+        out += "  \"header\": [\"Verification of malformed VAZ256 signatures\"],\n";
+        out += &format!("  \"notes\": {},\n", notes);
+        out += "  \"testGroups\": [\n";
+        out += "    {\n";
+        out += "      \"type\": \"Vaz256Verify\",\n";
+        out += &format!("      \"publicKey\": \"{}\",\n", hex::encode(self.public_key.as_bytes()));
+        out += "      \"tests\": [\n";
+        for (i, test) in self.tests.iter().enumerate() {
+            let separator = if i + 1 == self.tests.len() { "" } else { "," };
+            out += &format!("        {}{}\n", test, separator);
+        }
+        out += "      ]\n    }\n  ]\n}\n";
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CORPUS: &str = include_str!("../kat/vaz256_verify_test.json");
+
+    #[test]
+    fn test_error_names() {
+        for error in [VAZ256Error::VerificationFailed, VAZ256Error::InvalidLength, VAZ256Error::OutOfMemory] {
+            assert_eq!(error_name(error), format!("{:?}", error));
+            assert_eq!(error_from_name(error_name(error)), Some(error));
+        }
+        assert_eq!(error_from_name("Verificationfailed"), None);
+    }
+
+    #[test]
+    fn test_corpus() {
+        assert!(corpus() == CORPUS, "kat/vaz256_verify_test.json differs");
+        let vectors = parse(CORPUS).unwrap();
+        let root = Value::parse(CORPUS).unwrap();
+        assert_eq!(root.get("numberOfTests").and_then(Value::as_i64), Some(vectors.len() as i64));
+        for (i, vector) in vectors.iter().enumerate() {
+            assert_eq!(vector.tc_id, i + 1);
+            assert!(vector.passes(), "tcId {} ({}): got {:?}", vector.tc_id, vector.comment, vector.run());
+            for flag in &vector.flags {
+                assert!(NOTES.iter().any(|(name, _)| name == flag), "flag {} has no note", flag);
+            }
+        }
+        for (flag, _) in NOTES {
+            assert!(vectors.iter().any(|vector| vector.flags.iter().any(|f| f == flag)), "no vector for {}", flag);
+        }
+    }
+
+    #[test]
+    fn test_malformed_hints_fail_to_unpack() {
+        // The hint vectors must be rejected by the decoder, not only by the final comparison
+        let decoder = ["TooManyHints", "DecreasingHintCounts", "UnsortedHints", "DuplicateHint", "NonzeroHintPadding"];
+        for vector in parse(CORPUS).unwrap() {
+            if vector.flags.iter().any(|flag| decoder.contains(&flag.as_str())) {
+                let (mut c, mut z, mut h) = ([0u8; SEEDBYTES], Default::default(), Default::default());
+                let unpacked = crate::packing_dilithium5::unpack_sig(&mut c, &mut z, &mut h, &vector.sig[..SIGNBYTES]);
+                assert!(!unpacked, "tcId {} unpacks", vector.tc_id);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_files() {
+        assert_eq!(parse("[]"), Err(VAZ256Error::DeserializationError));
+        let key = "00".repeat(32);
+        let group = |test: &str| format!(r#"{{"testGroups":[{{"publicKey":"{}","tests":[{}]}}]}}"#, key, test);
+        let test = r#"{"tcId":1,"comment":"","flags":[],"msg":"","sig":"","result":"invalid","#.to_string()
+            + r#""error":"VerificationFailed"}"#;
+        assert_eq!(parse(&group(&test)).unwrap()[0].expected, Some(VAZ256Error::VerificationFailed));
+        let unknown = test.replace("VerificationFailed", "Unknown");
+        assert_eq!(parse(&group(&unknown)), Err(VAZ256Error::DeserializationError));
+        assert_eq!(parse(&group(&test.replace("invalid", "valid"))), Err(VAZ256Error::DeserializationError));
+        assert_eq!(parse(&group(&test.replace(r#""msg":"""#, r#""msg":"0""#))), Err(VAZ256Error::HexDecodingError));
+    }
+}
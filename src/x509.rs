@@ -0,0 +1,773 @@
+// VAZ256™ - A hybrid post-quantum digital signature scheme
+// Copyright (C) 2025 Fran Luis Vazquez Alonso
+//
+// The name "VAZ256" is a trademark of Fran Luis Vazquez Alonso
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! X.509 v3 certificates (RFC 5280) and PKCS#10 certification requests
+//! (RFC 2986) signed with VAZ256.
+//!
+//! Subject public keys are compact VAZ256 keys and the signature algorithm
+//! identifier is the same `<arc>.1` identifier used for the key (see
+//! [`crate::pkcs8`]). Because every VAZ256 signature embeds the signer's
+//! Dilithium5 key, checking a certificate signature against the issuer's
+//! SubjectPublicKeyInfo also checks that the embedded key hashes to it.
+
+use alloc::{string::{String, ToString}, vec, vec::Vec};
+
+use crate::der::{self, ObjectIdentifier, Reader};
+use crate::pem;
+use crate::pkcs8::{decode_algorithm, encode_algorithm, OidArc};
+use crate::{sign, verify, PublicKey, SecretKey, Signature, VAZ256Error, VAZ256Result};
+
+const OID_COMMON_NAME: &[u64] = &[2, 5, 4, 3];
+const OID_COUNTRY: &[u64] = &[2, 5, 4, 6];
+const OID_ORGANIZATION: &[u64] = &[2, 5, 4, 10];
+const OID_ORGANIZATIONAL_UNIT: &[u64] = &[2, 5, 4, 11];
+const OID_KEY_USAGE: &[u64] = &[2, 5, 29, 15];
+const OID_SUBJECT_ALT_NAME: &[u64] = &[2, 5, 29, 17];
+const OID_BASIC_CONSTRAINTS: &[u64] = &[2, 5, 29, 19];
+const OID_EXTENSION_REQUEST: &[u64] = &[1, 2, 840, 113549, 1, 9, 14];
+
+fn oid(arcs: &[u64]) -> ObjectIdentifier {
+    ObjectIdentifier::new(arcs).expect("valid constant identifier")
+}
+
+/// Distinguished name made of single-valued relative distinguished names
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Name {
+    attributes: Vec<(ObjectIdentifier, u8, String)>,
+}
+
+impl Name {
+    /// Creates an empty name
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a commonName attribute
+    pub fn common_name(self, value: &str) -> Self {
+        self.with(oid(OID_COMMON_NAME), der::TAG_UTF8_STRING, value)
+    }
+
+    /// Appends a two-letter countryName attribute
+    pub fn country(self, value: &str) -> Self {
+        self.with(oid(OID_COUNTRY), der::TAG_PRINTABLE_STRING, value)
+    }
+
+    /// Appends an organizationName attribute
+    pub fn organization(self, value: &str) -> Self {
+        self.with(oid(OID_ORGANIZATION), der::TAG_UTF8_STRING, value)
+    }
+
+    /// Appends an organizationalUnitName attribute
+    pub fn organizational_unit(self, value: &str) -> Self {
+        self.with(oid(OID_ORGANIZATIONAL_UNIT), der::TAG_UTF8_STRING, value)
+    }
+
+    fn with(mut self, oid: ObjectIdentifier, tag: u8, value: &str) -> Self {
+        self.attributes.push((oid, tag, value.to_string()));
+        self
+    }
+
+    /// Returns the attributes in order as (type, value) pairs
+    pub fn attributes(&self) -> impl Iterator<Item = (&ObjectIdentifier, &str)> {
+        self.attributes.iter().map(|(oid, _, value)| (oid, value.as_str()))
+    }
+
+    /// Returns the first commonName attribute
+    pub fn get_common_name(&self) -> Option<&str> {
+        let cn = oid(OID_COMMON_NAME);
+        self.attributes().find(|(oid, _)| **oid == cn).map(|(_, value)| value)
+    }
+
+    fn to_der(&self) -> Vec<u8> {
+        let rdns: Vec<Vec<u8>> = self
+            .attributes
+            .iter()
+            .map(|(oid, tag, value)| {
+                let attribute = der::encode_sequence(&[&der::encode_oid(oid), &der::encode_tlv(*tag, value.as_bytes())]);
+                der::encode_set_of(&[&attribute])
+            })
+            .collect();
+        der::encode_sequence(&rdns.iter().map(Vec::as_slice).collect::<Vec<_>>())
+    }
+
+    fn decode(reader: &mut Reader) -> VAZ256Result<Self> {
+        let mut rdns = reader.read_sequence()?;
+        let mut attributes = Vec::new();
+        while !rdns.is_empty() {
+            let mut rdn = rdns.read_constructed(der::TAG_SET)?;
+            let mut attribute = rdn.read_sequence()?;
+            // Multi-valued relative distinguished names are not supported
+            rdn.finish()?;
+            let oid = attribute.read_oid()?;
+            let (tag, value, _) = attribute.read_any()?;
+            attribute.finish()?;
+            if !matches!(tag, der::TAG_UTF8_STRING | der::TAG_PRINTABLE_STRING | der::TAG_IA5_STRING) {
+                return Err(VAZ256Error::DeserializationError);
+            }
+            let value = String::from_utf8(value.to_vec()).map_err(|_| VAZ256Error::DeserializationError)?;
+            attributes.push((oid, tag, value));
+        }
+        Ok(Self { attributes })
+    }
+}
+
+/// Validity period in seconds since the Unix epoch, both ends inclusive
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Validity {
+    pub not_before: i64,
+    pub not_after: i64,
+}
+
+impl Validity {
+    /// Returns true if `time` lies within the validity period
+    pub fn contains(&self, time: i64) -> bool {
+        self.not_before <= time && time <= self.not_after
+    }
+}
+
+/// Basic constraints extension
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BasicConstraints {
+    pub ca: bool,
+    pub path_len: Option<u32>,
+}
+
+/// Key usage extension bits
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KeyUsage(pub u16);
+
+impl KeyUsage {
+    pub const DIGITAL_SIGNATURE: KeyUsage = KeyUsage(0x8000);
+    pub const CONTENT_COMMITMENT: KeyUsage = KeyUsage(0x4000);
+    pub const KEY_CERT_SIGN: KeyUsage = KeyUsage(0x0400);
+    pub const CRL_SIGN: KeyUsage = KeyUsage(0x0200);
+
+    /// Returns true if all bits of `other` are set
+    pub fn contains(&self, other: KeyUsage) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl core::ops::BitOr for KeyUsage {
+    type Output = KeyUsage;
+
+    fn bitor(self, rhs: KeyUsage) -> KeyUsage {
+        KeyUsage(self.0 | rhs.0)
+    }
+}
+
+/// Subject alternative name entry
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GeneralName {
+    Email(String),
+    DnsName(String),
+    Uri(String),
+    /// Four or sixteen address bytes
+    IpAddress(Vec<u8>),
+}
+
+impl GeneralName {
+    fn to_der(&self) -> Vec<u8> {
+        match self {
+            GeneralName::Email(value) => der::encode_tlv(der::context(1, false), value.as_bytes()),
+            GeneralName::DnsName(value) => der::encode_tlv(der::context(2, false), value.as_bytes()),
+            GeneralName::Uri(value) => der::encode_tlv(der::context(6, false), value.as_bytes()),
+            GeneralName::IpAddress(bytes) => der::encode_tlv(der::context(7, false), bytes),
+        }
+    }
+
+    fn decode(tag: u8, content: &[u8]) -> VAZ256Result<Option<Self>> {
+        let text = || {
+            core::str::from_utf8(content)
+                .ok()
+                .filter(|s| s.is_ascii())
+                .map(str::to_string)
+                .ok_or(VAZ256Error::DeserializationError)
+        };
+        Ok(Some(match tag {
+            t if t == der::context(1, false) => GeneralName::Email(text()?),
+            t if t == der::context(2, false) => GeneralName::DnsName(text()?),
+            t if t == der::context(6, false) => GeneralName::Uri(text()?),
+            t if t == der::context(7, false) && matches!(content.len(), 4 | 16) => {
+                GeneralName::IpAddress(content.to_vec())
+            }
+            // Other name forms are skipped
+            _ => return Ok(None),
+        }))
+    }
+}
+
+/// Supported certificate extensions
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Extensions {
+    pub basic_constraints: Option<BasicConstraints>,
+    pub key_usage: Option<KeyUsage>,
+    pub subject_alt_names: Vec<GeneralName>,
+}
+
+impl Extensions {
+    /// Encodes the extensions as a SEQUENCE OF Extension, or None if there are none
+    fn to_der(&self) -> Option<Vec<u8>> {
+        let mut list = Vec::new();
+        if let Some(bc) = self.basic_constraints {
+            let mut fields = Vec::new();
+            if bc.ca {
+                fields.push(der::encode_boolean(true));
+            }
+            if let Some(path_len) = bc.path_len {
+                fields.push(der::encode_integer_u64(path_len as u64));
+            }
+            let value = der::encode_sequence(&fields.iter().map(Vec::as_slice).collect::<Vec<_>>());
+            list.push(encode_extension(OID_BASIC_CONSTRAINTS, true, &value));
+        }
+        if let Some(usage) = self.key_usage {
+            list.push(encode_extension(OID_KEY_USAGE, true, &der::encode_named_bits(usage.0)));
+        }
+        if !self.subject_alt_names.is_empty() {
+            let names: Vec<Vec<u8>> = self.subject_alt_names.iter().map(GeneralName::to_der).collect();
+            let value = der::encode_sequence(&names.iter().map(Vec::as_slice).collect::<Vec<_>>());
+            list.push(encode_extension(OID_SUBJECT_ALT_NAME, false, &value));
+        }
+        if list.is_empty() {
+            return None;
+        }
+        Some(der::encode_sequence(&list.iter().map(Vec::as_slice).collect::<Vec<_>>()))
+    }
+
+    /// Decodes a SEQUENCE OF Extension, rejecting unknown critical extensions
+    fn decode(reader: &mut Reader) -> VAZ256Result<Self> {
+        let mut extensions = Extensions::default();
+        let mut seen = Vec::new();
+        let mut list = reader.read_sequence()?;
+        while !list.is_empty() {
+            let mut extension = list.read_sequence()?;
+            let id = extension.read_oid()?;
+            let critical = match extension.peek_tag() {
+                Some(der::TAG_BOOLEAN) => extension.read_boolean()?,
+                _ => false,
+            };
+            let mut value = Reader::new(extension.read_octet_string()?);
+            extension.finish()?;
+            if seen.contains(&id) {
+                return Err(VAZ256Error::DeserializationError);
+            }
+
+            if id.arcs() == OID_BASIC_CONSTRAINTS {
+                let mut fields = value.read_sequence()?;
+                let ca = match fields.peek_tag() {
+                    Some(der::TAG_BOOLEAN) => fields.read_boolean()?,
+                    _ => false,
+                };
+                let path_len = match fields.peek_tag() {
+                    Some(der::TAG_INTEGER) => Some(
+                        u32::try_from(fields.read_integer_u64()?).map_err(|_| VAZ256Error::DeserializationError)?,
+                    ),
+                    _ => None,
+                };
+                fields.finish()?;
+                extensions.basic_constraints = Some(BasicConstraints { ca, path_len });
+            } else if id.arcs() == OID_KEY_USAGE {
+                extensions.key_usage = Some(KeyUsage(value.read_named_bits()?));
+            } else if id.arcs() == OID_SUBJECT_ALT_NAME {
+                let mut names = value.read_sequence()?;
+                while !names.is_empty() {
+                    let (tag, content, _) = names.read_any()?;
+                    extensions.subject_alt_names.extend(GeneralName::decode(tag, content)?);
+                }
+            } else if critical {
+                return Err(VAZ256Error::DeserializationError);
+            } else {
+                seen.push(id);
+                continue;
+            }
+            value.finish()?;
+            seen.push(id);
+        }
+        Ok(extensions)
+    }
+}
+
+fn encode_extension(id: &[u64], critical: bool, value: &[u8]) -> Vec<u8> {
+    let id = der::encode_oid(&oid(id));
+    let value = der::encode_octet_string(value);
+    if critical {
+        der::encode_sequence(&[&id, &der::encode_boolean(true), &value])
+    } else {
+        der::encode_sequence(&[&id, &value])
+    }
+}
+
+/// Signs `tbs` and wraps it as SEQUENCE { tbs, algorithm, BIT STRING signature }
+fn sign_structure(tbs: &[u8], key: &SecretKey, arc: &OidArc) -> VAZ256Result<Vec<u8>> {
+    let signature = sign(tbs, key)?;
+    Ok(der::encode_sequence(&[
+        tbs,
+        &encode_algorithm(&arc.vaz256()),
+        &der::encode_bit_string(&signature.to_bytes()),
+    ]))
+}
+
+/// Splits SEQUENCE { tbs, algorithm, BIT STRING signature } into the raw tbs and the signature
+fn split_structure<'a>(der: &'a [u8], arc: &OidArc) -> VAZ256Result<(&'a [u8], Signature)> {
+    let mut outer = Reader::new(der);
+    let mut structure = outer.read_sequence()?;
+    outer.finish()?;
+    let tbs = structure.read_raw(der::TAG_SEQUENCE)?;
+    decode_algorithm(&mut structure, &arc.vaz256())?;
+    let signature = Signature::from_bytes(structure.read_bit_string()?)?;
+    structure.finish()?;
+    Ok((tbs, signature))
+}
+
+/// Parameters of a certificate to be issued
+#[derive(Clone, Debug)]
+pub struct CertificateParams {
+    /// Positive serial number as big-endian bytes, at most 20 bytes
+    pub serial: Vec<u8>,
+    pub subject: Name,
+    pub validity: Validity,
+    pub extensions: Extensions,
+}
+
+/// Parsed X.509 v3 certificate signed with VAZ256
+pub struct Certificate {
+    der: Vec<u8>,
+    tbs: Vec<u8>,
+    serial: Vec<u8>,
+    issuer: Name,
+    subject: Name,
+    validity: Validity,
+    public_key: PublicKey,
+    extensions: Extensions,
+    signature: Signature,
+}
+
+impl Certificate {
+    /// Issues a certificate for `subject_key`, signed by `issuer_key` on behalf of `issuer`
+    pub fn issue(
+        params: &CertificateParams,
+        subject_key: &PublicKey,
+        issuer: &Name,
+        issuer_key: &SecretKey,
+        arc: &OidArc,
+    ) -> VAZ256Result<Self> {
+        if params.serial.is_empty() || params.serial.len() > 20 || params.serial.iter().all(|&b| b == 0) {
+            return Err(VAZ256Error::InvalidLength);
+        }
+        let algorithm = encode_algorithm(&arc.vaz256());
+        let validity = der::encode_sequence(&[
+            &der::encode_time(params.validity.not_before),
+            &der::encode_time(params.validity.not_after),
+        ]);
+        let mut fields = vec![
+            der::encode_tlv(der::context(0, true), &der::encode_integer_u64(2)),
+            der::encode_integer_bytes(&params.serial),
+            algorithm,
+            issuer.to_der(),
+            validity,
+            params.subject.to_der(),
+            subject_key.to_public_key_der(arc),
+        ];
+        if let Some(extensions) = params.extensions.to_der() {
+            fields.push(der::encode_tlv(der::context(3, true), &extensions));
+        }
+        let tbs = der::encode_sequence(&fields.iter().map(Vec::as_slice).collect::<Vec<_>>());
+        Self::from_der(&sign_structure(&tbs, issuer_key, arc)?, arc)
+    }
+
+    /// Issues a self-signed certificate for `key`
+    pub fn self_signed(params: &CertificateParams, key: &SecretKey, arc: &OidArc) -> VAZ256Result<Self> {
+        Self::issue(params, &key.public_key(), &params.subject, key, arc)
+    }
+
+    /// Parses a DER encoded certificate. The signature is not checked.
+    pub fn from_der(der: &[u8], arc: &OidArc) -> VAZ256Result<Self> {
+        let (tbs, signature) = split_structure(der, arc)?;
+
+        let mut fields = Reader::new(tbs).read_sequence()?;
+        let mut version = fields.read_constructed(der::context(0, true))?;
+        if version.read_integer_u64()? != 2 {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        version.finish()?;
+        let serial = fields.read_integer_bytes()?.to_vec();
+        decode_algorithm(&mut fields, &arc.vaz256())?;
+        let issuer = Name::decode(&mut fields)?;
+        let mut validity = fields.read_sequence()?;
+        let validity = Validity {
+            not_before: validity.read_time()?,
+            not_after: {
+                let not_after = validity.read_time()?;
+                validity.finish()?;
+                not_after
+            },
+        };
+        let subject = Name::decode(&mut fields)?;
+        let public_key = PublicKey::from_public_key_der(fields.read_raw(der::TAG_SEQUENCE)?, arc)?;
+        let extensions = match fields.read_optional(der::context(3, true))? {
+            Some(content) => {
+                let mut reader = Reader::new(content);
+                let extensions = Extensions::decode(&mut reader)?;
+                reader.finish()?;
+                extensions
+            }
+            None => Extensions::default(),
+        };
+        fields.finish()?;
+
+        Ok(Self {
+            der: der.to_vec(),
+            tbs: tbs.to_vec(),
+            serial,
+            issuer,
+            subject,
+            validity,
+            public_key,
+            extensions,
+            signature,
+        })
+    }
+
+    /// Parses a PEM armored certificate. The signature is not checked.
+    pub fn from_pem(pem: &str, arc: &OidArc) -> VAZ256Result<Self> {
+        Self::from_der(&pem::decode_label(pem, pem::CERTIFICATE_LABEL)?, arc)
+    }
+
+    /// Returns the DER encoding
+    pub fn to_der(&self) -> &[u8] {
+        &self.der
+    }
+
+    /// Returns the PEM armored encoding
+    pub fn to_pem(&self) -> String {
+        pem::encode(pem::CERTIFICATE_LABEL, &self.der)
+    }
+
+    pub fn serial(&self) -> &[u8] {
+        &self.serial
+    }
+
+    pub fn issuer(&self) -> &Name {
+        &self.issuer
+    }
+
+    pub fn subject(&self) -> &Name {
+        &self.subject
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.validity
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    /// Returns true if the certificate may issue other certificates
+    pub fn is_ca(&self) -> bool {
+        let ca = self.extensions.basic_constraints.is_some_and(|bc| bc.ca);
+        let usage = self.extensions.key_usage.is_none_or(|ku| ku.contains(KeyUsage::KEY_CERT_SIGN));
+        ca && usage
+    }
+
+    /// Checks the certificate signature against the issuer's public key
+    pub fn verify_signature(&self, issuer_key: &PublicKey) -> VAZ256Result<()> {
+        verify(&self.tbs, &self.signature, issuer_key)
+    }
+}
+
+/// Validates `chain` (leaf first, each certificate followed by its issuer) up to
+/// one of the trust `anchors` at `time` (seconds since the Unix epoch).
+///
+/// Each signature is checked with the issuer's SubjectPublicKeyInfo, which also
+/// requires the Dilithium5 key embedded in the signature to hash to it.
+pub fn verify_chain(chain: &[Certificate], anchors: &[Certificate], time: i64) -> VAZ256Result<()> {
+    if chain.is_empty() {
+        return Err(VAZ256Error::ChainValidationFailed);
+    }
+
+    for (depth, cert) in chain.iter().enumerate() {
+        if !cert.validity.contains(time) {
+            return Err(VAZ256Error::ChainValidationFailed);
+        }
+
+        // The chain may end with the trust anchor itself
+        if anchors.iter().any(|anchor| anchor.der == cert.der) {
+            return Ok(());
+        }
+
+        match chain.get(depth + 1) {
+            Some(issuer) => check_issuer(cert, issuer, depth, time)?,
+            None => {
+                let mut result = Err(VAZ256Error::ChainValidationFailed);
+                for anchor in anchors.iter().filter(|anchor| anchor.subject == cert.issuer) {
+                    result = check_issuer(cert, anchor, depth, time);
+                    if result.is_ok() {
+                        break;
+                    }
+                }
+                return result;
+            }
+        }
+    }
+    unreachable!("the last certificate either is or is issued by a trust anchor")
+}
+
+/// Checks that `issuer` may have issued `cert`, which has `depth` certificates below it
+fn check_issuer(cert: &Certificate, issuer: &Certificate, depth: usize, time: i64) -> VAZ256Result<()> {
+    if cert.issuer != issuer.subject || !issuer.validity.contains(time) || !issuer.is_ca() {
+        return Err(VAZ256Error::ChainValidationFailed);
+    }
+    // Intermediate CA certificates between the leaf and this issuer
+    let intermediates = depth as u64;
+    if let Some(path_len) = issuer.extensions.basic_constraints.and_then(|bc| bc.path_len) {
+        if intermediates > path_len as u64 {
+            return Err(VAZ256Error::ChainValidationFailed);
+        }
+    }
+    cert.verify_signature(&issuer.public_key)
+}
+
+/// Parsed PKCS#10 certification request signed with VAZ256
+pub struct CertificateRequest {
+    der: Vec<u8>,
+    info: Vec<u8>,
+    subject: Name,
+    public_key: PublicKey,
+    extensions: Extensions,
+    signature: Signature,
+}
+
+impl CertificateRequest {
+    /// Creates a request for `key` with the requested extensions, signed by `key`
+    pub fn new(subject: &Name, extensions: &Extensions, key: &SecretKey, arc: &OidArc) -> VAZ256Result<Self> {
+        let attributes = match extensions.to_der() {
+            Some(extensions) => {
+                let attribute = der::encode_sequence(&[
+                    &der::encode_oid(&oid(OID_EXTENSION_REQUEST)),
+                    &der::encode_set_of(&[&extensions]),
+                ]);
+                der::encode_tlv(der::context(0, true), &attribute)
+            }
+            None => der::encode_tlv(der::context(0, true), &[]),
+        };
+        let info = der::encode_sequence(&[
+            &der::encode_integer_u64(0),
+            &subject.to_der(),
+            &key.public_key().to_public_key_der(arc),
+            &attributes,
+        ]);
+        Self::from_der(&sign_structure(&info, key, arc)?, arc)
+    }
+
+    /// Parses a DER encoded request. The signature is not checked.
+    pub fn from_der(der: &[u8], arc: &OidArc) -> VAZ256Result<Self> {
+        let (info, signature) = split_structure(der, arc)?;
+
+        let mut fields = Reader::new(info).read_sequence()?;
+        if fields.read_integer_u64()? != 0 {
+            return Err(VAZ256Error::DeserializationError);
+        }
+        let subject = Name::decode(&mut fields)?;
+        let public_key = PublicKey::from_public_key_der(fields.read_raw(der::TAG_SEQUENCE)?, arc)?;
+        let mut attributes = fields.read_constructed(der::context(0, true))?;
+        fields.finish()?;
+
+        let mut extensions = Extensions::default();
+        while !attributes.is_empty() {
+            let mut attribute = attributes.read_sequence()?;
+            let id = attribute.read_oid()?;
+            let mut values = attribute.read_constructed(der::TAG_SET)?;
+            attribute.finish()?;
+            if id.arcs() == OID_EXTENSION_REQUEST {
+                extensions = Extensions::decode(&mut values)?;
+                values.finish()?;
+            }
+        }
+
+        Ok(Self {
+            der: der.to_vec(),
+            info: info.to_vec(),
+            subject,
+            public_key,
+            extensions,
+            signature,
+        })
+    }
+
+    /// Parses a PEM armored request. The signature is not checked.
+    pub fn from_pem(pem: &str, arc: &OidArc) -> VAZ256Result<Self> {
+        Self::from_der(&pem::decode_label(pem, pem::CERTIFICATE_REQUEST_LABEL)?, arc)
+    }
+
+    /// Returns the DER encoding
+    pub fn to_der(&self) -> &[u8] {
+        &self.der
+    }
+
+    /// Returns the PEM armored encoding
+    pub fn to_pem(&self) -> String {
+        pem::encode(pem::CERTIFICATE_REQUEST_LABEL, &self.der)
+    }
+
+    pub fn subject(&self) -> &Name {
+        &self.subject
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    /// Checks the proof of possession, i.e. the signature by the requested key
+    pub fn verify(&self) -> VAZ256Result<()> {
+        verify(&self.info, &self.signature, &self.public_key)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::keygen;
+
+    const NOW: i64 = 1_750_000_000;
+    const YEAR: i64 = 365 * 86400;
+
+    fn ca_params(name: &str, serial: u8, path_len: Option<u32>) -> CertificateParams {
+        CertificateParams {
+            serial: vec![serial],
+            subject: Name::new().country("ES").organization("VAZ256 Test").common_name(name),
+            validity: Validity { not_before: NOW - YEAR, not_after: NOW + YEAR },
+            extensions: Extensions {
+                basic_constraints: Some(BasicConstraints { ca: true, path_len }),
+                key_usage: Some(KeyUsage::KEY_CERT_SIGN | KeyUsage::CRL_SIGN),
+                subject_alt_names: Vec::new(),
+            },
+        }
+    }
+
+    fn leaf_params() -> CertificateParams {
+        CertificateParams {
+            serial: vec![0x80, 0x01],
+            subject: Name::new().common_name("leaf.example"),
+            validity: Validity { not_before: NOW - 86400, not_after: NOW + 86400 },
+            extensions: Extensions {
+                basic_constraints: None,
+                key_usage: Some(KeyUsage::DIGITAL_SIGNATURE),
+                subject_alt_names: vec![
+                    GeneralName::DnsName("leaf.example".to_string()),
+                    GeneralName::IpAddress(vec![10, 0, 0, 1]),
+                ],
+            },
+        }
+    }
+
+    #[test]
+    fn test_certificate_round_trip() {
+        let arc = OidArc::test();
+        let (sk, pk) = keygen().unwrap();
+        let params = ca_params("Root", 1, None);
+        let cert = Certificate::self_signed(&params, &sk, &arc).unwrap();
+
+        let parsed = Certificate::from_pem(&cert.to_pem(), &arc).unwrap();
+        assert_eq!(parsed.subject(), &params.subject);
+        assert_eq!(parsed.issuer(), &params.subject);
+        assert_eq!(parsed.subject().get_common_name(), Some("Root"));
+        assert_eq!(parsed.validity(), params.validity);
+        assert_eq!(parsed.extensions(), &params.extensions);
+        assert_eq!(parsed.public_key(), &pk);
+        assert_eq!(parsed.serial(), &[1]);
+        assert!(parsed.verify_signature(&pk).is_ok());
+    }
+
+    #[test]
+    fn test_chain_validation() {
+        let arc = OidArc::test();
+        let (root_sk, _) = keygen().unwrap();
+        let (inter_sk, inter_pk) = keygen().unwrap();
+        let (_, leaf_pk) = keygen().unwrap();
+
+        let root = Certificate::self_signed(&ca_params("Root", 1, Some(1)), &root_sk, &arc).unwrap();
+        let inter = Certificate::issue(&ca_params("Intermediate", 2, None), &inter_pk, root.subject(), &root_sk, &arc).unwrap();
+        let leaf = Certificate::issue(&leaf_params(), &leaf_pk, inter.subject(), &inter_sk, &arc).unwrap();
+        assert_eq!(leaf.extensions(), &leaf_params().extensions);
+
+        let chain = [leaf, inter];
+        let anchors = [root];
+        assert!(verify_chain(&chain, &anchors, NOW).is_ok());
+        assert!(verify_chain(&chain[1..], &anchors, NOW).is_ok());
+        assert_eq!(verify_chain(&chain, &[], NOW), Err(VAZ256Error::ChainValidationFailed));
+
+        // The leaf has expired at the given time
+        assert_eq!(
+            verify_chain(&chain, &anchors, NOW + 2 * 86400),
+            Err(VAZ256Error::ChainValidationFailed)
+        );
+
+        // A root restricted to path length zero cannot sit above an intermediate
+        let strict = Certificate::self_signed(&ca_params("Root", 1, Some(0)), &root_sk, &arc).unwrap();
+        assert_eq!(verify_chain(&chain, &[strict], NOW), Err(VAZ256Error::ChainValidationFailed));
+    }
+
+    #[test]
+    fn test_issuer_key_mismatch() {
+        let arc = OidArc::test();
+        let (root_sk, _) = keygen().unwrap();
+        let (other_sk, _) = keygen().unwrap();
+        let (_, leaf_pk) = keygen().unwrap();
+
+        let params = ca_params("Root", 1, None);
+        let root = Certificate::self_signed(&params, &root_sk, &arc).unwrap();
+        // Same issuer name, but signed by a different key
+        let leaf = Certificate::issue(&leaf_params(), &leaf_pk, root.subject(), &other_sk, &arc).unwrap();
+        assert_eq!(verify_chain(&[leaf], &[root], NOW), Err(VAZ256Error::PublicKeyMismatch));
+    }
+
+    #[test]
+    fn test_non_ca_issuer() {
+        let arc = OidArc::test();
+        let (leaf_sk, leaf_pk) = keygen().unwrap();
+        let (_, other_pk) = keygen().unwrap();
+
+        let leaf = Certificate::self_signed(&leaf_params(), &leaf_sk, &arc).unwrap();
+        let child = Certificate::issue(&leaf_params(), &other_pk, leaf.subject(), &leaf_sk, &arc).unwrap();
+        assert!(child.verify_signature(&leaf_pk).is_ok());
+        assert_eq!(verify_chain(&[child], &[leaf], NOW), Err(VAZ256Error::ChainValidationFailed));
+    }
+
+    #[test]
+    fn test_certificate_request() {
+        let arc = OidArc::test();
+        let (sk, pk) = keygen().unwrap();
+        let subject = Name::new().common_name("leaf.example");
+        let extensions = leaf_params().extensions;
+
+        let csr = CertificateRequest::new(&subject, &extensions, &sk, &arc).unwrap();
+        let parsed = CertificateRequest::from_pem(&csr.to_pem(), &arc).unwrap();
+        assert!(parsed.verify().is_ok());
+        assert_eq!(parsed.subject(), &subject);
+        assert_eq!(parsed.public_key(), &pk);
+        assert_eq!(parsed.extensions(), &extensions);
+
+        let mut der = csr.to_der().to_vec();
+        der[20] ^= 1;
+        assert!(CertificateRequest::from_der(&der, &arc).map_or(true, |csr| csr.verify().is_err()));
+    }
+}
@@ -1,8 +1,11 @@
 // This is a feature-reduced implementation of Zeroize. 
 // Created by the author to simplify the code and only work with necessary functions.
 
-use std::ptr;
-use std::sync::atomic::{fence, Ordering};
+use core::ptr;
+use core::sync::atomic::{fence, Ordering};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
 
 /// Trait for securely zeroing memory to prevent sensitive data from remaining in memory
 pub trait Zeroize {
@@ -36,6 +39,7 @@ impl<T: Zeroize, const N: usize> Zeroize for [T; N] {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T: Zeroize> Zeroize for Vec<T> {
     fn zeroize(&mut self) {
         for elem in self.iter_mut() {
@@ -47,6 +51,7 @@ impl<T: Zeroize> Zeroize for Vec<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Zeroize for String {
     fn zeroize(&mut self) {
         unsafe {
@@ -100,13 +105,15 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn test_zeroize_vec() {
-        let mut vec = vec![0xFFu8; 32];
+        let mut vec = alloc::vec![0xFFu8; 32];
         vec.zeroize();
         assert!(vec.is_empty());
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn test_zeroize_string() {
         let mut string = String::from("sensitive data");
         string.zeroize();